@@ -1,4 +1,4 @@
-use app_store_connect_rust::{Client, Result};
+use app_store_connect_rust::{BulkAction, Client, Result};
 use serde_json::json;
 use std::collections::HashMap;
 use std::env;
@@ -92,22 +92,15 @@ async fn main() -> Result<()> {
     let mut success_count = 0;
     let mut failure_count = 0;
 
-    for (locale, result) in &results {
-        if let Some(success) = result.get("success").and_then(|s| s.as_bool()) {
-            if success {
-                success_count += 1;
-                let action = result
-                    .get("action")
-                    .and_then(|a| a.as_str())
-                    .unwrap_or("updated");
-                println!("  ✓ {}: {}", locale, action);
-            } else {
+    for result in &results {
+        match &result.action {
+            BulkAction::Failed(error) => {
                 failure_count += 1;
-                let error = result
-                    .get("error")
-                    .and_then(|e| e.as_str())
-                    .unwrap_or("Unknown error");
-                println!("  ✗ {}: {}", locale, error);
+                println!("  ✗ {}: {}", result.locale, error);
+            }
+            action => {
+                success_count += 1;
+                println!("  ✓ {}: {}", result.locale, action);
             }
         }
     }
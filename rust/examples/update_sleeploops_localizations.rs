@@ -1,4 +1,4 @@
-use app_store_connect_rust::{Client, AppStoreConnectError, AuthenticationError};
+use app_store_connect_rust::{normalize_locale, BulkAction, Client, AppStoreConnectError, AuthenticationError};
 use serde_json::json;
 use std::env;
 
@@ -117,8 +117,7 @@ PERFECTO PARA:
             // Create Spanish (Mexico) localization directly
             println!("\n📝 Creating Spanish (Mexico) localization...");
             
-            // Try different locale codes - Apple might use es-ES or es-419 for Latin America
-            let locale_code = "es-ES"; // Try Spanish (Spain) first
+            let locale_code = normalize_locale("es_MX")?;
             println!("  Using locale code: {}", locale_code);
             
             // Extract the values we need
@@ -129,7 +128,7 @@ PERFECTO PARA:
             // Create the localization
             let result = client.localizations().create(
                 app_info_id,
-                locale_code,
+                &locale_code,
                 name,
                 subtitle,
                 if privacy_policy_url == Some("") { None } else { privacy_policy_url },
@@ -151,6 +150,36 @@ PERFECTO PARA:
                     println!("  • Locale: {}", locale);
                 }
             }
+            
+            // Push description, keywords and release notes to the current version
+            println!("\n📝 Adding version metadata...");
+            if let Some(version) = client.get_current_version(app_id).await? {
+                let version_id = version.get("id")
+                    .and_then(|id| id.as_str())
+                    .ok_or_else(|| AppStoreConnectError::Api { 
+                        message: "Version ID not found".to_string() 
+                    })?;
+                
+                let mut version_localizations = std::collections::HashMap::new();
+                version_localizations.insert(locale_code.to_string(), spanish_mx_metadata.clone());
+                
+                let results = client.version_localizations()
+                    .bulk_update(version_id, version_localizations)
+                    .await?;
+                
+                for result in results {
+                    match &result.action {
+                        BulkAction::Failed(error) => {
+                            println!("❌ Version metadata failed for {}: {}", result.locale, error);
+                        }
+                        action => {
+                            println!("✅ Version metadata {} for {}", action, result.locale);
+                        }
+                    }
+                }
+            } else {
+                println!("❌ No app store version found for this app");
+            }
         } else {
             println!("❌ No app info found for this app");
         }
@@ -0,0 +1,268 @@
+use crate::base::BaseAPI;
+use crate::error::AppStoreConnectError;
+use crate::metadata::FieldUpdate;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ContentDescriptor {
+    AlcoholTobaccoOrDrugUseOrReferences,
+    Contests,
+    GamblingSimulated,
+    HorrorOrFearThemes,
+    MatureOrSuggestiveThemes,
+    MedicalOrTreatmentInformation,
+    ProfanityOrCrudeHumor,
+    SexualContentGraphicAndNudity,
+    SexualContentOrNudity,
+    ViolenceCartoonOrFantasy,
+    ViolenceRealistic,
+    ViolenceRealisticProlongedGraphicOrSadistic,
+}
+
+impl ContentDescriptor {
+    pub const ALL: [ContentDescriptor; 12] = [
+        ContentDescriptor::AlcoholTobaccoOrDrugUseOrReferences,
+        ContentDescriptor::Contests,
+        ContentDescriptor::GamblingSimulated,
+        ContentDescriptor::HorrorOrFearThemes,
+        ContentDescriptor::MatureOrSuggestiveThemes,
+        ContentDescriptor::MedicalOrTreatmentInformation,
+        ContentDescriptor::ProfanityOrCrudeHumor,
+        ContentDescriptor::SexualContentGraphicAndNudity,
+        ContentDescriptor::SexualContentOrNudity,
+        ContentDescriptor::ViolenceCartoonOrFantasy,
+        ContentDescriptor::ViolenceRealistic,
+        ContentDescriptor::ViolenceRealisticProlongedGraphicOrSadistic,
+    ];
+
+    pub fn attribute(&self) -> &'static str {
+        match self {
+            ContentDescriptor::AlcoholTobaccoOrDrugUseOrReferences => {
+                "alcoholTobaccoOrDrugUseOrReferences"
+            }
+            ContentDescriptor::Contests => "contests",
+            ContentDescriptor::GamblingSimulated => "gamblingSimulated",
+            ContentDescriptor::HorrorOrFearThemes => "horrorOrFearThemes",
+            ContentDescriptor::MatureOrSuggestiveThemes => "matureOrSuggestiveThemes",
+            ContentDescriptor::MedicalOrTreatmentInformation => "medicalOrTreatmentInformation",
+            ContentDescriptor::ProfanityOrCrudeHumor => "profanityOrCrudeHumor",
+            ContentDescriptor::SexualContentGraphicAndNudity => "sexualContentGraphicAndNudity",
+            ContentDescriptor::SexualContentOrNudity => "sexualContentOrNudity",
+            ContentDescriptor::ViolenceCartoonOrFantasy => "violenceCartoonOrFantasy",
+            ContentDescriptor::ViolenceRealistic => "violenceRealistic",
+            ContentDescriptor::ViolenceRealisticProlongedGraphicOrSadistic => {
+                "violenceRealisticProlongedGraphicOrSadistic"
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContentLevel {
+    None,
+    InfrequentOrMild,
+    FrequentOrIntense,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KidsAgeBand {
+    FiveAndUnder,
+    SixToEight,
+    NineToEleven,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AgeRatingOverride {
+    None,
+    SeventeenPlus,
+    Unrated,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgeRatingDeclaration {
+    pub id: String,
+    pub descriptors: BTreeMap<ContentDescriptor, ContentLevel>,
+    pub gambling: Option<bool>,
+    pub unrestricted_web_access: Option<bool>,
+    pub loot_box: Option<bool>,
+    pub kids_age_band: Option<KidsAgeBand>,
+    pub age_rating_override: Option<AgeRatingOverride>,
+}
+
+impl AgeRatingDeclaration {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            descriptors: ContentDescriptor::ALL
+                .into_iter()
+                .filter_map(|descriptor| {
+                    Some((
+                        descriptor,
+                        typed_attribute(attributes, descriptor.attribute())?,
+                    ))
+                })
+                .collect(),
+            gambling: typed_attribute(attributes, "gambling"),
+            unrestricted_web_access: typed_attribute(attributes, "unrestrictedWebAccess"),
+            loot_box: typed_attribute(attributes, "lootBox"),
+            kids_age_band: typed_attribute(attributes, "kidsAgeBand"),
+            age_rating_override: typed_attribute(attributes, "ageRatingOverride"),
+        })
+    }
+
+    pub fn is_made_for_kids(&self) -> bool {
+        self.kids_age_band.is_some()
+    }
+
+    // Submission is blocked until every question has been answered.
+    pub fn unanswered(&self) -> Vec<&'static str> {
+        let mut unanswered: Vec<&'static str> = ContentDescriptor::ALL
+            .iter()
+            .filter(|descriptor| !self.descriptors.contains_key(descriptor))
+            .map(|descriptor| descriptor.attribute())
+            .collect();
+
+        if self.gambling.is_none() {
+            unanswered.push("gambling");
+        }
+        if self.unrestricted_web_access.is_none() {
+            unanswered.push("unrestrictedWebAccess");
+        }
+
+        unanswered
+    }
+}
+
+fn typed_attribute<T: DeserializeOwned>(attributes: Option<&Value>, key: &str) -> Option<T> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgeRatingUpdate {
+    pub descriptors: BTreeMap<ContentDescriptor, ContentLevel>,
+    pub gambling: Option<bool>,
+    pub unrestricted_web_access: Option<bool>,
+    pub loot_box: Option<bool>,
+    pub kids_age_band: FieldUpdate<KidsAgeBand>,
+    pub age_rating_override: Option<AgeRatingOverride>,
+}
+
+impl AgeRatingUpdate {
+    // The answers for an app with none of the listed content, the usual
+    // starting point for utility apps.
+    pub fn no_objectionable_content() -> Self {
+        Self {
+            descriptors: ContentDescriptor::ALL
+                .into_iter()
+                .map(|descriptor| (descriptor, ContentLevel::None))
+                .collect(),
+            gambling: Some(false),
+            unrestricted_web_access: Some(false),
+            loot_box: Some(false),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_descriptor(mut self, descriptor: ContentDescriptor, level: ContentLevel) -> Self {
+        self.descriptors.insert(descriptor, level);
+        self
+    }
+
+    pub fn to_attributes(&self) -> Value {
+        let mut attributes = json!({});
+
+        for (descriptor, level) in &self.descriptors {
+            attributes[descriptor.attribute()] = json!(level);
+        }
+        if let Some(gambling) = self.gambling {
+            attributes["gambling"] = json!(gambling);
+        }
+        if let Some(unrestricted) = self.unrestricted_web_access {
+            attributes["unrestrictedWebAccess"] = json!(unrestricted);
+        }
+        if let Some(loot_box) = self.loot_box {
+            attributes["lootBox"] = json!(loot_box);
+        }
+        self.kids_age_band.write(&mut attributes, "kidsAgeBand");
+        if let Some(age_rating_override) = self.age_rating_override {
+            attributes["ageRatingOverride"] = json!(age_rating_override);
+        }
+
+        attributes
+    }
+}
+
+#[derive(Clone)]
+pub struct AgeRatingsAPI {
+    base: BaseAPI,
+}
+
+impl AgeRatingsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get(
+        &self,
+        app_info_id: &str,
+    ) -> Result<AgeRatingDeclaration, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("appInfos/{}/ageRatingDeclaration", app_info_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .and_then(AgeRatingDeclaration::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        declaration_id: &str,
+        update: &AgeRatingUpdate,
+    ) -> Result<AgeRatingDeclaration, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "ageRatingDeclarations",
+                "id": declaration_id,
+                "attributes": update.to_attributes()
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("ageRatingDeclarations/{}", declaration_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(AgeRatingDeclaration::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update_for_app_info(
+        &self,
+        app_info_id: &str,
+        update: &AgeRatingUpdate,
+    ) -> Result<AgeRatingDeclaration, AppStoreConnectError> {
+        let declaration = self.get(app_info_id).await?;
+        self.update(&declaration.id, update).await
+    }
+}
@@ -0,0 +1,423 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde_json::{json, Value};
+
+// Keys are uploaded as the base64 body of a PEM public key; headers and
+// line breaks are stripped so a `.pem` file can be passed as-is.
+pub fn public_key_content(public_key: &str) -> Result<String, AppStoreConnectError> {
+    let body: String = public_key
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("-----"))
+        .collect();
+
+    let is_base64 = body
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '='));
+    if body.is_empty() || !is_base64 {
+        return Err(AppStoreConnectError::Validation(ValidationError::new(
+            "Public key must be a PEM or base64 encoded public key",
+        )));
+    }
+
+    Ok(body)
+}
+
+pub fn validate_webhook_url(endpoint_url: &str) -> Result<(), AppStoreConnectError> {
+    match url::Url::parse(endpoint_url) {
+        Ok(url) if url.scheme() == "https" && url.host().is_some() => Ok(()),
+        _ => Err(AppStoreConnectError::Validation(ValidationError::new(
+            format!("Webhook endpoint '{}' must be an https URL", endpoint_url),
+        ))),
+    }
+}
+
+#[derive(Clone)]
+pub struct AlternativeDistributionAPI {
+    base: BaseAPI,
+}
+
+impl AlternativeDistributionAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_package_for_version(
+        &self,
+        version_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!(
+                    "appStoreVersions/{}/alternativeDistributionPackage",
+                    version_id
+                ),
+                None,
+            )
+            .await?;
+
+        Ok(response.get("data").cloned().filter(|d| !d.is_null()))
+    }
+
+    pub async fn get_package(&self, package_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("alternativeDistributionPackages/{}", package_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn create_package(&self, version_id: &str) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "alternativeDistributionPackages",
+                "relationships": {
+                    "appStoreVersion": {
+                        "data": {
+                            "type": "appStoreVersions",
+                            "id": version_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .post("alternativeDistributionPackages", data)
+            .await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_or_create_package(
+        &self,
+        version_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        match self.get_package_for_version(version_id).await? {
+            Some(package) => Ok(package),
+            None => self.create_package(version_id).await,
+        }
+    }
+
+    pub async fn get_package_versions(
+        &self,
+        package_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("alternativeDistributionPackages/{}/versions", package_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_package_version(
+        &self,
+        package_version_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!(
+                    "alternativeDistributionPackageVersions/{}",
+                    package_version_id
+                ),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_latest_completed_version(
+        &self,
+        package_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let versions = self.get_package_versions(package_id).await?;
+
+        Ok(versions
+            .into_iter()
+            .filter(|v| {
+                v.get("attributes")
+                    .and_then(|a| a.get("state"))
+                    .and_then(|s| s.as_str())
+                    == Some("COMPLETED")
+            })
+            .max_by(|a, b| {
+                let version = |v: &Value| {
+                    v.get("attributes")
+                        .and_then(|a| a.get("version"))
+                        .and_then(|s| s.as_str())
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                version(a).cmp(&version(b))
+            }))
+    }
+
+    pub async fn get_variants(
+        &self,
+        package_version_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!(
+                    "alternativeDistributionPackageVersions/{}/variants",
+                    package_version_id
+                ),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_variant(&self, variant_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("alternativeDistributionPackageVariants/{}", variant_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_deltas(
+        &self,
+        package_version_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!(
+                    "alternativeDistributionPackageVersions/{}/deltas",
+                    package_version_id
+                ),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_delta(&self, delta_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("alternativeDistributionPackageDeltas/{}", delta_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_keys(&self) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages("alternativeDistributionKeys", None, None)
+            .await
+    }
+
+    pub async fn get_key_for_app(
+        &self,
+        app_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        match self
+            .base
+            .get(&format!("apps/{}/alternativeDistributionKey", app_id), None)
+            .await
+        {
+            Ok(response) => Ok(response.get("data").cloned().filter(|d| !d.is_null())),
+            Err(AppStoreConnectError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Without an app the key is registered for the whole account, which is
+    // what marketplaces use to verify their own distribution.
+    pub async fn create_key(
+        &self,
+        public_key: &str,
+        app_id: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut data = json!({
+            "data": {
+                "type": "alternativeDistributionKeys",
+                "attributes": {
+                    "publicKey": public_key_content(public_key)?
+                }
+            }
+        });
+
+        if let Some(app_id) = app_id {
+            data["data"]["relationships"] = json!({
+                "app": {
+                    "data": { "type": "apps", "id": app_id }
+                }
+            });
+        }
+
+        let response = self.base.post("alternativeDistributionKeys", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete_key(&self, key_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("alternativeDistributionKeys/{}", key_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_marketplace_domains(&self) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages("marketplaceDomains", None, None)
+            .await
+    }
+
+    pub async fn create_marketplace_domain(
+        &self,
+        domain: &str,
+        reference_name: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "marketplaceDomains",
+                "attributes": {
+                    "domain": domain,
+                    "referenceName": reference_name
+                }
+            }
+        });
+
+        let response = self.base.post("marketplaceDomains", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete_marketplace_domain(
+        &self,
+        domain_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("marketplaceDomains/{}", domain_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_marketplace_webhooks(&self) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages("marketplaceWebhooks", None, None)
+            .await
+    }
+
+    pub async fn create_marketplace_webhook(
+        &self,
+        endpoint_url: &str,
+        secret: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        validate_webhook_url(endpoint_url)?;
+
+        let data = json!({
+            "data": {
+                "type": "marketplaceWebhooks",
+                "attributes": {
+                    "endpointUrl": endpoint_url,
+                    "secret": secret
+                }
+            }
+        });
+
+        let response = self.base.post("marketplaceWebhooks", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update_marketplace_webhook(
+        &self,
+        webhook_id: &str,
+        endpoint_url: Option<&str>,
+        secret: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({});
+
+        if let Some(endpoint_url) = endpoint_url {
+            validate_webhook_url(endpoint_url)?;
+            attributes["endpointUrl"] = json!(endpoint_url);
+        }
+        if let Some(secret) = secret {
+            attributes["secret"] = json!(secret);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "marketplaceWebhooks",
+                "id": webhook_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("marketplaceWebhooks/{}", webhook_id), data)
+            .await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete_marketplace_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("marketplaceWebhooks/{}", webhook_id))
+            .await?;
+        Ok(())
+    }
+}
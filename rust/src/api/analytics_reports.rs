@@ -0,0 +1,302 @@
+use crate::base::BaseAPI;
+use crate::error::AppStoreConnectError;
+use crate::upload::md5_file;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AnalyticsAccessType {
+    OneTimeSnapshot,
+    Ongoing,
+}
+
+impl AnalyticsAccessType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalyticsAccessType::OneTimeSnapshot => "ONE_TIME_SNAPSHOT",
+            AnalyticsAccessType::Ongoing => "ONGOING",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportGranularity {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl ReportGranularity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportGranularity::Daily => "DAILY",
+            ReportGranularity::Weekly => "WEEKLY",
+            ReportGranularity::Monthly => "MONTHLY",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsReport {
+    pub id: String,
+    pub name: String,
+    pub category: Option<String>,
+}
+
+impl AnalyticsReport {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: string_attribute(attributes, "name").unwrap_or_default(),
+            category: string_attribute(attributes, "category"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsReportInstance {
+    pub id: String,
+    pub granularity: Option<String>,
+    pub processing_date: Option<String>,
+}
+
+impl AnalyticsReportInstance {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            granularity: string_attribute(attributes, "granularity"),
+            processing_date: string_attribute(attributes, "processingDate"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsReportSegment {
+    pub id: String,
+    pub url: String,
+    pub checksum: Option<String>,
+    pub size_in_bytes: Option<u64>,
+}
+
+impl AnalyticsReportSegment {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            url: string_attribute(attributes, "url")?,
+            checksum: string_attribute(attributes, "checksum"),
+            size_in_bytes: attributes
+                .and_then(|a| a.get("sizeInBytes"))
+                .and_then(|s| s.as_u64()),
+        })
+    }
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+#[derive(Clone)]
+pub struct AnalyticsReportsAPI {
+    base: BaseAPI,
+}
+
+impl AnalyticsReportsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn create_report_request(
+        &self,
+        app_id: &str,
+        access_type: AnalyticsAccessType,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "analyticsReportRequests",
+                "attributes": {
+                    "accessType": access_type.as_str()
+                },
+                "relationships": {
+                    "app": {
+                        "data": {
+                            "type": "apps",
+                            "id": app_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("analyticsReportRequests", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_report_requests(
+        &self,
+        app_id: &str,
+        access_type: Option<AnalyticsAccessType>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if let Some(access_type) = access_type {
+            params.insert(
+                "filter[accessType]".to_string(),
+                access_type.as_str().to_string(),
+            );
+        }
+
+        self.base
+            .get_all_pages(
+                &format!("apps/{}/analyticsReportRequests", app_id),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn delete_report_request(
+        &self,
+        request_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("analyticsReportRequests/{}", request_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_reports(
+        &self,
+        request_id: &str,
+        category: Option<&str>,
+    ) -> Result<Vec<AnalyticsReport>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if let Some(category) = category {
+            params.insert("filter[category]".to_string(), category.to_string());
+        }
+
+        let reports = self
+            .base
+            .get_all_pages(
+                &format!("analyticsReportRequests/{}/reports", request_id),
+                Some(params),
+                None,
+            )
+            .await?;
+
+        Ok(reports
+            .iter()
+            .filter_map(AnalyticsReport::from_value)
+            .collect())
+    }
+
+    pub async fn get_instances(
+        &self,
+        report_id: &str,
+        granularity: Option<ReportGranularity>,
+        processing_date: Option<&str>,
+    ) -> Result<Vec<AnalyticsReportInstance>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if let Some(granularity) = granularity {
+            params.insert(
+                "filter[granularity]".to_string(),
+                granularity.as_str().to_string(),
+            );
+        }
+        if let Some(date) = processing_date {
+            params.insert("filter[processingDate]".to_string(), date.to_string());
+        }
+
+        let instances = self
+            .base
+            .get_all_pages(
+                &format!("analyticsReports/{}/instances", report_id),
+                Some(params),
+                None,
+            )
+            .await?;
+
+        Ok(instances
+            .iter()
+            .filter_map(AnalyticsReportInstance::from_value)
+            .collect())
+    }
+
+    pub async fn get_segments(
+        &self,
+        instance_id: &str,
+    ) -> Result<Vec<AnalyticsReportSegment>, AppStoreConnectError> {
+        let segments = self
+            .base
+            .get_all_pages(
+                &format!("analyticsReportInstances/{}/segments", instance_id),
+                None,
+                None,
+            )
+            .await?;
+
+        Ok(segments
+            .iter()
+            .filter_map(AnalyticsReportSegment::from_value)
+            .collect())
+    }
+
+    pub async fn download_segment(
+        &self,
+        segment: &AnalyticsReportSegment,
+        path: impl AsRef<Path>,
+    ) -> Result<u64, AppStoreConnectError> {
+        let path = path.as_ref();
+        let written = self.base.download_url_to_file(&segment.url, path).await?;
+
+        if let Some(expected) = &segment.checksum {
+            let actual = md5_file(path).await?;
+            if !actual.eq_ignore_ascii_case(expected) {
+                let _ = tokio::fs::remove_file(path).await;
+                return Err(AppStoreConnectError::Api {
+                    message: format!(
+                        "Checksum mismatch for segment {}: expected {}, got {}",
+                        segment.id, expected, actual
+                    ),
+                });
+            }
+        }
+
+        Ok(written)
+    }
+
+    pub async fn download_instance(
+        &self,
+        instance_id: &str,
+        dest_dir: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>, AppStoreConnectError> {
+        let dest_dir = dest_dir.as_ref();
+        tokio::fs::create_dir_all(dest_dir).await?;
+
+        let mut paths = Vec::new();
+        for segment in self.get_segments(instance_id).await? {
+            let path = dest_dir.join(format!("{}.gz", segment.id));
+            self.download_segment(&segment, &path).await?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::base::{linkages, BaseAPI};
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde_json::{json, Value};
+
+#[derive(Clone)]
+pub struct AvailabilityAPI {
+    base: BaseAPI,
+}
+
+impl AvailabilityAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get(&self, app_id: &str) -> Result<Option<Value>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("apps/{}/appAvailability", app_id), None)
+            .await?;
+
+        Ok(response.get("data").cloned().filter(|d| !d.is_null()))
+    }
+
+    pub async fn get_available_territories(
+        &self,
+        app_id: &str,
+    ) -> Result<Vec<String>, AppStoreConnectError> {
+        let territories = self
+            .base
+            .get_all_pages(&format!("apps/{}/availableTerritories", app_id), None, None)
+            .await?;
+
+        Ok(territories
+            .iter()
+            .filter_map(|t| t.get("id").and_then(|i| i.as_str()))
+            .map(|id| id.to_string())
+            .collect())
+    }
+
+    pub async fn is_available_in_new_territories(
+        &self,
+        app_id: &str,
+    ) -> Result<bool, AppStoreConnectError> {
+        let availability = self.get(app_id).await?;
+
+        Ok(availability
+            .as_ref()
+            .and_then(|a| a.get("attributes"))
+            .and_then(|a| a.get("availableInNewTerritories"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false))
+    }
+
+    pub async fn set(
+        &self,
+        app_id: &str,
+        territories: &[&str],
+        available_in_new_territories: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "appAvailabilities",
+                "attributes": {
+                    "availableInNewTerritories": available_in_new_territories
+                },
+                "relationships": {
+                    "app": {
+                        "data": {
+                            "type": "apps",
+                            "id": app_id
+                        }
+                    },
+                    "availableTerritories": {
+                        "data": linkages("territories", territories)
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("appAvailabilities", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn remove_from_sale(&self, app_id: &str) -> Result<Value, AppStoreConnectError> {
+        self.set(app_id, &[], false).await
+    }
+
+    // `remove_from_sale` turns new territories off, so the live flag can't be
+    // reused here; read it before removing the app and pass it back in.
+    pub async fn relist(
+        &self,
+        app_id: &str,
+        territories: &[&str],
+        available_in_new_territories: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        if territories.is_empty() {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                "At least one territory is required to relist an app",
+            )));
+        }
+
+        self.set(app_id, territories, available_in_new_territories)
+            .await
+    }
+
+    pub async fn get_all_territories(&self) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base.get_all_pages("territories", None, None).await
+    }
+}
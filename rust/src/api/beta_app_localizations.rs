@@ -0,0 +1,213 @@
+use crate::base::BaseAPI;
+use crate::bulk::{BulkAction, BulkResult};
+use crate::error::AppStoreConnectError;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct BetaAppLocalizationsAPI {
+    base: BaseAPI,
+}
+
+impl BetaAppLocalizationsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, app_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(&format!("apps/{}/betaAppLocalizations", app_id), None, None)
+            .await
+    }
+
+    pub async fn get(&self, localization_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("betaAppLocalizations/{}", localization_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn create(
+        &self,
+        app_id: &str,
+        locale: &str,
+        description: Option<&str>,
+        feedback_email: Option<&str>,
+        marketing_url: Option<&str>,
+        privacy_policy_url: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({ "locale": locale });
+
+        if let Some(description) = description {
+            attributes["description"] = json!(description);
+        }
+        if let Some(email) = feedback_email {
+            attributes["feedbackEmail"] = json!(email);
+        }
+        if let Some(url) = marketing_url {
+            attributes["marketingUrl"] = json!(url);
+        }
+        if let Some(url) = privacy_policy_url {
+            attributes["privacyPolicyUrl"] = json!(url);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "betaAppLocalizations",
+                "attributes": attributes,
+                "relationships": {
+                    "app": {
+                        "data": {
+                            "type": "apps",
+                            "id": app_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("betaAppLocalizations", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        localization_id: &str,
+        description: Option<&str>,
+        feedback_email: Option<&str>,
+        marketing_url: Option<&str>,
+        privacy_policy_url: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({});
+
+        if let Some(description) = description {
+            attributes["description"] = json!(description);
+        }
+        if let Some(email) = feedback_email {
+            attributes["feedbackEmail"] = json!(email);
+        }
+        if let Some(url) = marketing_url {
+            attributes["marketingUrl"] = json!(url);
+        }
+        if let Some(url) = privacy_policy_url {
+            attributes["privacyPolicyUrl"] = json!(url);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "betaAppLocalizations",
+                "id": localization_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("betaAppLocalizations/{}", localization_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, localization_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("betaAppLocalizations/{}", localization_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn bulk_update(
+        &self,
+        app_id: &str,
+        localizations: HashMap<String, Value>,
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
+        let existing = self.get_all(app_id).await?;
+        let mut existing_by_locale = HashMap::new();
+
+        for loc in existing {
+            if let (Some(locale), Some(id)) = (
+                loc.get("attributes")
+                    .and_then(|a| a.get("locale"))
+                    .and_then(|l| l.as_str()),
+                loc.get("id").and_then(|i| i.as_str()),
+            ) {
+                existing_by_locale.insert(locale.to_string(), id.to_string());
+            }
+        }
+
+        let mut results = Vec::new();
+
+        for (locale, attributes) in localizations {
+            let result = if let Some(localization_id) = existing_by_locale.get(&locale) {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Updated,
+                    self.update_from_value(localization_id, &attributes).await,
+                )
+            } else {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Created,
+                    self.create_from_value(app_id, &locale, &attributes).await,
+                )
+            };
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    async fn update_from_value(
+        &self,
+        localization_id: &str,
+        attributes: &Value,
+    ) -> Result<Value, AppStoreConnectError> {
+        let field = |name: &str| attributes.get(name).and_then(|v| v.as_str());
+
+        self.update(
+            localization_id,
+            field("description"),
+            field("feedbackEmail"),
+            field("marketingUrl"),
+            field("privacyPolicyUrl"),
+        )
+        .await
+    }
+
+    async fn create_from_value(
+        &self,
+        app_id: &str,
+        locale: &str,
+        attributes: &Value,
+    ) -> Result<Value, AppStoreConnectError> {
+        let field = |name: &str| attributes.get(name).and_then(|v| v.as_str());
+
+        self.create(
+            app_id,
+            locale,
+            field("description"),
+            field("feedbackEmail"),
+            field("marketingUrl"),
+            field("privacyPolicyUrl"),
+        )
+        .await
+    }
+}
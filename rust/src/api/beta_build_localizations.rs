@@ -0,0 +1,150 @@
+use crate::base::BaseAPI;
+use crate::bulk::{BulkAction, BulkResult};
+use crate::error::AppStoreConnectError;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct BetaBuildLocalizationsAPI {
+    base: BaseAPI,
+}
+
+impl BetaBuildLocalizationsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, build_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("builds/{}/betaBuildLocalizations", build_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get(&self, localization_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("betaBuildLocalizations/{}", localization_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn create(
+        &self,
+        build_id: &str,
+        locale: &str,
+        whats_new: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "betaBuildLocalizations",
+                "attributes": {
+                    "locale": locale,
+                    "whatsNew": whats_new
+                },
+                "relationships": {
+                    "build": {
+                        "data": {
+                            "type": "builds",
+                            "id": build_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("betaBuildLocalizations", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        localization_id: &str,
+        whats_new: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "betaBuildLocalizations",
+                "id": localization_id,
+                "attributes": {
+                    "whatsNew": whats_new
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("betaBuildLocalizations/{}", localization_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, localization_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("betaBuildLocalizations/{}", localization_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn bulk_update(
+        &self,
+        build_id: &str,
+        whats_new: HashMap<String, String>,
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
+        let existing = self.get_all(build_id).await?;
+        let mut existing_by_locale = HashMap::new();
+
+        for loc in existing {
+            if let (Some(locale), Some(id)) = (
+                loc.get("attributes")
+                    .and_then(|a| a.get("locale"))
+                    .and_then(|l| l.as_str()),
+                loc.get("id").and_then(|i| i.as_str()),
+            ) {
+                existing_by_locale.insert(locale.to_string(), id.to_string());
+            }
+        }
+
+        let mut results = Vec::new();
+
+        for (locale, notes) in whats_new {
+            let result = if let Some(localization_id) = existing_by_locale.get(&locale) {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Updated,
+                    self.update(localization_id, &notes).await,
+                )
+            } else {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Created,
+                    self.create(build_id, &locale, &notes).await,
+                )
+            };
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::base::BaseAPI;
+use crate::error::AppStoreConnectError;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub fn screenshot_urls(submission: &Value) -> Vec<String> {
+    submission
+        .get("attributes")
+        .and_then(|a| a.get("screenshots"))
+        .and_then(|s| s.as_array())
+        .map(|screenshots| {
+            screenshots
+                .iter()
+                .filter_map(|s| s.get("url").and_then(|u| u.as_str()))
+                .map(|url| url.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[derive(Clone)]
+pub struct BetaFeedbackAPI {
+    base: BaseAPI,
+}
+
+impl BetaFeedbackAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_crash_submissions(
+        &self,
+        app_id: &str,
+        build_id: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "build,tester".to_string());
+        params.insert("sort".to_string(), "-createdDate".to_string());
+
+        if let Some(build_id) = build_id {
+            params.insert("filter[build]".to_string(), build_id.to_string());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!("apps/{}/betaFeedbackCrashSubmissions", app_id),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_crash_submission(
+        &self,
+        submission_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("betaFeedbackCrashSubmissions/{}", submission_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_crash_log(&self, submission_id: &str) -> Result<String, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("betaFeedbackCrashSubmissions/{}/crashLog", submission_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .and_then(|d| d.get("attributes"))
+            .and_then(|a| a.get("logText"))
+            .and_then(|t| t.as_str())
+            .map(|t| t.to_string())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete_crash_submission(
+        &self,
+        submission_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("betaFeedbackCrashSubmissions/{}", submission_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_screenshot_submissions(
+        &self,
+        app_id: &str,
+        build_id: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "build,tester".to_string());
+        params.insert("sort".to_string(), "-createdDate".to_string());
+
+        if let Some(build_id) = build_id {
+            params.insert("filter[build]".to_string(), build_id.to_string());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!("apps/{}/betaFeedbackScreenshotSubmissions", app_id),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_screenshot_submission(
+        &self,
+        submission_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("betaFeedbackScreenshotSubmissions/{}", submission_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete_screenshot_submission(
+        &self,
+        submission_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!(
+                "betaFeedbackScreenshotSubmissions/{}",
+                submission_id
+            ))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn download_screenshots(
+        &self,
+        submission: &Value,
+        directory: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>, AppStoreConnectError> {
+        let directory = directory.as_ref();
+        let submission_id = submission
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid feedback submission ID".to_string(),
+            })?;
+
+        std::fs::create_dir_all(directory)?;
+        let mut paths = Vec::new();
+
+        for (index, url) in screenshot_urls(submission).iter().enumerate() {
+            let bytes = self.base.download(url).await?;
+            let path = directory.join(format!("{}-{}.png", submission_id, index + 1));
+            std::fs::write(&path, bytes)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+}
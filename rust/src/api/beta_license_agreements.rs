@@ -0,0 +1,128 @@
+use crate::base::BaseAPI;
+use crate::error::AppStoreConnectError;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct BetaLicenseAgreementsAPI {
+    base: BaseAPI,
+}
+
+impl BetaLicenseAgreementsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages("betaLicenseAgreements", None, None)
+            .await
+    }
+
+    pub async fn get_for_app(&self, app_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("apps/{}/betaLicenseAgreement", app_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get(&self, agreement_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("betaLicenseAgreements/{}", agreement_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        agreement_id: &str,
+        agreement_text: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "betaLicenseAgreements",
+                "id": agreement_id,
+                "attributes": {
+                    "agreementText": agreement_text
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("betaLicenseAgreements/{}", agreement_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update_for_app(
+        &self,
+        app_id: &str,
+        agreement_text: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let agreement = self.get_for_app(app_id).await?;
+
+        if agreement
+            .get("attributes")
+            .and_then(|a| a.get("agreementText"))
+            .and_then(|t| t.as_str())
+            == Some(agreement_text)
+        {
+            return Ok(agreement);
+        }
+
+        let agreement_id = agreement
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid beta license agreement ID".to_string(),
+            })?;
+
+        self.update(agreement_id, agreement_text).await
+    }
+
+    pub async fn sync_to_apps(
+        &self,
+        app_ids: &[&str],
+        agreement_text: &str,
+    ) -> Result<HashMap<String, Value>, AppStoreConnectError> {
+        let mut results = HashMap::new();
+
+        for app_id in app_ids {
+            let result = match self.update_for_app(app_id, agreement_text).await {
+                Ok(data) => json!({
+                    "success": true,
+                    "data": data
+                }),
+                Err(e) => json!({
+                    "success": false,
+                    "error": e.to_string()
+                }),
+            };
+
+            results.insert(app_id.to_string(), result);
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,263 @@
+use crate::base::{linkages, BaseAPI};
+use crate::error::AppStoreConnectError;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TesterUsage {
+    pub tester_id: String,
+    pub sessions: u64,
+    pub crashes: u64,
+    pub feedback: u64,
+}
+
+pub fn summarize_tester_usage(metrics: &[Value]) -> Vec<TesterUsage> {
+    let mut usage: HashMap<String, TesterUsage> = HashMap::new();
+
+    for metric in metrics {
+        let dimension = metric
+            .get("dimensions")
+            .and_then(|d| d.get("betaTesters"))
+            .and_then(|t| t.get("data"));
+        let tester_id = match dimension {
+            Some(Value::String(id)) => id.clone(),
+            Some(data) => match data.get("id").and_then(|i| i.as_str()) {
+                Some(id) => id.to_string(),
+                None => continue,
+            },
+            None => continue,
+        };
+
+        let entry = usage
+            .entry(tester_id.clone())
+            .or_insert_with(|| TesterUsage {
+                tester_id,
+                ..Default::default()
+            });
+
+        let points = metric.get("dataPoints").and_then(|p| p.as_array());
+        for point in points.into_iter().flatten() {
+            let count = |name: &str| {
+                point
+                    .get("values")
+                    .and_then(|v| v.get(name))
+                    .and_then(|c| c.as_u64())
+                    .unwrap_or(0)
+            };
+            entry.sessions += count("sessionCount");
+            entry.crashes += count("crashCount");
+            entry.feedback += count("feedbackCount");
+        }
+    }
+
+    let mut usage: Vec<TesterUsage> = usage.into_values().collect();
+    usage.sort_by(|a, b| a.tester_id.cmp(&b.tester_id));
+    usage
+}
+
+#[derive(Clone)]
+pub struct BetaTestersAPI {
+    base: BaseAPI,
+}
+
+impl BetaTestersAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, app_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[apps]".to_string(), app_id.to_string());
+
+        self.base
+            .get_all_pages("betaTesters", Some(params), None)
+            .await
+    }
+
+    pub async fn get_for_group(&self, group_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(&format!("betaGroups/{}/betaTesters", group_id), None, None)
+            .await
+    }
+
+    pub async fn get(&self, tester_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("betaTesters/{}", tester_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, tester_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("betaTesters/{}", tester_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn remove_from_app(
+        &self,
+        tester_id: &str,
+        app_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        let data = json!({ "data": linkages("apps", &[app_id]) });
+
+        self.base
+            .delete_with_data(
+                &format!("betaTesters/{}/relationships/apps", tester_id),
+                data,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn remove_from_group(
+        &self,
+        group_id: &str,
+        tester_ids: &[&str],
+    ) -> Result<(), AppStoreConnectError> {
+        let data = json!({ "data": linkages("betaTesters", tester_ids) });
+
+        self.base
+            .delete_with_data(
+                &format!("betaGroups/{}/relationships/betaTesters", group_id),
+                data,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_app_usage(
+        &self,
+        app_id: &str,
+        period: &str,
+    ) -> Result<Vec<TesterUsage>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("groupBy".to_string(), "betaTesters".to_string());
+        params.insert("period".to_string(), period.to_string());
+
+        let metrics = self
+            .base
+            .get_all_pages(
+                &format!("apps/{}/metrics/betaTesterUsages", app_id),
+                Some(params),
+                None,
+            )
+            .await?;
+
+        Ok(summarize_tester_usage(&metrics))
+    }
+
+    pub async fn get_group_usage(
+        &self,
+        group_id: &str,
+        period: &str,
+    ) -> Result<Vec<TesterUsage>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("groupBy".to_string(), "betaTesters".to_string());
+        params.insert("period".to_string(), period.to_string());
+
+        let metrics = self
+            .base
+            .get_all_pages(
+                &format!("betaGroups/{}/metrics/betaTesterUsages", group_id),
+                Some(params),
+                None,
+            )
+            .await?;
+
+        Ok(summarize_tester_usage(&metrics))
+    }
+
+    pub async fn get_tester_usage(
+        &self,
+        tester_id: &str,
+        app_id: &str,
+        period: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[apps]".to_string(), app_id.to_string());
+        params.insert("period".to_string(), period.to_string());
+
+        let response = self
+            .base
+            .get(
+                &format!("betaTesters/{}/metrics/betaTesterUsages", tester_id),
+                Some(params),
+            )
+            .await?;
+
+        Ok(response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    pub async fn get_build_usage(
+        &self,
+        build_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("builds/{}/metrics/betaBuildUsages", build_id),
+                None,
+            )
+            .await?;
+
+        Ok(response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    pub async fn find_inactive(
+        &self,
+        app_id: &str,
+        period: &str,
+        min_sessions: u64,
+    ) -> Result<Vec<String>, AppStoreConnectError> {
+        let testers = self.get_all(app_id).await?;
+        let active: HashSet<String> = self
+            .get_app_usage(app_id, period)
+            .await?
+            .into_iter()
+            .filter(|u| u.sessions >= min_sessions.max(1))
+            .map(|u| u.tester_id)
+            .collect();
+
+        Ok(testers
+            .iter()
+            .filter_map(|t| t.get("id").and_then(|i| i.as_str()))
+            .filter(|id| !active.contains(*id))
+            .map(|id| id.to_string())
+            .collect())
+    }
+
+    pub async fn prune_inactive(
+        &self,
+        app_id: &str,
+        period: &str,
+        min_sessions: u64,
+        dry_run: bool,
+    ) -> Result<Vec<String>, AppStoreConnectError> {
+        let inactive = self.find_inactive(app_id, period, min_sessions).await?;
+
+        if !dry_run {
+            for tester_id in &inactive {
+                self.remove_from_app(tester_id, app_id).await?;
+            }
+        }
+
+        Ok(inactive)
+    }
+}
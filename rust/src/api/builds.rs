@@ -0,0 +1,293 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, InvalidBinaryError};
+use crate::polling::{poll_until, PollOptions};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpirePolicy {
+    KeepLatest(usize),
+    OlderThan(chrono::Duration),
+}
+
+pub fn select_builds_to_expire(
+    builds: &[Value],
+    policy: ExpirePolicy,
+    now: DateTime<Utc>,
+) -> Vec<Value> {
+    let uploaded = |build: &Value| {
+        build
+            .get("attributes")
+            .and_then(|a| a.get("uploadedDate"))
+            .and_then(|d| d.as_str())
+            .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+            .map(|d| d.with_timezone(&Utc))
+    };
+
+    let mut dated: Vec<(DateTime<Utc>, &Value)> = builds
+        .iter()
+        .filter_map(|build| uploaded(build).map(|date| (date, build)))
+        .collect();
+    dated.sort_by_key(|(date, _)| std::cmp::Reverse(*date));
+
+    match policy {
+        ExpirePolicy::KeepLatest(n) => dated
+            .into_iter()
+            .skip(n)
+            .map(|(_, build)| build.clone())
+            .collect(),
+        ExpirePolicy::OlderThan(age) => dated
+            .into_iter()
+            .filter(|(date, _)| *date < now - age)
+            .map(|(_, build)| build.clone())
+            .collect(),
+    }
+}
+
+#[derive(Clone)]
+pub struct BuildsAPI {
+    base: BaseAPI,
+}
+
+impl BuildsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get(&self, build_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self.base.get(&format!("builds/{}", build_id), None).await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_all(
+        &self,
+        app_id: &str,
+        version: Option<&str>,
+        processing_state: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[app]".to_string(), app_id.to_string());
+        params.insert("sort".to_string(), "-uploadedDate".to_string());
+
+        if let Some(version) = version {
+            params.insert(
+                "filter[preReleaseVersion.version]".to_string(),
+                version.to_string(),
+            );
+        }
+        if let Some(state) = processing_state {
+            params.insert("filter[processingState]".to_string(), state.to_string());
+        }
+
+        self.base.get_all_pages("builds", Some(params), None).await
+    }
+
+    pub async fn find(
+        &self,
+        app_id: &str,
+        version: &str,
+        build_number: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[app]".to_string(), app_id.to_string());
+        params.insert(
+            "filter[preReleaseVersion.version]".to_string(),
+            version.to_string(),
+        );
+        params.insert("filter[version]".to_string(), build_number.to_string());
+        params.insert("limit".to_string(), "1".to_string());
+
+        let response = self.base.get("builds", Some(params)).await?;
+
+        Ok(response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .and_then(|builds| builds.first())
+            .cloned())
+    }
+
+    pub async fn get_processing_state(
+        &self,
+        build_id: &str,
+    ) -> Result<String, AppStoreConnectError> {
+        let build = self.get(build_id).await?;
+
+        build
+            .get("attributes")
+            .and_then(|a| a.get("processingState"))
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Build is missing a processing state".to_string(),
+            })
+    }
+
+    pub async fn wait_for_processing(
+        &self,
+        app_id: &str,
+        version: &str,
+        build_number: &str,
+        timeout: Duration,
+    ) -> Result<Value, AppStoreConnectError> {
+        let options = PollOptions::new(timeout, Duration::from_secs(30));
+        let description = format!("build {} ({}) to finish processing", version, build_number);
+
+        poll_until(&description, &options, || async {
+            let build = match self.find(app_id, version, build_number).await? {
+                Some(build) => build,
+                None => return Ok(None),
+            };
+
+            let state = build
+                .get("attributes")
+                .and_then(|a| a.get("processingState"))
+                .and_then(|s| s.as_str())
+                .unwrap_or("PROCESSING");
+
+            match state {
+                "VALID" => Ok(Some(build)),
+                "PROCESSING" => Ok(None),
+                other => Err(AppStoreConnectError::InvalidBinary(
+                    InvalidBinaryError::new(version, build_number, other),
+                )),
+            }
+        })
+        .await
+    }
+
+    pub async fn get_beta_detail(&self, build_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("builds/{}/buildBetaDetail", build_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update_beta_detail(
+        &self,
+        beta_detail_id: &str,
+        auto_notify_enabled: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "buildBetaDetails",
+                "id": beta_detail_id,
+                "attributes": {
+                    "autoNotifyEnabled": auto_notify_enabled
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("buildBetaDetails/{}", beta_detail_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn set_auto_notify(
+        &self,
+        build_id: &str,
+        enabled: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let detail = self.get_beta_detail(build_id).await?;
+        let detail_id =
+            detail
+                .get("id")
+                .and_then(|i| i.as_str())
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: "Invalid build beta detail ID".to_string(),
+                })?;
+
+        self.update_beta_detail(detail_id, enabled).await
+    }
+
+    pub async fn get_beta_states(
+        &self,
+        build_id: &str,
+    ) -> Result<(String, String), AppStoreConnectError> {
+        let detail = self.get_beta_detail(build_id).await?;
+        let state = |name: &str| {
+            detail
+                .get("attributes")
+                .and_then(|a| a.get(name))
+                .and_then(|s| s.as_str())
+                .unwrap_or("UNKNOWN")
+                .to_string()
+        };
+
+        Ok((state("internalBuildState"), state("externalBuildState")))
+    }
+
+    pub async fn expire(&self, build_id: &str) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "builds",
+                "id": build_id,
+                "attributes": {
+                    "expired": true
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("builds/{}", build_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn expire_older_than(
+        &self,
+        app_id: &str,
+        policy: ExpirePolicy,
+        dry_run: bool,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[app]".to_string(), app_id.to_string());
+        params.insert("filter[expired]".to_string(), "false".to_string());
+        params.insert("sort".to_string(), "-uploadedDate".to_string());
+
+        let builds = self
+            .base
+            .get_all_pages("builds", Some(params), None)
+            .await?;
+        let stale = select_builds_to_expire(&builds, policy, Utc::now());
+
+        if !dry_run {
+            for build in &stale {
+                if let Some(build_id) = build.get("id").and_then(|i| i.as_str()) {
+                    self.expire(build_id).await?;
+                }
+            }
+        }
+
+        Ok(stale)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BundleIdPlatform {
+    Ios,
+    MacOs,
+    Universal,
+}
+
+impl BundleIdPlatform {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BundleIdPlatform::Ios => "IOS",
+            BundleIdPlatform::MacOs => "MAC_OS",
+            BundleIdPlatform::Universal => "UNIVERSAL",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "IOS" => Some(BundleIdPlatform::Ios),
+            "MAC_OS" => Some(BundleIdPlatform::MacOs),
+            "UNIVERSAL" => Some(BundleIdPlatform::Universal),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleId {
+    pub id: String,
+    pub identifier: String,
+    pub name: Option<String>,
+    pub platform: Option<BundleIdPlatform>,
+    pub seed_id: Option<String>,
+}
+
+impl BundleId {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            identifier: string_attribute(attributes, "identifier").unwrap_or_default(),
+            name: string_attribute(attributes, "name"),
+            platform: string_attribute(attributes, "platform")
+                .as_deref()
+                .and_then(BundleIdPlatform::parse),
+            seed_id: string_attribute(attributes, "seedId"),
+        })
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        self.identifier.ends_with('*')
+    }
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+// Reverse-DNS made of letters, digits and hyphens. `*` or a trailing `.*`
+// registers a wildcard ID (e.g. `com.example.*`).
+pub fn validate_bundle_identifier(identifier: &str) -> Result<(), AppStoreConnectError> {
+    let explicit = identifier.strip_suffix(".*").unwrap_or(identifier);
+
+    let valid = identifier == "*"
+        || explicit.split('.').all(|part| {
+            !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(AppStoreConnectError::Validation(ValidationError::new(
+            format!(
+                "'{}' is not a valid bundle identifier; use reverse-DNS such as com.example.app",
+                identifier
+            ),
+        )))
+    }
+}
+
+#[derive(Clone)]
+pub struct BundleIdsAPI {
+    base: BaseAPI,
+}
+
+impl BundleIdsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn register(
+        &self,
+        identifier: &str,
+        name: &str,
+        platform: BundleIdPlatform,
+    ) -> Result<BundleId, AppStoreConnectError> {
+        validate_bundle_identifier(identifier)?;
+
+        let data = json!({
+            "data": {
+                "type": "bundleIds",
+                "attributes": {
+                    "identifier": identifier,
+                    "name": name,
+                    "platform": platform.as_str()
+                }
+            }
+        });
+
+        let response = self.base.post("bundleIds", data).await?;
+        response
+            .get("data")
+            .and_then(BundleId::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_all(
+        &self,
+        identifier: Option<&str>,
+        platform: Option<BundleIdPlatform>,
+    ) -> Result<Vec<BundleId>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if let Some(identifier) = identifier {
+            params.insert("filter[identifier]".to_string(), identifier.to_string());
+        }
+        if let Some(platform) = platform {
+            params.insert(
+                "filter[platform]".to_string(),
+                platform.as_str().to_string(),
+            );
+        }
+
+        let bundle_ids = self
+            .base
+            .get_all_pages("bundleIds", Some(params), None)
+            .await?;
+
+        Ok(bundle_ids.iter().filter_map(BundleId::from_value).collect())
+    }
+
+    pub async fn get(&self, bundle_id: &str) -> Result<BundleId, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("bundleIds/{}", bundle_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(BundleId::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    // filter[identifier] is a prefix match on Apple's side, so narrow it
+    // down to the exact identifier here.
+    pub async fn find_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<Option<BundleId>, AppStoreConnectError> {
+        let bundle_ids = self.get_all(Some(identifier), None).await?;
+        Ok(bundle_ids
+            .into_iter()
+            .find(|bundle_id| bundle_id.identifier == identifier))
+    }
+
+    pub async fn rename(
+        &self,
+        bundle_id: &str,
+        name: &str,
+    ) -> Result<BundleId, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "bundleIds",
+                "id": bundle_id,
+                "attributes": {
+                    "name": name
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("bundleIds/{}", bundle_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(BundleId::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, bundle_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("bundleIds/{}", bundle_id))
+            .await?;
+        Ok(())
+    }
+}
@@ -108,6 +108,7 @@ impl CategoriesAPI {
         Ok(result)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_app_categories(
         &self,
         app_info_id: &str,
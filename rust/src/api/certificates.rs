@@ -0,0 +1,344 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CertificateType {
+    Development,
+    Distribution,
+    IosDevelopment,
+    IosDistribution,
+    MacAppDevelopment,
+    MacAppDistribution,
+    MacInstallerDistribution,
+    DeveloperIdApplication,
+    DeveloperIdApplicationG2,
+    DeveloperIdKext,
+    PassTypeId,
+    PassTypeIdWithNfc,
+}
+
+impl CertificateType {
+    pub const ALL: [CertificateType; 12] = [
+        CertificateType::Development,
+        CertificateType::Distribution,
+        CertificateType::IosDevelopment,
+        CertificateType::IosDistribution,
+        CertificateType::MacAppDevelopment,
+        CertificateType::MacAppDistribution,
+        CertificateType::MacInstallerDistribution,
+        CertificateType::DeveloperIdApplication,
+        CertificateType::DeveloperIdApplicationG2,
+        CertificateType::DeveloperIdKext,
+        CertificateType::PassTypeId,
+        CertificateType::PassTypeIdWithNfc,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CertificateType::Development => "DEVELOPMENT",
+            CertificateType::Distribution => "DISTRIBUTION",
+            CertificateType::IosDevelopment => "IOS_DEVELOPMENT",
+            CertificateType::IosDistribution => "IOS_DISTRIBUTION",
+            CertificateType::MacAppDevelopment => "MAC_APP_DEVELOPMENT",
+            CertificateType::MacAppDistribution => "MAC_APP_DISTRIBUTION",
+            CertificateType::MacInstallerDistribution => "MAC_INSTALLER_DISTRIBUTION",
+            CertificateType::DeveloperIdApplication => "DEVELOPER_ID_APPLICATION",
+            CertificateType::DeveloperIdApplicationG2 => "DEVELOPER_ID_APPLICATION_G2",
+            CertificateType::DeveloperIdKext => "DEVELOPER_ID_KEXT",
+            CertificateType::PassTypeId => "PASS_TYPE_ID",
+            CertificateType::PassTypeIdWithNfc => "PASS_TYPE_ID_WITH_NFC",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Certificate {
+    pub id: String,
+    pub name: Option<String>,
+    pub display_name: Option<String>,
+    pub certificate_type: Option<CertificateType>,
+    pub platform: Option<String>,
+    pub serial_number: Option<String>,
+    pub expiration_date: Option<String>,
+    pub certificate_content: Option<String>,
+}
+
+impl Certificate {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: string_attribute(attributes, "name"),
+            display_name: string_attribute(attributes, "displayName"),
+            certificate_type: string_attribute(attributes, "certificateType")
+                .as_deref()
+                .and_then(CertificateType::parse),
+            platform: string_attribute(attributes, "platform"),
+            serial_number: string_attribute(attributes, "serialNumber"),
+            expiration_date: string_attribute(attributes, "expirationDate"),
+            certificate_content: string_attribute(attributes, "certificateContent"),
+        })
+    }
+
+    pub fn der(&self) -> Result<Vec<u8>, AppStoreConnectError> {
+        let content =
+            self.certificate_content
+                .as_deref()
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: format!("Certificate {} has no certificateContent", self.id),
+                })?;
+
+        STANDARD
+            .decode(content.trim())
+            .map_err(|e| AppStoreConnectError::Api {
+                message: format!("Certificate {} content is not base64: {}", self.id, e),
+            })
+    }
+
+    pub fn info(&self) -> Result<CertificateInfo, AppStoreConnectError> {
+        decode_certificate(&self.der()?)
+    }
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertificateInfo {
+    pub serial_number: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+}
+
+impl CertificateInfo {
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.not_after <= now
+    }
+}
+
+// Reads one DER tag-length-value at `offset`, returning the tag, the value
+// bytes and the offset just past it.
+fn read_der(data: &[u8], offset: usize) -> Option<(u8, &[u8], usize)> {
+    let tag = *data.get(offset)?;
+    let first = *data.get(offset + 1)? as usize;
+    let mut start = offset + 2;
+
+    let length = if first < 0x80 {
+        first
+    } else {
+        let count = first & 0x7F;
+        if count == 0 || count > 4 {
+            return None;
+        }
+        let bytes = data.get(start..start + count)?;
+        start += count;
+        bytes.iter().fold(0usize, |acc, b| (acc << 8) | *b as usize)
+    };
+
+    let value = data.get(start..start.checked_add(length)?)?;
+    Some((tag, value, start + length))
+}
+
+fn der_time(tag: u8, value: &[u8]) -> Option<DateTime<Utc>> {
+    let text = std::str::from_utf8(value).ok()?;
+    let parsed = match tag {
+        0x17 => NaiveDateTime::parse_from_str(text, "%y%m%d%H%M%SZ").ok()?,
+        0x18 => NaiveDateTime::parse_from_str(text, "%Y%m%d%H%M%SZ").ok()?,
+        _ => return None,
+    };
+    Some(parsed.and_utc())
+}
+
+// Pulls the serial number and validity window out of an X.509 certificate
+// without a full ASN.1 parser: Certificate -> TBSCertificate -> [version],
+// serialNumber, signature, issuer, validity.
+pub fn decode_certificate(der: &[u8]) -> Result<CertificateInfo, AppStoreConnectError> {
+    let invalid = || {
+        AppStoreConnectError::Validation(ValidationError::new(
+            "Certificate content is not a DER encoded X.509 certificate",
+        ))
+    };
+
+    let (_, certificate, _) = read_der(der, 0)
+        .filter(|(tag, ..)| *tag == 0x30)
+        .ok_or_else(invalid)?;
+    let (_, tbs, _) = read_der(certificate, 0)
+        .filter(|(tag, ..)| *tag == 0x30)
+        .ok_or_else(invalid)?;
+
+    let (mut tag, mut value, mut offset) = read_der(tbs, 0).ok_or_else(invalid)?;
+    if tag == 0xA0 {
+        (tag, value, offset) = read_der(tbs, offset).ok_or_else(invalid)?;
+    }
+    if tag != 0x02 {
+        return Err(invalid());
+    }
+    let serial: String = value
+        .iter()
+        .skip_while(|b| **b == 0)
+        .map(|b| format!("{:02X}", b))
+        .collect();
+
+    let (_, _, offset) = read_der(tbs, offset).ok_or_else(invalid)?;
+    let (_, _, offset) = read_der(tbs, offset).ok_or_else(invalid)?;
+    let (_, validity, _) = read_der(tbs, offset)
+        .filter(|(tag, ..)| *tag == 0x30)
+        .ok_or_else(invalid)?;
+
+    let (tag, value, next) = read_der(validity, 0).ok_or_else(invalid)?;
+    let not_before = der_time(tag, value).ok_or_else(invalid)?;
+    let (tag, value, _) = read_der(validity, next).ok_or_else(invalid)?;
+    let not_after = der_time(tag, value).ok_or_else(invalid)?;
+
+    Ok(CertificateInfo {
+        serial_number: if serial.is_empty() {
+            "00".to_string()
+        } else {
+            serial
+        },
+        not_before,
+        not_after,
+    })
+}
+
+// Accepts either a PEM certificate signing request or its bare base64 body.
+pub fn csr_content(csr: &str) -> Result<String, AppStoreConnectError> {
+    let body: String = csr
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("-----"))
+        .collect();
+
+    if body.is_empty() || STANDARD.decode(&body).is_err() {
+        return Err(AppStoreConnectError::Validation(ValidationError::new(
+            "CSR must be a PEM or base64 encoded certificate signing request",
+        )));
+    }
+
+    Ok(body)
+}
+
+#[derive(Clone)]
+pub struct CertificatesAPI {
+    base: BaseAPI,
+}
+
+impl CertificatesAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn create(
+        &self,
+        csr: &str,
+        certificate_type: CertificateType,
+    ) -> Result<Certificate, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "certificates",
+                "attributes": {
+                    "csrContent": csr_content(csr)?,
+                    "certificateType": certificate_type.as_str()
+                }
+            }
+        });
+
+        let response = self.base.post("certificates", data).await?;
+        response
+            .get("data")
+            .and_then(Certificate::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn create_from_file(
+        &self,
+        csr_path: impl AsRef<Path>,
+        certificate_type: CertificateType,
+    ) -> Result<Certificate, AppStoreConnectError> {
+        let csr = tokio::fs::read_to_string(csr_path).await?;
+        self.create(&csr, certificate_type).await
+    }
+
+    pub async fn get_all(
+        &self,
+        certificate_types: &[CertificateType],
+        display_name: Option<&str>,
+        serial_number: Option<&str>,
+    ) -> Result<Vec<Certificate>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if !certificate_types.is_empty() {
+            let types: Vec<&str> = certificate_types.iter().map(|t| t.as_str()).collect();
+            params.insert("filter[certificateType]".to_string(), types.join(","));
+        }
+        if let Some(display_name) = display_name {
+            params.insert("filter[displayName]".to_string(), display_name.to_string());
+        }
+        if let Some(serial_number) = serial_number {
+            params.insert(
+                "filter[serialNumber]".to_string(),
+                serial_number.to_string(),
+            );
+        }
+
+        let certificates = self
+            .base
+            .get_all_pages("certificates", Some(params), None)
+            .await?;
+
+        Ok(certificates
+            .iter()
+            .filter_map(Certificate::from_value)
+            .collect())
+    }
+
+    pub async fn get(&self, certificate_id: &str) -> Result<Certificate, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("certificates/{}", certificate_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(Certificate::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn download(
+        &self,
+        certificate_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<CertificateInfo, AppStoreConnectError> {
+        let der = self.get(certificate_id).await?.der()?;
+        let info = decode_certificate(&der)?;
+        tokio::fs::write(path, der).await?;
+        Ok(info)
+    }
+
+    pub async fn revoke(&self, certificate_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("certificates/{}", certificate_id))
+            .await?;
+        Ok(())
+    }
+}
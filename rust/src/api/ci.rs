@@ -0,0 +1,619 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use crate::polling::{poll_until, PollOptions};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CiExecutionProgress {
+    Pending,
+    Running,
+    Complete,
+}
+
+impl CiExecutionProgress {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "PENDING" => Some(CiExecutionProgress::Pending),
+            "RUNNING" => Some(CiExecutionProgress::Running),
+            "COMPLETE" => Some(CiExecutionProgress::Complete),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CiCompletionStatus {
+    Succeeded,
+    Failed,
+    Errored,
+    Canceled,
+    Skipped,
+}
+
+impl CiCompletionStatus {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "SUCCEEDED" => Some(CiCompletionStatus::Succeeded),
+            "FAILED" => Some(CiCompletionStatus::Failed),
+            "ERRORED" => Some(CiCompletionStatus::Errored),
+            "CANCELED" => Some(CiCompletionStatus::Canceled),
+            "SKIPPED" => Some(CiCompletionStatus::Skipped),
+            _ => None,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, CiCompletionStatus::Succeeded)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiProduct {
+    pub id: String,
+    pub name: Option<String>,
+    pub product_type: Option<String>,
+    pub created_date: Option<String>,
+}
+
+impl CiProduct {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: string_attribute(attributes, "name"),
+            product_type: string_attribute(attributes, "productType"),
+            created_date: string_attribute(attributes, "createdDate"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiWorkflow {
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub is_enabled: bool,
+    pub is_locked_for_editing: bool,
+    pub last_modified_date: Option<String>,
+}
+
+impl CiWorkflow {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: string_attribute(attributes, "name"),
+            description: string_attribute(attributes, "description"),
+            is_enabled: bool_attribute(attributes, "isEnabled"),
+            is_locked_for_editing: bool_attribute(attributes, "isLockedForEditing"),
+            last_modified_date: string_attribute(attributes, "lastModifiedDate"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiBuildRun {
+    pub id: String,
+    pub number: Option<u64>,
+    pub execution_progress: Option<CiExecutionProgress>,
+    pub completion_status: Option<CiCompletionStatus>,
+    pub start_reason: Option<String>,
+    pub source_commit: Option<String>,
+    pub created_date: Option<String>,
+    pub started_date: Option<String>,
+    pub finished_date: Option<String>,
+}
+
+impl CiBuildRun {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            number: attributes
+                .and_then(|a| a.get("number"))
+                .and_then(|n| n.as_u64()),
+            execution_progress: string_attribute(attributes, "executionProgress")
+                .as_deref()
+                .and_then(CiExecutionProgress::parse),
+            completion_status: string_attribute(attributes, "completionStatus")
+                .as_deref()
+                .and_then(CiCompletionStatus::parse),
+            start_reason: string_attribute(attributes, "startReason"),
+            source_commit: attributes
+                .and_then(|a| a.pointer("/sourceCommit/commitSha"))
+                .and_then(|sha| sha.as_str())
+                .map(|sha| sha.to_string()),
+            created_date: string_attribute(attributes, "createdDate"),
+            started_date: string_attribute(attributes, "startedDate"),
+            finished_date: string_attribute(attributes, "finishedDate"),
+        })
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.execution_progress == Some(CiExecutionProgress::Complete)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiBuildAction {
+    pub id: String,
+    pub name: Option<String>,
+    pub action_type: Option<String>,
+    pub execution_progress: Option<CiExecutionProgress>,
+    pub completion_status: Option<CiCompletionStatus>,
+    pub is_required_to_pass: bool,
+}
+
+impl CiBuildAction {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: string_attribute(attributes, "name"),
+            action_type: string_attribute(attributes, "actionType"),
+            execution_progress: string_attribute(attributes, "executionProgress")
+                .as_deref()
+                .and_then(CiExecutionProgress::parse),
+            completion_status: string_attribute(attributes, "completionStatus")
+                .as_deref()
+                .and_then(CiCompletionStatus::parse),
+            is_required_to_pass: bool_attribute(attributes, "isRequiredToPass"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiArtifact {
+    pub id: String,
+    pub file_type: Option<String>,
+    pub file_name: Option<String>,
+    pub file_size: Option<u64>,
+    pub download_url: Option<String>,
+}
+
+impl CiArtifact {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            file_type: string_attribute(attributes, "fileType"),
+            file_name: string_attribute(attributes, "fileName"),
+            file_size: attributes
+                .and_then(|a| a.get("fileSize"))
+                .and_then(|s| s.as_u64()),
+            download_url: string_attribute(attributes, "downloadUrl"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiTestResult {
+    pub id: String,
+    pub class_name: Option<String>,
+    pub name: Option<String>,
+    pub status: Option<String>,
+    pub message: Option<String>,
+}
+
+impl CiTestResult {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            class_name: string_attribute(attributes, "className"),
+            name: string_attribute(attributes, "name"),
+            status: string_attribute(attributes, "status"),
+            message: string_attribute(attributes, "message"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CiTestSummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub other: usize,
+}
+
+impl CiTestSummary {
+    // MIXED means the test failed on at least one destination.
+    pub fn from_results(results: &[CiTestResult]) -> Self {
+        let mut summary = Self::default();
+        for result in results {
+            match result.status.as_deref() {
+                Some("SUCCESS") | Some("EXPECTED_FAILURE") => summary.passed += 1,
+                Some("FAILURE") | Some("MIXED") => summary.failed += 1,
+                Some("SKIPPED") => summary.skipped += 1,
+                _ => summary.other += 1,
+            }
+        }
+        summary
+    }
+
+    pub fn total(&self) -> usize {
+        self.passed + self.failed + self.skipped + self.other
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiGitReference {
+    pub id: String,
+    pub name: Option<String>,
+    pub canonical_name: Option<String>,
+    pub kind: Option<String>,
+    pub is_deleted: bool,
+}
+
+impl CiGitReference {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: string_attribute(attributes, "name"),
+            canonical_name: string_attribute(attributes, "canonicalName"),
+            kind: string_attribute(attributes, "kind"),
+            is_deleted: bool_attribute(attributes, "isDeleted"),
+        })
+    }
+
+    // Matches `main`, `refs/heads/main` or `refs/tags/v1.2.0`.
+    pub fn matches(&self, reference: &str) -> bool {
+        !self.is_deleted
+            && (self.name.as_deref() == Some(reference)
+                || self.canonical_name.as_deref() == Some(reference))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiRunOutcome {
+    pub run: CiBuildRun,
+    pub status: CiCompletionStatus,
+    pub actions: Vec<CiBuildAction>,
+}
+
+impl CiRunOutcome {
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+
+    pub fn failed_actions(&self) -> Vec<&CiBuildAction> {
+        self.actions
+            .iter()
+            .filter(|action| {
+                action.completion_status.is_some_and(|status| {
+                    !status.is_success() && status != CiCompletionStatus::Skipped
+                })
+            })
+            .collect()
+    }
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+fn bool_attribute(attributes: Option<&Value>, key: &str) -> bool {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+#[derive(Clone)]
+pub struct CiAPI {
+    base: BaseAPI,
+}
+
+impl CiAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    async fn get_one<T>(
+        &self,
+        endpoint: &str,
+        parse: fn(&Value) -> Option<T>,
+    ) -> Result<T, AppStoreConnectError> {
+        let response = self.base.get(endpoint, None).await?;
+
+        response
+            .get("data")
+            .and_then(parse)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    async fn get_many<T>(
+        &self,
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+        parse: fn(&Value) -> Option<T>,
+    ) -> Result<Vec<T>, AppStoreConnectError> {
+        let items = self.base.get_all_pages(endpoint, params, None).await?;
+        Ok(items.iter().filter_map(parse).collect())
+    }
+
+    pub async fn get_products(
+        &self,
+        app_id: Option<&str>,
+    ) -> Result<Vec<CiProduct>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if let Some(app_id) = app_id {
+            params.insert("filter[app]".to_string(), app_id.to_string());
+        }
+
+        self.get_many("ciProducts", Some(params), CiProduct::from_value)
+            .await
+    }
+
+    pub async fn get_product(&self, product_id: &str) -> Result<CiProduct, AppStoreConnectError> {
+        self.get_one(&format!("ciProducts/{}", product_id), CiProduct::from_value)
+            .await
+    }
+
+    pub async fn get_workflows(
+        &self,
+        product_id: &str,
+    ) -> Result<Vec<CiWorkflow>, AppStoreConnectError> {
+        self.get_many(
+            &format!("ciProducts/{}/workflows", product_id),
+            None,
+            CiWorkflow::from_value,
+        )
+        .await
+    }
+
+    pub async fn get_workflow(
+        &self,
+        workflow_id: &str,
+    ) -> Result<CiWorkflow, AppStoreConnectError> {
+        self.get_one(
+            &format!("ciWorkflows/{}", workflow_id),
+            CiWorkflow::from_value,
+        )
+        .await
+    }
+
+    pub async fn get_build_runs(
+        &self,
+        workflow_id: &str,
+    ) -> Result<Vec<CiBuildRun>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("sort".to_string(), "-number".to_string());
+
+        self.get_many(
+            &format!("ciWorkflows/{}/buildRuns", workflow_id),
+            Some(params),
+            CiBuildRun::from_value,
+        )
+        .await
+    }
+
+    pub async fn get_product_build_runs(
+        &self,
+        product_id: &str,
+    ) -> Result<Vec<CiBuildRun>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("sort".to_string(), "-number".to_string());
+
+        self.get_many(
+            &format!("ciProducts/{}/buildRuns", product_id),
+            Some(params),
+            CiBuildRun::from_value,
+        )
+        .await
+    }
+
+    pub async fn get_build_run(&self, run_id: &str) -> Result<CiBuildRun, AppStoreConnectError> {
+        self.get_one(&format!("ciBuildRuns/{}", run_id), CiBuildRun::from_value)
+            .await
+    }
+
+    pub async fn get_build_actions(
+        &self,
+        run_id: &str,
+    ) -> Result<Vec<CiBuildAction>, AppStoreConnectError> {
+        self.get_many(
+            &format!("ciBuildRuns/{}/actions", run_id),
+            None,
+            CiBuildAction::from_value,
+        )
+        .await
+    }
+
+    pub async fn get_build_action(
+        &self,
+        action_id: &str,
+    ) -> Result<CiBuildAction, AppStoreConnectError> {
+        self.get_one(
+            &format!("ciBuildActions/{}", action_id),
+            CiBuildAction::from_value,
+        )
+        .await
+    }
+
+    pub async fn get_artifacts(
+        &self,
+        action_id: &str,
+    ) -> Result<Vec<CiArtifact>, AppStoreConnectError> {
+        self.get_many(
+            &format!("ciBuildActions/{}/artifacts", action_id),
+            None,
+            CiArtifact::from_value,
+        )
+        .await
+    }
+
+    pub async fn get_test_results(
+        &self,
+        action_id: &str,
+    ) -> Result<Vec<CiTestResult>, AppStoreConnectError> {
+        self.get_many(
+            &format!("ciBuildActions/{}/testResults", action_id),
+            None,
+            CiTestResult::from_value,
+        )
+        .await
+    }
+
+    pub async fn get_issues(&self, action_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(&format!("ciBuildActions/{}/issues", action_id), None, None)
+            .await
+    }
+
+    pub async fn get_git_references(
+        &self,
+        workflow_id: &str,
+    ) -> Result<Vec<CiGitReference>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("ciWorkflows/{}/repository", workflow_id), None)
+            .await?;
+        let repository_id = response
+            .pointer("/data/id")
+            .and_then(|id| id.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })?;
+
+        self.get_many(
+            &format!("scmRepositories/{}/gitReferences", repository_id),
+            None,
+            CiGitReference::from_value,
+        )
+        .await
+    }
+
+    // Without a git reference Xcode Cloud builds the workflow's default
+    // branch, as it does for a manual start in Xcode.
+    pub async fn start_build(
+        &self,
+        workflow_id: &str,
+        git_reference_id: Option<&str>,
+    ) -> Result<CiBuildRun, AppStoreConnectError> {
+        let mut data = json!({
+            "data": {
+                "type": "ciBuildRuns",
+                "relationships": {
+                    "workflow": {
+                        "data": { "type": "ciWorkflows", "id": workflow_id }
+                    }
+                }
+            }
+        });
+
+        if let Some(reference_id) = git_reference_id {
+            data["data"]["relationships"]["sourceBranchOrTag"] = json!({
+                "data": { "type": "scmGitReferences", "id": reference_id }
+            });
+        }
+
+        let response = self.base.post("ciBuildRuns", data).await?;
+        response
+            .get("data")
+            .and_then(CiBuildRun::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn start_build_on(
+        &self,
+        workflow_id: &str,
+        reference: &str,
+    ) -> Result<CiBuildRun, AppStoreConnectError> {
+        let git_reference = self
+            .get_git_references(workflow_id)
+            .await?
+            .into_iter()
+            .find(|git_reference| git_reference.matches(reference))
+            .ok_or_else(|| {
+                AppStoreConnectError::Validation(ValidationError::new(format!(
+                    "No branch or tag named '{}' in the repository for workflow {}",
+                    reference, workflow_id
+                )))
+            })?;
+
+        self.start_build(workflow_id, Some(&git_reference.id)).await
+    }
+
+    // A run that finishes as FAILED or ERRORED is still returned as Ok;
+    // check `CiRunOutcome::is_success`. Only the timeout is an error.
+    pub async fn wait_for_run(
+        &self,
+        run_id: &str,
+        timeout: Duration,
+    ) -> Result<CiRunOutcome, AppStoreConnectError> {
+        let options = PollOptions::new(timeout, Duration::from_secs(30));
+        let description = format!("Xcode Cloud build run {} to complete", run_id);
+
+        let run = poll_until(&description, &options, || async {
+            let run = self.get_build_run(run_id).await?;
+            Ok(run.is_complete().then_some(run))
+        })
+        .await?;
+
+        let actions = self.get_build_actions(run_id).await?;
+        Ok(CiRunOutcome {
+            status: run.completion_status.unwrap_or(CiCompletionStatus::Errored),
+            run,
+            actions,
+        })
+    }
+
+    pub async fn get_run_artifacts(
+        &self,
+        run_id: &str,
+    ) -> Result<Vec<CiArtifact>, AppStoreConnectError> {
+        let mut artifacts = Vec::new();
+        for action in self.get_build_actions(run_id).await? {
+            artifacts.extend(self.get_artifacts(&action.id).await?);
+        }
+        Ok(artifacts)
+    }
+
+    // Download URLs expire quickly, so the artifact is re-fetched for a
+    // fresh one rather than trusting a URL from an earlier listing.
+    pub async fn download_artifact(
+        &self,
+        artifact_id: &str,
+        dest_dir: impl AsRef<Path>,
+    ) -> Result<PathBuf, AppStoreConnectError> {
+        let artifact = self
+            .get_one(
+                &format!("ciArtifacts/{}", artifact_id),
+                CiArtifact::from_value,
+            )
+            .await?;
+        let url = artifact
+            .download_url
+            .as_deref()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: format!("Artifact {} has no download URL", artifact_id),
+            })?;
+
+        let dest_dir = dest_dir.as_ref();
+        tokio::fs::create_dir_all(dest_dir).await?;
+        let path = dest_dir.join(artifact.file_name.as_deref().unwrap_or(&artifact.id));
+        self.base.download_url_to_file(url, &path).await?;
+        Ok(path)
+    }
+}
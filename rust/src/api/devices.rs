@@ -0,0 +1,377 @@
+use crate::api::bundle_ids::BundleIdPlatform;
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeviceStatus {
+    Enabled,
+    Disabled,
+}
+
+impl DeviceStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeviceStatus::Enabled => "ENABLED",
+            DeviceStatus::Disabled => "DISABLED",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ENABLED" => Some(DeviceStatus::Enabled),
+            "DISABLED" => Some(DeviceStatus::Disabled),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Device {
+    pub id: String,
+    pub name: Option<String>,
+    pub udid: String,
+    pub platform: Option<BundleIdPlatform>,
+    pub status: Option<DeviceStatus>,
+    pub device_class: Option<String>,
+    pub model: Option<String>,
+    pub added_date: Option<String>,
+}
+
+impl Device {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: string_attribute(attributes, "name"),
+            udid: string_attribute(attributes, "udid").unwrap_or_default(),
+            platform: string_attribute(attributes, "platform")
+                .as_deref()
+                .and_then(BundleIdPlatform::parse),
+            status: string_attribute(attributes, "status")
+                .as_deref()
+                .and_then(DeviceStatus::parse),
+            device_class: string_attribute(attributes, "deviceClass"),
+            model: string_attribute(attributes, "model"),
+            added_date: string_attribute(attributes, "addedDate"),
+        })
+    }
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+// Accepts the 40 character legacy UDIDs, the 25 character `00008030-...`
+// form used since the iPhone XS, and Mac provisioning UUIDs.
+pub fn validate_udid(udid: &str) -> Result<(), AppStoreConnectError> {
+    let digits: Vec<char> = udid.chars().filter(|c| *c != '-').collect();
+    let valid =
+        digits.iter().all(|c| c.is_ascii_hexdigit()) && matches!(digits.len(), 24 | 32 | 40);
+
+    if valid {
+        Ok(())
+    } else {
+        Err(AppStoreConnectError::Validation(ValidationError::new(
+            format!("'{}' is not a valid device UDID", udid),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceRegistration {
+    pub name: String,
+    pub udid: String,
+    pub platform: BundleIdPlatform,
+}
+
+impl DeviceRegistration {
+    pub fn new(
+        name: impl Into<String>,
+        udid: impl Into<String>,
+        platform: BundleIdPlatform,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            udid: udid.into(),
+            platform,
+        }
+    }
+}
+
+// Parses the tab separated file the developer portal imports and exports
+// (`Device ID`, `Device Name`, `Device Platform`). Lines with only a UDID
+// are accepted too and named after it; `default_platform` fills in a
+// missing platform column.
+pub fn parse_device_list(
+    content: &str,
+    default_platform: BundleIdPlatform,
+) -> Result<Vec<DeviceRegistration>, AppStoreConnectError> {
+    let mut devices = Vec::new();
+    let mut issues = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("Device ID") {
+            continue;
+        }
+
+        let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+        let udid = columns[0];
+        if let Err(e) = validate_udid(udid) {
+            issues.push(format!("line {}: {}", index + 1, e));
+            continue;
+        }
+
+        let name = columns
+            .get(1)
+            .filter(|name| !name.is_empty())
+            .unwrap_or(&udid);
+        let platform = match columns.get(2).filter(|p| !p.is_empty()) {
+            None => default_platform,
+            Some(&"ios") | Some(&"IOS") => BundleIdPlatform::Ios,
+            Some(&"mac") | Some(&"MAC_OS") => BundleIdPlatform::MacOs,
+            Some(other) => {
+                issues.push(format!("line {}: unknown platform '{}'", index + 1, other));
+                continue;
+            }
+        };
+
+        devices.push(DeviceRegistration::new(*name, udid, platform));
+    }
+
+    if issues.is_empty() {
+        Ok(devices)
+    } else {
+        Err(AppStoreConnectError::Validation(
+            ValidationError::with_issues("Device list is not valid", issues),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeviceRegistrationOutcome {
+    Registered(Device),
+    AlreadyRegistered(Device),
+    Failed(String),
+}
+
+impl fmt::Display for DeviceRegistrationOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceRegistrationOutcome::Registered(_) => write!(f, "registered"),
+            DeviceRegistrationOutcome::AlreadyRegistered(_) => write!(f, "already registered"),
+            DeviceRegistrationOutcome::Failed(error) => write!(f, "failed: {}", error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceRegistrationResult {
+    pub udid: String,
+    pub name: String,
+    pub outcome: DeviceRegistrationOutcome,
+}
+
+impl DeviceRegistrationResult {
+    pub fn is_success(&self) -> bool {
+        !matches!(self.outcome, DeviceRegistrationOutcome::Failed(_))
+    }
+}
+
+#[derive(Clone)]
+pub struct DevicesAPI {
+    base: BaseAPI,
+}
+
+impl DevicesAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn register(
+        &self,
+        name: &str,
+        udid: &str,
+        platform: BundleIdPlatform,
+    ) -> Result<Device, AppStoreConnectError> {
+        validate_udid(udid)?;
+
+        let data = json!({
+            "data": {
+                "type": "devices",
+                "attributes": {
+                    "name": name,
+                    "udid": udid,
+                    "platform": platform.as_str()
+                }
+            }
+        });
+
+        let response = self.base.post("devices", data).await?;
+        response
+            .get("data")
+            .and_then(Device::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_all(
+        &self,
+        status: Option<DeviceStatus>,
+        platform: Option<BundleIdPlatform>,
+        udid: Option<&str>,
+    ) -> Result<Vec<Device>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if let Some(status) = status {
+            params.insert("filter[status]".to_string(), status.as_str().to_string());
+        }
+        if let Some(platform) = platform {
+            params.insert(
+                "filter[platform]".to_string(),
+                platform.as_str().to_string(),
+            );
+        }
+        if let Some(udid) = udid {
+            params.insert("filter[udid]".to_string(), udid.to_string());
+        }
+
+        let devices = self
+            .base
+            .get_all_pages("devices", Some(params), None)
+            .await?;
+
+        Ok(devices.iter().filter_map(Device::from_value).collect())
+    }
+
+    pub async fn get(&self, device_id: &str) -> Result<Device, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("devices/{}", device_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(Device::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        device_id: &str,
+        name: Option<&str>,
+        status: Option<DeviceStatus>,
+    ) -> Result<Device, AppStoreConnectError> {
+        let mut attributes = json!({});
+
+        if let Some(name) = name {
+            attributes["name"] = json!(name);
+        }
+        if let Some(status) = status {
+            attributes["status"] = json!(status.as_str());
+        }
+
+        let data = json!({
+            "data": {
+                "type": "devices",
+                "id": device_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("devices/{}", device_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(Device::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn rename(
+        &self,
+        device_id: &str,
+        name: &str,
+    ) -> Result<Device, AppStoreConnectError> {
+        self.update(device_id, Some(name), None).await
+    }
+
+    pub async fn enable(&self, device_id: &str) -> Result<Device, AppStoreConnectError> {
+        self.update(device_id, None, Some(DeviceStatus::Enabled))
+            .await
+    }
+
+    pub async fn disable(&self, device_id: &str) -> Result<Device, AppStoreConnectError> {
+        self.update(device_id, None, Some(DeviceStatus::Disabled))
+            .await
+    }
+
+    // UDIDs already on the team are reported rather than re-registered, so
+    // the same list can be re-run safely. Failures don't stop the batch.
+    pub async fn register_many(
+        &self,
+        devices: &[DeviceRegistration],
+    ) -> Result<Vec<DeviceRegistrationResult>, AppStoreConnectError> {
+        let existing: HashMap<String, Device> = self
+            .get_all(None, None, None)
+            .await?
+            .into_iter()
+            .map(|device| (device.udid.to_lowercase(), device))
+            .collect();
+
+        let mut seen = HashSet::new();
+        let mut results = Vec::new();
+
+        for device in devices {
+            let key = device.udid.to_lowercase();
+            if !seen.insert(key.clone()) {
+                continue;
+            }
+
+            let outcome = match existing.get(&key) {
+                Some(found) => DeviceRegistrationOutcome::AlreadyRegistered(found.clone()),
+                None => match self
+                    .register(&device.name, &device.udid, device.platform)
+                    .await
+                {
+                    Ok(registered) => DeviceRegistrationOutcome::Registered(registered),
+                    Err(e) => DeviceRegistrationOutcome::Failed(e.to_string()),
+                },
+            };
+
+            results.push(DeviceRegistrationResult {
+                udid: device.udid.clone(),
+                name: device.name.clone(),
+                outcome,
+            });
+        }
+
+        Ok(results)
+    }
+
+    pub async fn register_from_file(
+        &self,
+        path: impl AsRef<Path>,
+        default_platform: BundleIdPlatform,
+    ) -> Result<Vec<DeviceRegistrationResult>, AppStoreConnectError> {
+        let content = tokio::fs::read_to_string(path).await?;
+        let devices = parse_device_list(&content, default_platform)?;
+        self.register_many(&devices).await
+    }
+}
@@ -0,0 +1,237 @@
+use crate::base::{linkages, BaseAPI};
+use crate::error::AppStoreConnectError;
+use crate::upload::{
+    md5_file, upload_file_info, upload_parts, RetryOptions, UploadOperation, UploadProgress,
+};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Clone)]
+pub struct EncryptionDeclarationsAPI {
+    base: BaseAPI,
+}
+
+impl EncryptionDeclarationsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, app_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[app]".to_string(), app_id.to_string());
+
+        self.base
+            .get_all_pages("appEncryptionDeclarations", Some(params), None)
+            .await
+    }
+
+    pub async fn get(&self, declaration_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("appEncryptionDeclarations/{}", declaration_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_for_build(
+        &self,
+        build_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("builds/{}/appEncryptionDeclaration", build_id),
+                None,
+            )
+            .await?;
+
+        Ok(response.get("data").cloned().filter(|d| !d.is_null()))
+    }
+
+    pub async fn create(
+        &self,
+        app_id: &str,
+        app_description: &str,
+        contains_proprietary_cryptography: bool,
+        contains_third_party_cryptography: bool,
+        available_on_french_store: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "appEncryptionDeclarations",
+                "attributes": {
+                    "appDescription": app_description,
+                    "containsProprietaryCryptography": contains_proprietary_cryptography,
+                    "containsThirdPartyCryptography": contains_third_party_cryptography,
+                    "availableOnFrenchStore": available_on_french_store
+                },
+                "relationships": {
+                    "app": {
+                        "data": {
+                            "type": "apps",
+                            "id": app_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("appEncryptionDeclarations", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn assign_builds(
+        &self,
+        declaration_id: &str,
+        build_ids: &[&str],
+    ) -> Result<(), AppStoreConnectError> {
+        let data = json!({ "data": linkages("builds", build_ids) });
+
+        self.base
+            .post(
+                &format!(
+                    "appEncryptionDeclarations/{}/relationships/builds",
+                    declaration_id
+                ),
+                data,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_uses_non_exempt_encryption(
+        &self,
+        build_id: &str,
+        uses_non_exempt_encryption: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "builds",
+                "id": build_id,
+                "attributes": {
+                    "usesNonExemptEncryption": uses_non_exempt_encryption
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("builds/{}", build_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_document(
+        &self,
+        declaration_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!(
+                    "appEncryptionDeclarations/{}/appEncryptionDeclarationDocument",
+                    declaration_id
+                ),
+                None,
+            )
+            .await?;
+
+        Ok(response.get("data").cloned().filter(|d| !d.is_null()))
+    }
+
+    pub async fn upload_document(
+        &self,
+        declaration_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let path = path.as_ref();
+        let (file_name, file_size) = upload_file_info(path).await?;
+
+        let reservation = json!({
+            "data": {
+                "type": "appEncryptionDeclarationDocuments",
+                "attributes": {
+                    "fileName": file_name,
+                    "fileSize": file_size
+                },
+                "relationships": {
+                    "appEncryptionDeclaration": {
+                        "data": {
+                            "type": "appEncryptionDeclarations",
+                            "id": declaration_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .post("appEncryptionDeclarationDocuments", reservation)
+            .await?;
+        let document = response.get("data").cloned().unwrap_or_default();
+        let document_id = document.get("id").and_then(|i| i.as_str()).ok_or_else(|| {
+            AppStoreConnectError::Api {
+                message: "Invalid document ID".to_string(),
+            }
+        })?;
+
+        let operations = UploadOperation::from_resource(&document);
+        upload_parts(
+            &self.base,
+            &operations,
+            path,
+            &mut UploadProgress::new(document_id),
+            &RetryOptions::default(),
+            None,
+        )
+        .await?;
+
+        let commit = json!({
+            "data": {
+                "type": "appEncryptionDeclarationDocuments",
+                "id": document_id,
+                "attributes": {
+                    "uploaded": true,
+                    "sourceFileChecksum": md5_file(path).await?
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(
+                &format!("appEncryptionDeclarationDocuments/{}", document_id),
+                commit,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+}
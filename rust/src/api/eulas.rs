@@ -0,0 +1,184 @@
+use crate::base::{linkages, BaseAPI};
+use crate::error::AppStoreConnectError;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct EulasAPI {
+    base: BaseAPI,
+}
+
+impl EulasAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_for_app(&self, app_id: &str) -> Result<Option<Value>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("apps/{}/endUserLicenseAgreement", app_id), None)
+            .await?;
+
+        Ok(response.get("data").cloned().filter(|d| !d.is_null()))
+    }
+
+    pub async fn get(&self, eula_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("endUserLicenseAgreements/{}", eula_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_territories(
+        &self,
+        eula_id: &str,
+    ) -> Result<Vec<String>, AppStoreConnectError> {
+        let territories = self
+            .base
+            .get_all_pages(
+                &format!("endUserLicenseAgreements/{}/territories", eula_id),
+                None,
+                None,
+            )
+            .await?;
+
+        Ok(territories
+            .iter()
+            .filter_map(|t| t.get("id").and_then(|i| i.as_str()))
+            .map(|id| id.to_string())
+            .collect())
+    }
+
+    pub async fn create(
+        &self,
+        app_id: &str,
+        agreement_text: &str,
+        territories: &[&str],
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "endUserLicenseAgreements",
+                "attributes": {
+                    "agreementText": agreement_text
+                },
+                "relationships": {
+                    "app": {
+                        "data": {
+                            "type": "apps",
+                            "id": app_id
+                        }
+                    },
+                    "territories": {
+                        "data": linkages("territories", territories)
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("endUserLicenseAgreements", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        eula_id: &str,
+        agreement_text: Option<&str>,
+        territories: Option<&[&str]>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut data = json!({
+            "data": {
+                "type": "endUserLicenseAgreements",
+                "id": eula_id,
+                "attributes": {}
+            }
+        });
+
+        if let Some(text) = agreement_text {
+            data["data"]["attributes"]["agreementText"] = json!(text);
+        }
+        if let Some(territories) = territories {
+            data["data"]["relationships"] = json!({
+                "territories": {
+                    "data": linkages("territories", territories)
+                }
+            });
+        }
+
+        let response = self
+            .base
+            .patch(&format!("endUserLicenseAgreements/{}", eula_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, eula_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("endUserLicenseAgreements/{}", eula_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn upsert(
+        &self,
+        app_id: &str,
+        agreement_text: &str,
+        territories: &[&str],
+    ) -> Result<Value, AppStoreConnectError> {
+        match self.get_for_app(app_id).await? {
+            Some(existing) => {
+                let eula_id = existing.get("id").and_then(|i| i.as_str()).ok_or_else(|| {
+                    AppStoreConnectError::Api {
+                        message: "Invalid EULA ID".to_string(),
+                    }
+                })?;
+                self.update(eula_id, Some(agreement_text), Some(territories))
+                    .await
+            }
+            None => self.create(app_id, agreement_text, territories).await,
+        }
+    }
+
+    pub async fn apply_to_apps(
+        &self,
+        app_ids: &[&str],
+        agreement_text: &str,
+        territories: &[&str],
+    ) -> Result<HashMap<String, Value>, AppStoreConnectError> {
+        let mut results = HashMap::new();
+
+        for app_id in app_ids {
+            let result = match self.upsert(app_id, agreement_text, territories).await {
+                Ok(data) => json!({
+                    "success": true,
+                    "data": data
+                }),
+                Err(e) => json!({
+                    "success": false,
+                    "error": e.to_string()
+                }),
+            };
+
+            results.insert(app_id.to_string(), result);
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,574 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameCenterDetail {
+    pub id: String,
+    pub arcade_enabled: bool,
+    pub challenge_enabled: bool,
+    pub group_id: Option<String>,
+}
+
+impl GameCenterDetail {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            arcade_enabled: bool_attribute(attributes, "arcadeEnabled"),
+            challenge_enabled: bool_attribute(attributes, "challengeEnabled"),
+            group_id: value
+                .pointer("/relationships/gameCenterGroup/data/id")
+                .and_then(|id| id.as_str())
+                .map(|id| id.to_string()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameCenterGroup {
+    pub id: String,
+    pub reference_name: Option<String>,
+}
+
+impl GameCenterGroup {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            reference_name: value
+                .pointer("/attributes/referenceName")
+                .and_then(|name| name.as_str())
+                .map(|name| name.to_string()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GameCenterAssetKind {
+    Achievement,
+    Leaderboard,
+}
+
+impl GameCenterAssetKind {
+    fn collection(&self) -> &'static str {
+        match self {
+            GameCenterAssetKind::Achievement => "gameCenterAchievements",
+            GameCenterAssetKind::Leaderboard => "gameCenterLeaderboards",
+        }
+    }
+
+    fn release_type(&self) -> &'static str {
+        match self {
+            GameCenterAssetKind::Achievement => "gameCenterAchievementReleases",
+            GameCenterAssetKind::Leaderboard => "gameCenterLeaderboardReleases",
+        }
+    }
+
+    fn relationship(&self) -> &'static str {
+        match self {
+            GameCenterAssetKind::Achievement => "gameCenterAchievement",
+            GameCenterAssetKind::Leaderboard => "gameCenterLeaderboard",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameCenterAsset {
+    pub id: String,
+    pub kind: GameCenterAssetKind,
+    pub reference_name: Option<String>,
+    pub vendor_identifier: Option<String>,
+    pub archived: bool,
+    pub localized_names: BTreeMap<String, String>,
+}
+
+impl GameCenterAsset {
+    // `included` holds the localizations requested with `include=localizations`.
+    pub fn from_value(
+        kind: GameCenterAssetKind,
+        value: &Value,
+        included: &[Value],
+    ) -> Option<Self> {
+        let attributes = value.get("attributes");
+        let localization_ids: Vec<&str> = value
+            .pointer("/relationships/localizations/data")
+            .and_then(|data| data.as_array())
+            .map(|data| data.iter().filter_map(|l| l.get("id")?.as_str()).collect())
+            .unwrap_or_default();
+
+        let localized_names = included
+            .iter()
+            .filter(|item| {
+                item.get("id")
+                    .and_then(|id| id.as_str())
+                    .is_some_and(|id| localization_ids.contains(&id))
+            })
+            .filter_map(|item| {
+                let locale = item.pointer("/attributes/locale")?.as_str()?;
+                let name = item.pointer("/attributes/name")?.as_str()?.trim();
+                (!name.is_empty()).then(|| (locale.to_string(), name.to_string()))
+            })
+            .collect();
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            kind,
+            reference_name: attributes
+                .and_then(|a| a.get("referenceName"))
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_string()),
+            vendor_identifier: attributes
+                .and_then(|a| a.get("vendorIdentifier"))
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_string()),
+            archived: bool_attribute(attributes, "archived"),
+            localized_names,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalizationGap {
+    pub asset_id: String,
+    pub kind: GameCenterAssetKind,
+    pub reference_name: Option<String>,
+    pub missing_locales: Vec<String>,
+}
+
+// Archived assets never go live, so they're skipped. With no required
+// locales an asset only needs a name in some locale; `missing_locales` is
+// then empty for assets that have none at all.
+pub fn localization_gaps(
+    assets: &[GameCenterAsset],
+    required_locales: &[&str],
+) -> Vec<LocalizationGap> {
+    assets
+        .iter()
+        .filter(|asset| !asset.archived)
+        .filter_map(|asset| {
+            let missing_locales: Vec<String> = required_locales
+                .iter()
+                .filter(|locale| !asset.localized_names.contains_key(**locale))
+                .map(|locale| locale.to_string())
+                .collect();
+
+            let unnamed = asset.localized_names.is_empty();
+            (unnamed || !missing_locales.is_empty()).then(|| LocalizationGap {
+                asset_id: asset.id.clone(),
+                kind: asset.kind,
+                reference_name: asset.reference_name.clone(),
+                missing_locales,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameCenterRelease {
+    pub version_id: String,
+    pub app_version_id: String,
+    pub releases: Vec<(GameCenterAssetKind, String)>,
+}
+
+fn bool_attribute(attributes: Option<&Value>, key: &str) -> bool {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn to_one(resource_type: &str, id: Option<&str>) -> Value {
+    match id {
+        Some(id) => json!({ "data": { "type": resource_type, "id": id } }),
+        None => json!({ "data": null }),
+    }
+}
+
+#[derive(Clone)]
+pub struct GameCenterAPI {
+    base: BaseAPI,
+}
+
+impl GameCenterAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_detail(
+        &self,
+        app_id: &str,
+    ) -> Result<Option<GameCenterDetail>, AppStoreConnectError> {
+        match self
+            .base
+            .get(&format!("apps/{}/gameCenterDetail", app_id), None)
+            .await
+        {
+            Ok(response) => Ok(response.get("data").and_then(GameCenterDetail::from_value)),
+            Err(AppStoreConnectError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Creating the gameCenterDetail is what turns Game Center on for an app.
+    pub async fn enable(
+        &self,
+        app_id: &str,
+        challenge_enabled: bool,
+    ) -> Result<GameCenterDetail, AppStoreConnectError> {
+        if let Some(detail) = self.get_detail(app_id).await? {
+            if detail.challenge_enabled == challenge_enabled {
+                return Ok(detail);
+            }
+            return self
+                .update_detail(&detail.id, Some(challenge_enabled), None)
+                .await;
+        }
+
+        let data = json!({
+            "data": {
+                "type": "gameCenterDetails",
+                "attributes": {
+                    "challengeEnabled": challenge_enabled
+                },
+                "relationships": {
+                    "app": to_one("apps", Some(app_id))
+                }
+            }
+        });
+
+        let response = self.base.post("gameCenterDetails", data).await?;
+        response
+            .get("data")
+            .and_then(GameCenterDetail::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update_detail(
+        &self,
+        detail_id: &str,
+        challenge_enabled: Option<bool>,
+        default_leaderboard_id: Option<&str>,
+    ) -> Result<GameCenterDetail, AppStoreConnectError> {
+        let mut data = json!({
+            "data": {
+                "type": "gameCenterDetails",
+                "id": detail_id
+            }
+        });
+
+        if let Some(enabled) = challenge_enabled {
+            data["data"]["attributes"] = json!({ "challengeEnabled": enabled });
+        }
+        if let Some(leaderboard_id) = default_leaderboard_id {
+            data["data"]["relationships"] = json!({
+                "defaultLeaderboard": to_one("gameCenterLeaderboards", Some(leaderboard_id))
+            });
+        }
+
+        let response = self
+            .base
+            .patch(&format!("gameCenterDetails/{}", detail_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(GameCenterDetail::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    // Passing `None` moves the app out of its group so it keeps its own
+    // achievements and leaderboards.
+    pub async fn set_group(
+        &self,
+        detail_id: &str,
+        group_id: Option<&str>,
+    ) -> Result<GameCenterDetail, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "gameCenterDetails",
+                "id": detail_id,
+                "relationships": {
+                    "gameCenterGroup": to_one("gameCenterGroups", group_id)
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("gameCenterDetails/{}", detail_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(GameCenterDetail::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_group(
+        &self,
+        detail_id: &str,
+    ) -> Result<Option<GameCenterGroup>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("gameCenterDetails/{}/gameCenterGroup", detail_id),
+                None,
+            )
+            .await?;
+
+        Ok(response.get("data").and_then(GameCenterGroup::from_value))
+    }
+
+    pub async fn get_groups(&self) -> Result<Vec<GameCenterGroup>, AppStoreConnectError> {
+        let groups = self
+            .base
+            .get_all_pages("gameCenterGroups", None, None)
+            .await?;
+
+        Ok(groups
+            .iter()
+            .filter_map(GameCenterGroup::from_value)
+            .collect())
+    }
+
+    pub async fn create_group(
+        &self,
+        reference_name: &str,
+    ) -> Result<GameCenterGroup, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "gameCenterGroups",
+                "attributes": {
+                    "referenceName": reference_name
+                }
+            }
+        });
+
+        let response = self.base.post("gameCenterGroups", data).await?;
+        response
+            .get("data")
+            .and_then(GameCenterGroup::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_assets(
+        &self,
+        detail_id: &str,
+        kind: GameCenterAssetKind,
+    ) -> Result<Vec<GameCenterAsset>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "localizations".to_string());
+        params.insert("limit[localizations]".to_string(), "50".to_string());
+
+        let (assets, included) = self
+            .base
+            .get_all_pages_with_included(
+                &format!("gameCenterDetails/{}/{}", detail_id, kind.collection()),
+                Some(params),
+                None,
+            )
+            .await?;
+
+        Ok(assets
+            .iter()
+            .filter_map(|asset| GameCenterAsset::from_value(kind, asset, &included))
+            .collect())
+    }
+
+    pub async fn get_achievements(
+        &self,
+        detail_id: &str,
+    ) -> Result<Vec<GameCenterAsset>, AppStoreConnectError> {
+        self.get_assets(detail_id, GameCenterAssetKind::Achievement)
+            .await
+    }
+
+    pub async fn get_leaderboards(
+        &self,
+        detail_id: &str,
+    ) -> Result<Vec<GameCenterAsset>, AppStoreConnectError> {
+        self.get_assets(detail_id, GameCenterAssetKind::Leaderboard)
+            .await
+    }
+
+    // Review rejects builds whose live achievements or leaderboards lack a
+    // display name, so this is meant to run right before submission.
+    pub async fn verify_localized_names(
+        &self,
+        detail_id: &str,
+        required_locales: &[&str],
+    ) -> Result<(), AppStoreConnectError> {
+        let mut assets = self.get_achievements(detail_id).await?;
+        assets.extend(self.get_leaderboards(detail_id).await?);
+
+        let issues: Vec<String> = localization_gaps(&assets, required_locales)
+            .into_iter()
+            .map(|gap| {
+                let name = gap.reference_name.unwrap_or(gap.asset_id);
+                if gap.missing_locales.is_empty() {
+                    format!("{:?} '{}' has no localized name", gap.kind, name)
+                } else {
+                    format!(
+                        "{:?} '{}' has no name for {}",
+                        gap.kind,
+                        name,
+                        gap.missing_locales.join(", ")
+                    )
+                }
+            })
+            .collect();
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(AppStoreConnectError::Validation(
+                ValidationError::with_issues(
+                    "Game Center assets are missing localized names",
+                    issues,
+                ),
+            ))
+        }
+    }
+
+    pub async fn get_or_create_app_version(
+        &self,
+        version_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let existing = match self
+            .base
+            .get(
+                &format!("appStoreVersions/{}/gameCenterAppVersion", version_id),
+                None,
+            )
+            .await
+        {
+            Ok(response) => response.get("data").cloned().filter(|d| !d.is_null()),
+            Err(AppStoreConnectError::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+        if let Some(app_version) = existing {
+            return Ok(app_version);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "gameCenterAppVersions",
+                "relationships": {
+                    "appStoreVersion": to_one("appStoreVersions", Some(version_id))
+                }
+            }
+        });
+
+        let response = self.base.post("gameCenterAppVersions", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn create_release(
+        &self,
+        detail_id: &str,
+        kind: GameCenterAssetKind,
+        asset_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": kind.release_type(),
+                "relationships": {
+                    "gameCenterDetail": to_one("gameCenterDetails", Some(detail_id)),
+                    kind.relationship(): to_one(kind.collection(), Some(asset_id))
+                }
+            }
+        });
+
+        let response = self.base.post(kind.release_type(), data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    // Ties the app's Game Center version to `version_id` and releases the
+    // given achievements and leaderboards with it. Names are checked first
+    // so a missing localization fails here instead of in review.
+    pub async fn release_with_version(
+        &self,
+        app_id: &str,
+        version_id: &str,
+        achievement_ids: &[&str],
+        leaderboard_ids: &[&str],
+        required_locales: &[&str],
+    ) -> Result<GameCenterRelease, AppStoreConnectError> {
+        let detail = self.get_detail(app_id).await?.ok_or_else(|| {
+            AppStoreConnectError::Validation(ValidationError::new(format!(
+                "Game Center is not enabled for app {}",
+                app_id
+            )))
+        })?;
+        self.verify_localized_names(&detail.id, required_locales)
+            .await?;
+
+        let app_version = self.get_or_create_app_version(version_id).await?;
+        let mut releases = Vec::new();
+
+        let assets = achievement_ids
+            .iter()
+            .map(|id| (GameCenterAssetKind::Achievement, *id))
+            .chain(
+                leaderboard_ids
+                    .iter()
+                    .map(|id| (GameCenterAssetKind::Leaderboard, *id)),
+            );
+        for (kind, asset_id) in assets {
+            let release = self.create_release(&detail.id, kind, asset_id).await?;
+            if let Some(id) = release.get("id").and_then(|id| id.as_str()) {
+                releases.push((kind, id.to_string()));
+            }
+        }
+
+        Ok(GameCenterRelease {
+            version_id: version_id.to_string(),
+            app_version_id: app_version
+                .get("id")
+                .and_then(|id| id.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            releases,
+        })
+    }
+
+    pub async fn get_group_details(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<GameCenterDetail>, AppStoreConnectError> {
+        let details = self
+            .base
+            .get_all_pages(
+                &format!("gameCenterGroups/{}/gameCenterDetails", group_id),
+                None,
+                None,
+            )
+            .await?;
+
+        Ok(details
+            .iter()
+            .filter_map(GameCenterDetail::from_value)
+            .collect())
+    }
+}
@@ -0,0 +1,193 @@
+use crate::base::{BaseAPI, V2_BASE_URL};
+use crate::bulk::{BulkAction, BulkResult};
+use crate::diff::{DiffScope, SnapshotDiff};
+use crate::error::AppStoreConnectError;
+use crate::snapshot::{by_locale, index_by_locale, FieldMap};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Clone)]
+pub struct InAppPurchaseLocalizationsAPI {
+    base: BaseAPI,
+}
+
+impl InAppPurchaseLocalizationsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, iap_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!(
+                    "{}inAppPurchases/{}/inAppPurchaseLocalizations",
+                    V2_BASE_URL, iap_id
+                ),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get(&self, localization_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("inAppPurchaseLocalizations/{}", localization_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn create(
+        &self,
+        iap_id: &str,
+        locale: &str,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({
+            "locale": locale,
+            "name": name
+        });
+
+        if let Some(description) = description {
+            attributes["description"] = json!(description);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "inAppPurchaseLocalizations",
+                "attributes": attributes,
+                "relationships": {
+                    "inAppPurchaseV2": {
+                        "data": {
+                            "type": "inAppPurchases",
+                            "id": iap_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("inAppPurchaseLocalizations", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        localization_id: &str,
+        name: Option<&str>,
+        description: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({});
+
+        if let Some(name) = name {
+            attributes["name"] = json!(name);
+        }
+        if let Some(description) = description {
+            attributes["description"] = json!(description);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "inAppPurchaseLocalizations",
+                "id": localization_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self
+            .base
+            .patch(
+                &format!("inAppPurchaseLocalizations/{}", localization_id),
+                data,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, localization_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("inAppPurchaseLocalizations/{}", localization_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn bulk_update(
+        &self,
+        iap_id: &str,
+        localizations: HashMap<String, Value>,
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
+        let existing = index_by_locale(self.get_all(iap_id).await?);
+        let mut results = Vec::new();
+
+        for (locale, attributes) in localizations {
+            let field = |name: &str| attributes.get(name).and_then(|v| v.as_str());
+
+            let result = if let Some((localization_id, _)) = existing.get(&locale) {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Updated,
+                    self.update(localization_id, field("name"), field("description"))
+                        .await,
+                )
+            } else {
+                let created = match field("name") {
+                    Some(name) => {
+                        self.create(iap_id, &locale, name, field("description"))
+                            .await
+                    }
+                    None => Err(AppStoreConnectError::Api {
+                        message: "A name is required to create a localization".to_string(),
+                    }),
+                };
+
+                BulkResult::from_result(&locale, BulkAction::Created, created)
+            };
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    pub async fn diff(
+        &self,
+        iap_id: &str,
+        desired: &BTreeMap<String, FieldMap>,
+    ) -> Result<SnapshotDiff, AppStoreConnectError> {
+        let mut current = by_locale(&self.get_all(iap_id).await?);
+        for fields in current.values_mut() {
+            fields.retain(|key, _| key == "name" || key == "description");
+        }
+
+        let mut diff = SnapshotDiff::default();
+        diff.diff_locales(&current, desired, |locale| {
+            DiffScope::InAppPurchaseLocalization {
+                iap_id: iap_id.to_string(),
+                locale: locale.to_string(),
+            }
+        });
+
+        Ok(diff)
+    }
+}
@@ -0,0 +1,342 @@
+use crate::api::availability::AvailabilityAPI;
+pub use crate::api::pricing::ScheduledPrice;
+use crate::api::pricing::{find_price_point, manual_prices};
+use crate::base::{linkages, BaseAPI, V2_BASE_URL};
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct InAppPurchasesAPI {
+    base: BaseAPI,
+    availability: AvailabilityAPI,
+}
+
+impl InAppPurchasesAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self {
+            availability: AvailabilityAPI::new(base.clone()),
+            base,
+        }
+    }
+
+    pub async fn get_all(&self, app_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(&format!("apps/{}/inAppPurchasesV2", app_id), None, None)
+            .await
+    }
+
+    pub async fn get(&self, iap_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("{}inAppPurchases/{}", V2_BASE_URL, iap_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_price_points(
+        &self,
+        iap_id: &str,
+        territory: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "territory".to_string());
+
+        if let Some(territory) = territory {
+            params.insert("filter[territory]".to_string(), territory.to_string());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!("{}inAppPurchases/{}/pricePoints", V2_BASE_URL, iap_id),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn find_price_point(
+        &self,
+        iap_id: &str,
+        territory: &str,
+        customer_price: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let points = self.get_price_points(iap_id, Some(territory)).await?;
+
+        Ok(find_price_point(&points, customer_price).cloned())
+    }
+
+    pub async fn get_price_schedule(
+        &self,
+        iap_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "baseTerritory".to_string());
+
+        match self
+            .base
+            .get(
+                &format!("{}inAppPurchases/{}/iapPriceSchedule", V2_BASE_URL, iap_id),
+                Some(params),
+            )
+            .await
+        {
+            Ok(response) => Ok(response.get("data").cloned().filter(|d| !d.is_null())),
+            Err(AppStoreConnectError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn get_manual_prices(
+        &self,
+        schedule_id: &str,
+        territory: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert(
+            "include".to_string(),
+            "inAppPurchasePricePoint,territory".to_string(),
+        );
+
+        if let Some(territory) = territory {
+            params.insert("filter[territory]".to_string(), territory.to_string());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!("inAppPurchasePriceSchedules/{}/manualPrices", schedule_id),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_automatic_prices(
+        &self,
+        schedule_id: &str,
+        territory: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert(
+            "include".to_string(),
+            "inAppPurchasePricePoint,territory".to_string(),
+        );
+
+        if let Some(territory) = territory {
+            params.insert("filter[territory]".to_string(), territory.to_string());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!(
+                    "inAppPurchasePriceSchedules/{}/automaticPrices",
+                    schedule_id
+                ),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn set_price_schedule(
+        &self,
+        iap_id: &str,
+        base_territory: &str,
+        prices: &[ScheduledPrice],
+    ) -> Result<Value, AppStoreConnectError> {
+        if prices.is_empty() {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                "At least one price is required for a price schedule",
+            )));
+        }
+
+        let (linkages, included) = manual_prices(
+            "inAppPurchasePrices",
+            "inAppPurchasePricePoints",
+            "inAppPurchasePricePoint",
+            prices,
+        );
+
+        let data = json!({
+            "data": {
+                "type": "inAppPurchasePriceSchedules",
+                "relationships": {
+                    "inAppPurchase": {
+                        "data": { "type": "inAppPurchases", "id": iap_id }
+                    },
+                    "baseTerritory": {
+                        "data": { "type": "territories", "id": base_territory }
+                    },
+                    "manualPrices": {
+                        "data": linkages
+                    }
+                }
+            },
+            "included": included
+        });
+
+        let response = self.base.post("inAppPurchasePriceSchedules", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_availability(
+        &self,
+        iap_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        match self
+            .base
+            .get(
+                &format!(
+                    "{}inAppPurchases/{}/inAppPurchaseAvailability",
+                    V2_BASE_URL, iap_id
+                ),
+                None,
+            )
+            .await
+        {
+            Ok(response) => Ok(response.get("data").cloned().filter(|d| !d.is_null())),
+            Err(AppStoreConnectError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn get_available_territories(
+        &self,
+        iap_id: &str,
+    ) -> Result<Vec<String>, AppStoreConnectError> {
+        let availability_id = match self.get_availability(iap_id).await? {
+            Some(availability) => availability
+                .get("id")
+                .and_then(|i| i.as_str())
+                .map(|id| id.to_string())
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: "Invalid availability ID".to_string(),
+                })?,
+            None => return Ok(Vec::new()),
+        };
+
+        let territories = self
+            .base
+            .get_all_pages(
+                &format!(
+                    "inAppPurchaseAvailabilities/{}/availableTerritories",
+                    availability_id
+                ),
+                None,
+                None,
+            )
+            .await?;
+
+        Ok(territories
+            .iter()
+            .filter_map(|t| t.get("id").and_then(|i| i.as_str()))
+            .map(|id| id.to_string())
+            .collect())
+    }
+
+    pub async fn set_availability(
+        &self,
+        iap_id: &str,
+        territories: &[&str],
+        available_in_new_territories: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "inAppPurchaseAvailabilities",
+                "attributes": {
+                    "availableInNewTerritories": available_in_new_territories
+                },
+                "relationships": {
+                    "inAppPurchase": {
+                        "data": { "type": "inAppPurchases", "id": iap_id }
+                    },
+                    "availableTerritories": {
+                        "data": linkages("territories", territories)
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("inAppPurchaseAvailabilities", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn match_app_availability(
+        &self,
+        app_id: &str,
+        iap_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let territories = self.availability.get_available_territories(app_id).await?;
+        let available_in_new = self
+            .availability
+            .is_available_in_new_territories(app_id)
+            .await?;
+        let territories: Vec<&str> = territories.iter().map(|t| t.as_str()).collect();
+
+        self.set_availability(iap_id, &territories, available_in_new)
+            .await
+    }
+
+    pub async fn set_family_sharable(
+        &self,
+        iap_id: &str,
+        family_sharable: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let current = self.get(iap_id).await?;
+        let enabled = current
+            .get("attributes")
+            .and_then(|a| a.get("familySharable"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if enabled == family_sharable {
+            return Ok(current);
+        }
+        if enabled && !family_sharable {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                format!(
+                "Family Sharing is already enabled for in-app purchase {} and cannot be turned off",
+                iap_id
+            ),
+            )));
+        }
+
+        let data = json!({
+            "data": {
+                "type": "inAppPurchases",
+                "id": iap_id,
+                "attributes": {
+                    "familySharable": true
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("{}inAppPurchases/{}", V2_BASE_URL, iap_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+}
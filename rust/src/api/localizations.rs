@@ -1,8 +1,42 @@
 use crate::base::BaseAPI;
+use crate::bulk::{export_by_locale, BulkAction, BulkResult};
 use crate::error::AppStoreConnectError;
+use crate::locale::normalize_locale;
+use crate::metadata::{check_field_limits, validate_field_limits, FieldUpdate, Translator};
+use crate::snapshot::APP_INFO_LOCALIZATION_FIELDS;
 use serde_json::{json, Value};
 use std::collections::HashMap;
 
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AppInfoLocalizationUpdate {
+    pub name: FieldUpdate<String>,
+    pub subtitle: FieldUpdate<String>,
+    pub privacy_policy_url: FieldUpdate<String>,
+    pub privacy_policy_text: FieldUpdate<String>,
+}
+
+impl AppInfoLocalizationUpdate {
+    pub fn from_value(attributes: &Value) -> Self {
+        Self {
+            name: FieldUpdate::from_attribute(attributes, "name"),
+            subtitle: FieldUpdate::from_attribute(attributes, "subtitle"),
+            privacy_policy_url: FieldUpdate::from_attribute(attributes, "privacyPolicyUrl"),
+            privacy_policy_text: FieldUpdate::from_attribute(attributes, "privacyPolicyText"),
+        }
+    }
+
+    pub fn to_attributes(&self) -> Value {
+        let mut attributes = json!({});
+        self.name.write(&mut attributes, "name");
+        self.subtitle.write(&mut attributes, "subtitle");
+        self.privacy_policy_url
+            .write(&mut attributes, "privacyPolicyUrl");
+        self.privacy_policy_text
+            .write(&mut attributes, "privacyPolicyText");
+        attributes
+    }
+}
+
 #[derive(Clone)]
 pub struct LocalizationsAPI {
     base: BaseAPI,
@@ -52,6 +86,7 @@ impl LocalizationsAPI {
         privacy_policy_url: Option<&str>,
         privacy_policy_text: Option<&str>,
     ) -> Result<Value, AppStoreConnectError> {
+        let locale = normalize_locale(locale)?;
         let mut attributes = json!({ "locale": locale });
 
         if let Some(name) = name {
@@ -67,6 +102,8 @@ impl LocalizationsAPI {
             attributes["privacyPolicyText"] = json!(text);
         }
 
+        validate_field_limits(&locale, &attributes)?;
+
         let data = json!({
             "data": {
                 "type": "appInfoLocalizations",
@@ -99,19 +136,37 @@ impl LocalizationsAPI {
         privacy_policy_url: Option<&str>,
         privacy_policy_text: Option<&str>,
     ) -> Result<Value, AppStoreConnectError> {
-        let mut attributes = json!({});
+        let update = AppInfoLocalizationUpdate {
+            name: name.into(),
+            subtitle: subtitle.into(),
+            privacy_policy_url: privacy_policy_url.into(),
+            privacy_policy_text: privacy_policy_text.into(),
+        };
+        self.update_fields(localization_id, &update).await
+    }
 
-        if let Some(name) = name {
-            attributes["name"] = json!(name);
-        }
-        if let Some(subtitle) = subtitle {
-            attributes["subtitle"] = json!(subtitle);
-        }
-        if let Some(url) = privacy_policy_url {
-            attributes["privacyPolicyUrl"] = json!(url);
-        }
-        if let Some(text) = privacy_policy_text {
-            attributes["privacyPolicyText"] = json!(text);
+    pub async fn update_fields(
+        &self,
+        localization_id: &str,
+        update: &AppInfoLocalizationUpdate,
+    ) -> Result<Value, AppStoreConnectError> {
+        let attributes = update.to_attributes();
+
+        if !check_field_limits(&attributes).is_empty() {
+            // Only looked up on failure, so the error names the locale rather
+            // than the opaque localization ID.
+            let locale = self
+                .get(localization_id)
+                .await
+                .ok()
+                .and_then(|l| {
+                    l.get("attributes")?
+                        .get("locale")?
+                        .as_str()
+                        .map(String::from)
+                })
+                .unwrap_or_else(|| format!("localization {}", localization_id));
+            validate_field_limits(&locale, &attributes)?;
         }
 
         let data = json!({
@@ -146,7 +201,7 @@ impl LocalizationsAPI {
         &self,
         app_info_id: &str,
         localizations: HashMap<String, Value>,
-    ) -> Result<HashMap<String, Value>, AppStoreConnectError> {
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
         let existing = self.get_all(app_info_id).await?;
         let mut existing_by_locale = HashMap::new();
 
@@ -161,39 +216,159 @@ impl LocalizationsAPI {
             }
         }
 
-        let mut results = HashMap::new();
+        let mut results = Vec::new();
 
-        for (locale, attributes) in localizations {
-            let result = if let Some((localization_id, _)) = existing_by_locale.get(&locale) {
-                match self.update_from_value(localization_id, &attributes).await {
-                    Ok(data) => json!({
-                        "success": true,
-                        "action": "updated",
-                        "data": data
-                    }),
-                    Err(e) => json!({
-                        "success": false,
-                        "error": e.to_string()
-                    }),
+        for (requested, attributes) in localizations {
+            let locale = match normalize_locale(&requested) {
+                Ok(locale) => locale,
+                Err(e) => {
+                    results.push(BulkResult::failed(requested, e));
+                    continue;
                 }
+            };
+            if let Err(e) = validate_field_limits(&locale, &attributes) {
+                results.push(BulkResult::failed(&locale, e));
+                continue;
+            }
+
+            let result = if let Some((localization_id, _)) = existing_by_locale.get(&locale) {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Updated,
+                    self.update_from_value(localization_id, &attributes).await,
+                )
             } else {
-                match self
-                    .create_from_value(app_info_id, &locale, &attributes)
-                    .await
-                {
-                    Ok(data) => json!({
-                        "success": true,
-                        "action": "created",
-                        "data": data
-                    }),
-                    Err(e) => json!({
-                        "success": false,
-                        "error": e.to_string()
-                    }),
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Created,
+                    self.create_from_value(app_info_id, &locale, &attributes)
+                        .await,
+                )
+            };
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    pub async fn bulk_export(
+        &self,
+        app_info_id: &str,
+    ) -> Result<HashMap<String, Value>, AppStoreConnectError> {
+        let localizations = self.get_all(app_info_id).await?;
+        Ok(export_by_locale(
+            localizations,
+            APP_INFO_LOCALIZATION_FIELDS,
+        ))
+    }
+
+    pub async fn fill_missing(
+        &self,
+        app_info_id: &str,
+        translator: &dyn Translator,
+        locales: &[&str],
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
+        let existing = self.get_all(app_info_id).await?;
+        let find = |locale: &str| {
+            existing
+                .iter()
+                .find(|l| l.get("attributes").and_then(|a| a.get("locale")) == Some(&json!(locale)))
+        };
+        let attributes_for = |locale: &str| find(locale).and_then(|l| l.get("attributes")).cloned();
+
+        let source_locale = if attributes_for("en-US").is_some() {
+            "en-US".to_string()
+        } else {
+            existing
+                .first()
+                .and_then(|l| l.get("attributes"))
+                .and_then(|a| a.get("locale"))
+                .and_then(|l| l.as_str())
+                .map(|l| l.to_string())
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: format!(
+                        "App info {} has no localizations to translate from",
+                        app_info_id
+                    ),
+                })?
+        };
+        let source = attributes_for(&source_locale).unwrap_or_default();
+        let text = |attributes: &Value, field: &str| {
+            attributes
+                .get(field)
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| s.to_string())
+        };
+
+        let mut results = Vec::new();
+
+        for requested in locales {
+            // API locales are canonical ("de-DE"); match on the same form so
+            // "de" or "en-us" find the existing localization.
+            let locale = match normalize_locale(requested) {
+                Ok(locale) => locale,
+                Err(e) => {
+                    results.push(BulkResult::failed(*requested, e));
+                    continue;
                 }
             };
+            if locale == source_locale {
+                continue;
+            }
+            let locale = locale.as_str();
+            let current = attributes_for(locale).unwrap_or_default();
+            let mut attributes = json!({});
 
-            results.insert(locale, result);
+            let translated: Result<(), AppStoreConnectError> = async {
+                for field in ["name", "subtitle", "privacyPolicyText"] {
+                    if text(&current, field).is_some() {
+                        continue;
+                    }
+                    if let Some(source_text) = text(&source, field) {
+                        attributes[field] = json!(
+                            translator
+                                .translate(&source_locale, locale, field, &source_text)
+                                .await?
+                        );
+                    }
+                }
+                if text(&current, "privacyPolicyUrl").is_none() {
+                    if let Some(url) = text(&source, "privacyPolicyUrl") {
+                        attributes["privacyPolicyUrl"] = json!(url);
+                    }
+                }
+                Ok(())
+            }
+            .await;
+
+            if let Err(e) = translated {
+                results.push(BulkResult::failed(locale, e));
+                continue;
+            }
+            if attributes.as_object().is_some_and(|a| a.is_empty()) {
+                continue;
+            }
+
+            let localization_id = find(locale)
+                .and_then(|l| l.get("id"))
+                .and_then(|i| i.as_str());
+
+            let result = match localization_id {
+                Some(localization_id) => BulkResult::from_result(
+                    locale,
+                    BulkAction::Updated,
+                    self.update_from_value(localization_id, &attributes).await,
+                ),
+                None => BulkResult::from_result(
+                    locale,
+                    BulkAction::Created,
+                    self.create_from_value(app_info_id, locale, &attributes)
+                        .await,
+                ),
+            };
+            results.push(result);
         }
 
         Ok(results)
@@ -204,17 +379,9 @@ impl LocalizationsAPI {
         localization_id: &str,
         attributes: &Value,
     ) -> Result<Value, AppStoreConnectError> {
-        let name = attributes.get("name").and_then(|n| n.as_str());
-        let subtitle = attributes.get("subtitle").and_then(|s| s.as_str());
-        let privacy_policy_url = attributes.get("privacyPolicyUrl").and_then(|u| u.as_str());
-        let privacy_policy_text = attributes.get("privacyPolicyText").and_then(|t| t.as_str());
-
-        self.update(
+        self.update_fields(
             localization_id,
-            name,
-            subtitle,
-            privacy_policy_url,
-            privacy_policy_text,
+            &AppInfoLocalizationUpdate::from_value(attributes),
         )
         .await
     }
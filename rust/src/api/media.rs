@@ -1,7 +1,523 @@
-use crate::base::BaseAPI;
-use crate::error::AppStoreConnectError;
+use crate::base::{linkages, BaseAPI};
+use crate::error::{AppStoreConnectError, ValidationError};
+use crate::polling::{poll_until, PollOptions};
+use crate::upload::{
+    md5_file, upload_file_info, upload_parts, RetryOptions, UploadOperation, UploadProgress,
+};
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+pub const MAX_SCREENSHOT_FILE_SIZE: u64 = 10 * 1024 * 1024;
+pub const MAX_SCREENSHOTS_PER_SET: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScreenshotDisplayType {
+    AppIphone67,
+    AppIphone65,
+    AppIphone61,
+    AppIphone58,
+    AppIphone55,
+    AppIphone47,
+    AppIphone40,
+    AppIphone35,
+    AppIpadPro3Gen129,
+    AppIpadPro129,
+    AppIpadPro3Gen11,
+    AppIpad105,
+    AppIpad97,
+    AppAppleTv,
+    AppDesktop,
+    AppAppleVisionPro,
+    AppWatchUltra,
+    AppWatchSeries10,
+    AppWatchSeries7,
+    AppWatchSeries4,
+    AppWatchSeries3,
+    IMessageIphone67,
+    IMessageIphone65,
+    IMessageIphone61,
+    IMessageIphone58,
+    IMessageIphone55,
+    IMessageIphone47,
+    IMessageIphone40,
+    IMessageIpadPro3Gen129,
+    IMessageIpadPro129,
+    IMessageIpadPro3Gen11,
+    IMessageIpad105,
+    IMessageIpad97,
+}
+
+impl ScreenshotDisplayType {
+    pub const ALL: &'static [ScreenshotDisplayType] = &[
+        ScreenshotDisplayType::AppIphone67,
+        ScreenshotDisplayType::AppIphone65,
+        ScreenshotDisplayType::AppIphone61,
+        ScreenshotDisplayType::AppIphone58,
+        ScreenshotDisplayType::AppIphone55,
+        ScreenshotDisplayType::AppIphone47,
+        ScreenshotDisplayType::AppIphone40,
+        ScreenshotDisplayType::AppIphone35,
+        ScreenshotDisplayType::AppIpadPro3Gen129,
+        ScreenshotDisplayType::AppIpadPro129,
+        ScreenshotDisplayType::AppIpadPro3Gen11,
+        ScreenshotDisplayType::AppIpad105,
+        ScreenshotDisplayType::AppIpad97,
+        ScreenshotDisplayType::AppAppleTv,
+        ScreenshotDisplayType::AppDesktop,
+        ScreenshotDisplayType::AppAppleVisionPro,
+        ScreenshotDisplayType::AppWatchUltra,
+        ScreenshotDisplayType::AppWatchSeries10,
+        ScreenshotDisplayType::AppWatchSeries7,
+        ScreenshotDisplayType::AppWatchSeries4,
+        ScreenshotDisplayType::AppWatchSeries3,
+        ScreenshotDisplayType::IMessageIphone67,
+        ScreenshotDisplayType::IMessageIphone65,
+        ScreenshotDisplayType::IMessageIphone61,
+        ScreenshotDisplayType::IMessageIphone58,
+        ScreenshotDisplayType::IMessageIphone55,
+        ScreenshotDisplayType::IMessageIphone47,
+        ScreenshotDisplayType::IMessageIphone40,
+        ScreenshotDisplayType::IMessageIpadPro3Gen129,
+        ScreenshotDisplayType::IMessageIpadPro129,
+        ScreenshotDisplayType::IMessageIpadPro3Gen11,
+        ScreenshotDisplayType::IMessageIpad105,
+        ScreenshotDisplayType::IMessageIpad97,
+    ];
+
+    pub const IPHONE_69: ScreenshotDisplayType = ScreenshotDisplayType::AppIphone67;
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScreenshotDisplayType::AppIphone67 => "APP_IPHONE_67",
+            ScreenshotDisplayType::AppIphone65 => "APP_IPHONE_65",
+            ScreenshotDisplayType::AppIphone61 => "APP_IPHONE_61",
+            ScreenshotDisplayType::AppIphone58 => "APP_IPHONE_58",
+            ScreenshotDisplayType::AppIphone55 => "APP_IPHONE_55",
+            ScreenshotDisplayType::AppIphone47 => "APP_IPHONE_47",
+            ScreenshotDisplayType::AppIphone40 => "APP_IPHONE_40",
+            ScreenshotDisplayType::AppIphone35 => "APP_IPHONE_35",
+            ScreenshotDisplayType::AppIpadPro3Gen129 => "APP_IPAD_PRO_3GEN_129",
+            ScreenshotDisplayType::AppIpadPro129 => "APP_IPAD_PRO_129",
+            ScreenshotDisplayType::AppIpadPro3Gen11 => "APP_IPAD_PRO_3GEN_11",
+            ScreenshotDisplayType::AppIpad105 => "APP_IPAD_105",
+            ScreenshotDisplayType::AppIpad97 => "APP_IPAD_97",
+            ScreenshotDisplayType::AppAppleTv => "APP_APPLE_TV",
+            ScreenshotDisplayType::AppDesktop => "APP_DESKTOP",
+            ScreenshotDisplayType::AppAppleVisionPro => "APP_APPLE_VISION_PRO",
+            ScreenshotDisplayType::AppWatchUltra => "APP_WATCH_ULTRA",
+            ScreenshotDisplayType::AppWatchSeries10 => "APP_WATCH_SERIES_10",
+            ScreenshotDisplayType::AppWatchSeries7 => "APP_WATCH_SERIES_7",
+            ScreenshotDisplayType::AppWatchSeries4 => "APP_WATCH_SERIES_4",
+            ScreenshotDisplayType::AppWatchSeries3 => "APP_WATCH_SERIES_3",
+            ScreenshotDisplayType::IMessageIphone67 => "IMESSAGE_APP_IPHONE_67",
+            ScreenshotDisplayType::IMessageIphone65 => "IMESSAGE_APP_IPHONE_65",
+            ScreenshotDisplayType::IMessageIphone61 => "IMESSAGE_APP_IPHONE_61",
+            ScreenshotDisplayType::IMessageIphone58 => "IMESSAGE_APP_IPHONE_58",
+            ScreenshotDisplayType::IMessageIphone55 => "IMESSAGE_APP_IPHONE_55",
+            ScreenshotDisplayType::IMessageIphone47 => "IMESSAGE_APP_IPHONE_47",
+            ScreenshotDisplayType::IMessageIphone40 => "IMESSAGE_APP_IPHONE_40",
+            ScreenshotDisplayType::IMessageIpadPro3Gen129 => "IMESSAGE_APP_IPAD_PRO_3GEN_129",
+            ScreenshotDisplayType::IMessageIpadPro129 => "IMESSAGE_APP_IPAD_PRO_129",
+            ScreenshotDisplayType::IMessageIpadPro3Gen11 => "IMESSAGE_APP_IPAD_PRO_3GEN_11",
+            ScreenshotDisplayType::IMessageIpad105 => "IMESSAGE_APP_IPAD_105",
+            ScreenshotDisplayType::IMessageIpad97 => "IMESSAGE_APP_IPAD_97",
+        }
+    }
+
+    pub fn is_imessage(&self) -> bool {
+        self.as_str().starts_with("IMESSAGE_")
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|t| t.as_str() == value)
+    }
+
+    pub fn dimensions(&self) -> &'static [(u32, u32)] {
+        match self {
+            ScreenshotDisplayType::AppIphone67 => &[(1290, 2796), (1320, 2868), (1260, 2736)],
+            ScreenshotDisplayType::AppIphone65 => &[(1242, 2688), (1284, 2778)],
+            ScreenshotDisplayType::AppIphone61 => {
+                &[(1179, 2556), (1170, 2532), (1125, 2436), (1080, 2340)]
+            }
+            ScreenshotDisplayType::AppIphone58 => &[(1125, 2436), (1170, 2532), (1080, 2340)],
+            ScreenshotDisplayType::AppIphone55 => &[(1242, 2208)],
+            ScreenshotDisplayType::AppIphone47 => &[(750, 1334)],
+            ScreenshotDisplayType::AppIphone40 => &[(640, 1136), (640, 1096)],
+            ScreenshotDisplayType::AppIphone35 => &[(640, 960), (640, 920)],
+            ScreenshotDisplayType::AppIpadPro3Gen129 => &[(2048, 2732), (2064, 2752)],
+            ScreenshotDisplayType::AppIpadPro129 => &[(2048, 2732)],
+            ScreenshotDisplayType::AppIpadPro3Gen11 => &[(1668, 2388), (1640, 2360), (1488, 2266)],
+            ScreenshotDisplayType::AppIpad105 => &[(1668, 2224)],
+            ScreenshotDisplayType::AppIpad97 => &[(1536, 2048), (768, 1024)],
+            ScreenshotDisplayType::AppAppleTv => &[(1920, 1080), (3840, 2160)],
+            ScreenshotDisplayType::AppDesktop => {
+                &[(1280, 800), (1440, 900), (2560, 1600), (2880, 1800)]
+            }
+            ScreenshotDisplayType::AppAppleVisionPro => &[(3840, 2160)],
+            ScreenshotDisplayType::AppWatchUltra => &[(410, 502), (422, 514)],
+            ScreenshotDisplayType::AppWatchSeries10 => &[(416, 496), (374, 446)],
+            ScreenshotDisplayType::AppWatchSeries7 => &[(396, 484)],
+            ScreenshotDisplayType::AppWatchSeries4 => &[(368, 448)],
+            ScreenshotDisplayType::AppWatchSeries3 => &[(312, 390)],
+            ScreenshotDisplayType::IMessageIphone67 => {
+                ScreenshotDisplayType::AppIphone67.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIphone65 => {
+                ScreenshotDisplayType::AppIphone65.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIphone61 => {
+                ScreenshotDisplayType::AppIphone61.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIphone58 => {
+                ScreenshotDisplayType::AppIphone58.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIphone55 => {
+                ScreenshotDisplayType::AppIphone55.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIphone47 => {
+                ScreenshotDisplayType::AppIphone47.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIphone40 => {
+                ScreenshotDisplayType::AppIphone40.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIpadPro3Gen129 => {
+                ScreenshotDisplayType::AppIpadPro3Gen129.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIpadPro129 => {
+                ScreenshotDisplayType::AppIpadPro129.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIpadPro3Gen11 => {
+                ScreenshotDisplayType::AppIpadPro3Gen11.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIpad105 => {
+                ScreenshotDisplayType::AppIpad105.dimensions()
+            }
+            ScreenshotDisplayType::IMessageIpad97 => ScreenshotDisplayType::AppIpad97.dimensions(),
+        }
+    }
+
+    pub fn accepts(&self, width: u32, height: u32) -> bool {
+        self.dimensions()
+            .iter()
+            .any(|&(w, h)| (w, h) == (width, height) || (h, w) == (width, height))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+    pub is_rgb: bool,
+}
+
+pub fn inspect_image(data: &[u8]) -> Option<ImageInfo> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") && data.len() >= 26 && &data[12..16] == b"IHDR" {
+        let width = u32::from_be_bytes(data[16..20].try_into().ok()?);
+        let height = u32::from_be_bytes(data[20..24].try_into().ok()?);
+        let color_type = data[25];
+
+        return Some(ImageInfo {
+            format: ImageFormat::Png,
+            width,
+            height,
+            is_rgb: color_type == 2 || color_type == 6,
+        });
+    }
+
+    if data.starts_with(&[0xFF, 0xD8]) {
+        let mut offset = 2;
+        while offset + 4 <= data.len() {
+            if data[offset] != 0xFF {
+                return None;
+            }
+            let marker = data[offset + 1];
+            let length = u16::from_be_bytes([data[offset + 2], data[offset + 3]]) as usize;
+            let is_frame = matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+
+            if is_frame && offset + 10 <= data.len() {
+                let height = u16::from_be_bytes([data[offset + 5], data[offset + 6]]) as u32;
+                let width = u16::from_be_bytes([data[offset + 7], data[offset + 8]]) as u32;
+                let components = data[offset + 9];
+
+                return Some(ImageInfo {
+                    format: ImageFormat::Jpeg,
+                    width,
+                    height,
+                    is_rgb: components == 3,
+                });
+            }
+            offset += 2 + length;
+        }
+    }
+
+    None
+}
+
+pub fn validate_screenshot(
+    data: &[u8],
+    display_type: ScreenshotDisplayType,
+) -> Result<ImageInfo, AppStoreConnectError> {
+    let mut issues = Vec::new();
+
+    if data.len() as u64 > MAX_SCREENSHOT_FILE_SIZE {
+        issues.push(format!(
+            "file size {} bytes exceeds {} bytes",
+            data.len(),
+            MAX_SCREENSHOT_FILE_SIZE
+        ));
+    }
+
+    let info = match inspect_image(data) {
+        Some(info) => info,
+        None => {
+            issues.push("format must be PNG or JPEG".to_string());
+            return Err(AppStoreConnectError::Validation(
+                ValidationError::with_issues(
+                    format!("Screenshot is not valid for {}", display_type.as_str()),
+                    issues,
+                ),
+            ));
+        }
+    };
+
+    if !display_type.accepts(info.width, info.height) {
+        issues.push(format!(
+            "dimensions {}x{} are not accepted for {}",
+            info.width,
+            info.height,
+            display_type.as_str()
+        ));
+    }
+    if !info.is_rgb {
+        issues.push("color space must be RGB".to_string());
+    }
+
+    if issues.is_empty() {
+        Ok(info)
+    } else {
+        Err(AppStoreConnectError::Validation(
+            ValidationError::with_issues(
+                format!("Screenshot is not valid for {}", display_type.as_str()),
+                issues,
+            ),
+        ))
+    }
+}
+
+pub fn asset_url(asset: &Value, format: &str) -> Option<String> {
+    let template = asset.get("templateUrl")?.as_str()?;
+    let width = asset.get("width")?.as_u64()?;
+    let height = asset.get("height")?.as_u64()?;
+
+    Some(
+        template
+            .replace("{w}", &width.to_string())
+            .replace("{h}", &height.to_string())
+            .replace("{f}", format),
+    )
+}
+
+pub fn validate_frame_time_code(time_code: &str) -> Result<(), AppStoreConnectError> {
+    let parts: Vec<&str> = time_code.split(':').collect();
+    let valid = parts.len() == 4
+        && parts
+            .iter()
+            .all(|p| p.len() == 2 && p.chars().all(|c| c.is_ascii_digit()));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(AppStoreConnectError::Validation(ValidationError::new(
+            format!(
+                "Invalid preview frame time code '{}', expected HH:MM:SS:FF",
+                time_code
+            ),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AssetKind {
+    Screenshot,
+    Preview,
+}
+
+impl AssetKind {
+    fn resource_type(&self) -> &'static str {
+        match self {
+            AssetKind::Screenshot => "appScreenshots",
+            AssetKind::Preview => "appPreviews",
+        }
+    }
+}
+
+// Returned by the upload functions so callers can poll the asset without
+// having to know or guess which endpoint it lives under.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetHandle {
+    pub kind: AssetKind,
+    pub id: String,
+}
+
+impl AssetHandle {
+    pub fn screenshot(id: impl Into<String>) -> Self {
+        Self {
+            kind: AssetKind::Screenshot,
+            id: id.into(),
+        }
+    }
+
+    pub fn preview(id: impl Into<String>) -> Self {
+        Self {
+            kind: AssetKind::Preview,
+            id: id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetDeliveryState {
+    pub state: String,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl AssetDeliveryState {
+    pub fn from_value(value: Option<&Value>) -> Self {
+        let messages = |key: &str| -> Vec<String> {
+            value
+                .and_then(|v| v.get(key))
+                .and_then(|m| m.as_array())
+                .map(|messages| {
+                    messages
+                        .iter()
+                        .filter_map(|m| {
+                            m.get("description")
+                                .or_else(|| m.get("code"))
+                                .and_then(|d| d.as_str())
+                        })
+                        .map(|d| d.to_string())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Self {
+            state: value
+                .and_then(|v| v.get("state"))
+                .and_then(|s| s.as_str())
+                .unwrap_or("AWAITING_UPLOAD")
+                .to_string(),
+            errors: messages("errors"),
+            warnings: messages("warnings"),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.state == "COMPLETE"
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.state == "FAILED"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaAsset {
+    pub id: String,
+    pub kind: AssetKind,
+    pub file_name: Option<String>,
+    pub file_size: Option<u64>,
+    pub delivery_state: AssetDeliveryState,
+}
+
+impl MediaAsset {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let kind = match value.get("type")?.as_str()? {
+            "appScreenshots" => AssetKind::Screenshot,
+            "appPreviews" => AssetKind::Preview,
+            _ => return None,
+        };
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            kind,
+            file_name: attributes
+                .and_then(|a| a.get("fileName"))
+                .and_then(|n| n.as_str())
+                .map(|n| n.to_string()),
+            file_size: attributes
+                .and_then(|a| a.get("fileSize"))
+                .and_then(|s| s.as_u64()),
+            delivery_state: AssetDeliveryState::from_value(
+                attributes.and_then(|a| a.get("assetDeliveryState")),
+            ),
+        })
+    }
+    pub fn handle(&self) -> AssetHandle {
+        AssetHandle {
+            kind: self.kind,
+            id: self.id.clone(),
+        }
+    }
+}
+
+fn file_extension(resource: &Value, default: &str) -> String {
+    resource
+        .get("attributes")
+        .and_then(|a| a.get("fileName"))
+        .and_then(|n| n.as_str())
+        .and_then(|n| Path::new(n).extension())
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_else(|| default.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppEventAssetType {
+    EventCard,
+    EventDetailsPage,
+}
+
+impl AppEventAssetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AppEventAssetType::EventCard => "EVENT_CARD",
+            AppEventAssetType::EventDetailsPage => "EVENT_DETAILS_PAGE",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ScreenshotUpload {
+    pub screenshot_set_id: String,
+    pub path: PathBuf,
+}
+
+impl ScreenshotUpload {
+    pub fn new(screenshot_set_id: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            screenshot_set_id: screenshot_set_id.into(),
+            path: path.into(),
+        }
+    }
+}
+
+const REPLACE_PROCESSING_TIMEOUT: Duration = Duration::from_secs(10 * 60);
 
 #[derive(Clone)]
 pub struct MediaAPI {
@@ -18,53 +534,32 @@ impl MediaAPI {
         localization_id: &str,
         display_type: Option<&str>,
     ) -> Result<Vec<Value>, AppStoreConnectError> {
-        let mut endpoint = format!(
-            "appStoreVersionLocalizations/{}/appScreenshotSets",
-            localization_id
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "appScreenshots".to_string());
+        params.insert(
+            "limit[appScreenshots]".to_string(),
+            MAX_SCREENSHOTS_PER_SET.to_string(),
         );
-
         if let Some(display_type) = display_type {
-            endpoint.push_str(&format!("?filter[screenshotDisplayType]={}", display_type));
+            params.insert(
+                "filter[screenshotDisplayType]".to_string(),
+                display_type.to_string(),
+            );
         }
 
-        let response = self.base.get(&endpoint, None).await?;
-        let empty_vec = vec![];
-        let screenshot_sets = response
-            .get("data")
-            .and_then(|d| d.as_array())
-            .unwrap_or(&empty_vec);
-
-        let mut all_screenshots = Vec::new();
-        for set_data in screenshot_sets {
-            if let Some(set_id) = set_data.get("id").and_then(|id| id.as_str()) {
-                let screenshots_response = self
-                    .base
-                    .get(
-                        &format!("appScreenshotSets/{}/appScreenshots", set_id),
-                        None,
-                    )
-                    .await?;
-
-                if let Some(screenshots) =
-                    screenshots_response.get("data").and_then(|d| d.as_array())
-                {
-                    for mut screenshot in screenshots.iter().cloned() {
-                        if let Some(display_type) = set_data
-                            .get("attributes")
-                            .and_then(|a| a.get("screenshotDisplayType"))
-                        {
-                            if let Some(screenshot_obj) = screenshot.as_object_mut() {
-                                screenshot_obj
-                                    .insert("displayType".to_string(), display_type.clone());
-                            }
-                        }
-                        all_screenshots.push(screenshot);
-                    }
-                }
-            }
-        }
+        let (sets, included) = self
+            .base
+            .get_all_pages_with_included(
+                &format!(
+                    "appStoreVersionLocalizations/{}/appScreenshotSets",
+                    localization_id
+                ),
+                Some(params),
+                None,
+            )
+            .await?;
 
-        Ok(all_screenshots)
+        Ok(screenshots_from_sets(&sets, &included))
     }
 
     pub async fn create_screenshot_set(
@@ -98,25 +593,72 @@ impl MediaAPI {
             })
     }
 
-    pub async fn upload_screenshot(
+    pub async fn get_screenshot_set(
+        &self,
+        localization_id: &str,
+        display_type: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert(
+            "filter[screenshotDisplayType]".to_string(),
+            display_type.to_string(),
+        );
+        params.insert("limit".to_string(), "1".to_string());
+
+        let response = self
+            .base
+            .get(
+                &format!(
+                    "appStoreVersionLocalizations/{}/appScreenshotSets",
+                    localization_id
+                ),
+                Some(params),
+            )
+            .await?;
+
+        Ok(response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .and_then(|sets| sets.first())
+            .cloned())
+    }
+
+    pub async fn get_or_create_set(
+        &self,
+        localization_id: &str,
+        display_type: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        if let Some(set) = self
+            .get_screenshot_set(localization_id, display_type)
+            .await?
+        {
+            return Ok(set);
+        }
+
+        match self
+            .create_screenshot_set(localization_id, display_type)
+            .await
+        {
+            Err(AppStoreConnectError::Conflict(e)) => self
+                .get_screenshot_set(localization_id, display_type)
+                .await?
+                .ok_or(AppStoreConnectError::Conflict(e)),
+            result => result,
+        }
+    }
+
+    pub async fn reserve_screenshot(
         &self,
         screenshot_set_id: &str,
         file_name: &str,
         file_size: u64,
-        width: u32,
-        height: u32,
     ) -> Result<Value, AppStoreConnectError> {
         let data = json!({
             "data": {
                 "type": "appScreenshots",
                 "attributes": {
                     "fileSize": file_size,
-                    "fileName": file_name,
-                    "sourceFileChecksum": "",
-                    "imageAsset": {
-                        "width": width,
-                        "height": height
-                    }
+                    "fileName": file_name
                 },
                 "relationships": {
                     "appScreenshotSet": {
@@ -138,54 +680,1102 @@ impl MediaAPI {
             })
     }
 
-    pub async fn delete_screenshot(&self, screenshot_id: &str) -> Result<(), AppStoreConnectError> {
-        self.base
-            .delete(&format!("appScreenshots/{}", screenshot_id))
+    pub async fn commit_screenshot(
+        &self,
+        screenshot_id: &str,
+        checksum: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.commit_asset(AssetKind::Screenshot, screenshot_id, checksum)
+            .await
+    }
+
+    pub async fn upload_screenshot_file(
+        &self,
+        screenshot_set_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        let path = path.as_ref();
+        let (file_name, file_size) = upload_file_info(path).await?;
+
+        let reservation = self
+            .reserve_screenshot(screenshot_set_id, &file_name, file_size)
             .await?;
-        Ok(())
+        self.upload_reserved(AssetKind::Screenshot, &reservation, path)
+            .await
     }
 
-    pub fn get_display_types() -> HashMap<&'static str, Vec<&'static str>> {
-        let mut types = HashMap::new();
+    // Picks up an upload interrupted in `upload_screenshot_file` from the
+    // progress saved next to the file; completed parts are not sent again.
+    pub async fn resume_screenshot_upload(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        self.resume_asset_upload(AssetKind::Screenshot, path.as_ref())
+            .await
+    }
 
-        types.insert(
-            "iphone",
-            vec![
-                "APP_IPHONE_65",
-                "APP_IPHONE_61",
-                "APP_IPHONE_58",
-                "APP_IPHONE_55",
-                "APP_IPHONE_47",
-                "APP_IPHONE_40",
-                "APP_IPHONE_35",
-            ],
-        );
+    pub async fn resume_screenshot_reservation(
+        &self,
+        screenshot_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        self.resume_reserved_asset(AssetKind::Screenshot, screenshot_id, path.as_ref())
+            .await
+    }
 
-        types.insert(
-            "ipad",
-            vec![
-                "APP_IPAD_PRO_129",
-                "APP_IPAD_PRO_3GEN_129",
-                "APP_IPAD_PRO_3GEN_11",
-                "APP_IPAD_105",
-                "APP_IPAD_97",
-            ],
-        );
+    async fn commit_asset(
+        &self,
+        kind: AssetKind,
+        asset_id: &str,
+        checksum: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": kind.resource_type(),
+                "id": asset_id,
+                "attributes": {
+                    "uploaded": true,
+                    "sourceFileChecksum": checksum
+                }
+            }
+        });
 
-        types.insert("apple_tv", vec!["APP_APPLE_TV"]);
+        let response = self
+            .base
+            .patch(&format!("{}/{}", kind.resource_type(), asset_id), data)
+            .await?;
 
-        types.insert(
-            "apple_watch",
-            vec![
-                "APP_WATCH_ULTRA",
-                "APP_WATCH_SERIES_7",
-                "APP_WATCH_SERIES_4",
-                "APP_WATCH_SERIES_3",
-            ],
-        );
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
 
-        types.insert("mac", vec!["APP_DESKTOP"]);
+    async fn upload_reserved(
+        &self,
+        kind: AssetKind,
+        reservation: &Value,
+        path: &Path,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        let asset_id = reservation
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: format!("Invalid {} ID", kind.resource_type()),
+            })?;
 
-        types
+        let operations = UploadOperation::from_resource(reservation);
+        let mut progress = UploadProgress::new(asset_id);
+        // Saved before any part is sent, so even an upload killed right after
+        // the reservation can be resumed.
+        let _ = progress.save(&UploadProgress::sidecar_path(path)).await;
+
+        self.finish_upload(kind, &operations, path, &mut progress)
+            .await
+    }
+
+    async fn resume_asset_upload(
+        &self,
+        kind: AssetKind,
+        path: &Path,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        let progress = UploadProgress::load(&UploadProgress::sidecar_path(path)).await?;
+        self.resume_reserved_asset(kind, &progress.reservation_id, path)
+            .await
+    }
+
+    // Parts recorded in the file's saved progress are skipped when it belongs
+    // to the same reservation; otherwise every part is sent again.
+    async fn resume_reserved_asset(
+        &self,
+        kind: AssetKind,
+        reservation_id: &str,
+        path: &Path,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        let mut progress = UploadProgress::load(&UploadProgress::sidecar_path(path))
+            .await
+            .ok()
+            .filter(|saved| saved.reservation_id == reservation_id)
+            .unwrap_or_else(|| UploadProgress::new(reservation_id));
+
+        let response = self
+            .base
+            .get(
+                &format!("{}/{}", kind.resource_type(), reservation_id),
+                None,
+            )
+            .await?;
+        let resource = response
+            .get("data")
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })?;
+
+        let operations = UploadOperation::from_resource(resource);
+        self.finish_upload(kind, &operations, path, &mut progress)
+            .await
+    }
+
+    // Progress is kept next to the file after every part and removed once the
+    // asset is committed.
+    async fn finish_upload(
+        &self,
+        kind: AssetKind,
+        operations: &[UploadOperation],
+        path: &Path,
+        progress: &mut UploadProgress,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        let sidecar = UploadProgress::sidecar_path(path);
+        upload_parts(
+            &self.base,
+            operations,
+            path,
+            progress,
+            &RetryOptions::default(),
+            Some(&sidecar),
+        )
+        .await?;
+
+        let checksum = md5_file(path).await?;
+        self.commit_asset(kind, &progress.reservation_id, &checksum)
+            .await?;
+
+        if tokio::fs::try_exists(&sidecar).await.unwrap_or(false) {
+            tokio::fs::remove_file(&sidecar).await?;
+        }
+        Ok(AssetHandle {
+            kind,
+            id: progress.reservation_id.clone(),
+        })
+    }
+
+    pub async fn upload_app_event_screenshot(
+        &self,
+        event_localization_id: &str,
+        asset_type: AppEventAssetType,
+        path: impl AsRef<Path>,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.upload_app_event_asset(
+            "appEventScreenshots",
+            json!({ "appEventAssetType": asset_type.as_str() }),
+            event_localization_id,
+            path.as_ref(),
+        )
+        .await
+    }
+
+    pub async fn upload_app_event_video_clip(
+        &self,
+        event_localization_id: &str,
+        asset_type: AppEventAssetType,
+        path: impl AsRef<Path>,
+        preview_frame_time_code: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({ "appEventAssetType": asset_type.as_str() });
+        if let Some(time_code) = preview_frame_time_code {
+            validate_frame_time_code(time_code)?;
+            attributes["previewFrameTimeCode"] = json!(time_code);
+        }
+
+        self.upload_app_event_asset(
+            "appEventVideoClips",
+            attributes,
+            event_localization_id,
+            path.as_ref(),
+        )
+        .await
+    }
+
+    pub async fn get_app_event_screenshots(
+        &self,
+        event_localization_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!(
+                    "appEventLocalizations/{}/appEventScreenshots",
+                    event_localization_id
+                ),
+                None,
+                None,
+            )
+            .await
     }
+
+    pub async fn get_app_event_video_clips(
+        &self,
+        event_localization_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!(
+                    "appEventLocalizations/{}/appEventVideoClips",
+                    event_localization_id
+                ),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn delete_app_event_screenshot(
+        &self,
+        screenshot_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("appEventScreenshots/{}", screenshot_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete_app_event_video_clip(
+        &self,
+        video_clip_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("appEventVideoClips/{}", video_clip_id))
+            .await?;
+        Ok(())
+    }
+
+    async fn upload_app_event_asset(
+        &self,
+        resource_type: &str,
+        mut attributes: Value,
+        event_localization_id: &str,
+        path: &Path,
+    ) -> Result<Value, AppStoreConnectError> {
+        let (file_name, file_size) = upload_file_info(path).await?;
+        attributes["fileName"] = json!(file_name);
+        attributes["fileSize"] = json!(file_size);
+
+        let reservation = self
+            .base
+            .post(
+                resource_type,
+                json!({
+                    "data": {
+                        "type": resource_type,
+                        "attributes": attributes,
+                        "relationships": {
+                            "appEventLocalization": {
+                                "data": {
+                                    "type": "appEventLocalizations",
+                                    "id": event_localization_id
+                                }
+                            }
+                        }
+                    }
+                }),
+            )
+            .await?;
+        let reservation =
+            reservation
+                .get("data")
+                .cloned()
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: "Invalid response format".to_string(),
+                })?;
+        let asset_id = reservation
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: format!("Invalid {} ID", resource_type),
+            })?;
+
+        let operations = UploadOperation::from_resource(&reservation);
+        upload_parts(
+            &self.base,
+            &operations,
+            path,
+            &mut UploadProgress::new(asset_id),
+            &RetryOptions::default(),
+            None,
+        )
+        .await?;
+
+        let response = self
+            .base
+            .patch(
+                &format!("{}/{}", resource_type, asset_id),
+                json!({
+                    "data": {
+                        "type": resource_type,
+                        "id": asset_id,
+                        "attributes": {
+                            "uploaded": true,
+                            "sourceFileChecksum": md5_file(path).await?
+                        }
+                    }
+                }),
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn upload_validated_screenshot(
+        &self,
+        screenshot_set_id: &str,
+        display_type: ScreenshotDisplayType,
+        path: impl AsRef<Path>,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        let data = tokio::fs::read(path.as_ref()).await?;
+        validate_screenshot(&data, display_type)?;
+        self.upload_screenshot_file(screenshot_set_id, path).await
+    }
+
+    pub async fn upload_screenshots(
+        &self,
+        uploads: Vec<ScreenshotUpload>,
+        max_concurrency: usize,
+    ) -> Vec<(ScreenshotUpload, Result<AssetHandle, AppStoreConnectError>)> {
+        let semaphore = Arc::new(Semaphore::new(max_concurrency.max(1)));
+        let mut tasks = JoinSet::new();
+
+        for (index, upload) in uploads.iter().cloned().enumerate() {
+            let media = self.clone();
+            let semaphore = semaphore.clone();
+
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let result = media
+                    .upload_screenshot_file(&upload.screenshot_set_id, &upload.path)
+                    .await;
+                (index, result)
+            });
+        }
+
+        let mut results: Vec<Option<Result<AssetHandle, AppStoreConnectError>>> =
+            uploads.iter().map(|_| None).collect();
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok((index, result)) = joined {
+                results[index] = Some(result);
+            }
+        }
+
+        uploads
+            .into_iter()
+            .zip(results)
+            .map(|(upload, result)| {
+                let result = result.unwrap_or_else(|| {
+                    Err(AppStoreConnectError::Unknown(
+                        "Upload task did not complete".to_string(),
+                    ))
+                });
+                (upload, result)
+            })
+            .collect()
+    }
+
+    #[deprecated(note = "only reserves the asset; use upload_screenshot_file for the full upload")]
+    pub async fn upload_screenshot(
+        &self,
+        screenshot_set_id: &str,
+        file_name: &str,
+        file_size: u64,
+        _width: u32,
+        _height: u32,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.reserve_screenshot(screenshot_set_id, file_name, file_size)
+            .await
+    }
+
+    pub async fn delete_screenshot(&self, screenshot_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("appScreenshots/{}", screenshot_id))
+            .await?;
+        Ok(())
+    }
+
+    pub fn get_display_types() -> HashMap<&'static str, Vec<&'static str>> {
+        let mut types = HashMap::new();
+
+        types.insert(
+            "iphone",
+            vec![
+                "APP_IPHONE_67",
+                "APP_IPHONE_65",
+                "APP_IPHONE_61",
+                "APP_IPHONE_58",
+                "APP_IPHONE_55",
+                "APP_IPHONE_47",
+                "APP_IPHONE_40",
+                "APP_IPHONE_35",
+            ],
+        );
+
+        types.insert(
+            "ipad",
+            vec![
+                "APP_IPAD_PRO_129",
+                "APP_IPAD_PRO_3GEN_129",
+                "APP_IPAD_PRO_3GEN_11",
+                "APP_IPAD_105",
+                "APP_IPAD_97",
+            ],
+        );
+
+        types.insert("apple_tv", vec!["APP_APPLE_TV"]);
+
+        types.insert(
+            "apple_watch",
+            vec![
+                "APP_WATCH_ULTRA",
+                "APP_WATCH_SERIES_10",
+                "APP_WATCH_SERIES_7",
+                "APP_WATCH_SERIES_4",
+                "APP_WATCH_SERIES_3",
+            ],
+        );
+
+        types.insert("mac", vec!["APP_DESKTOP"]);
+
+        types.insert("vision_pro", vec!["APP_APPLE_VISION_PRO"]);
+
+        types.insert(
+            "imessage",
+            ScreenshotDisplayType::ALL
+                .iter()
+                .filter(|t| t.is_imessage())
+                .map(|t| t.as_str())
+                .collect(),
+        );
+
+        types
+    }
+
+    pub async fn get_screenshot(&self, screenshot_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("appScreenshots/{}", screenshot_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn wait_for_screenshot_processing(
+        &self,
+        screenshot_id: &str,
+        timeout: Duration,
+    ) -> Result<MediaAsset, AppStoreConnectError> {
+        self.wait_for_asset(AssetKind::Screenshot, screenshot_id, timeout)
+            .await
+    }
+
+    pub async fn replace_set(
+        &self,
+        localization_id: &str,
+        display_type: &str,
+        files: Vec<PathBuf>,
+    ) -> Result<Vec<MediaAsset>, AppStoreConnectError> {
+        if files.len() > MAX_SCREENSHOTS_PER_SET {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                format!(
+                    "A screenshot set holds at most {} screenshots, got {}",
+                    MAX_SCREENSHOTS_PER_SET,
+                    files.len()
+                ),
+            )));
+        }
+
+        let set = self
+            .get_or_create_set(localization_id, display_type)
+            .await?;
+        let set_id =
+            set.get("id")
+                .and_then(|i| i.as_str())
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: "Invalid screenshot set ID".to_string(),
+                })?;
+
+        let old_ids = self.get_screenshot_order(set_id).await?;
+
+        // Old and new screenshots sit side by side until the new ones have
+        // processed. Once the set is full, each further upload swaps out the
+        // last remaining old screenshot, saved to disk first so a rollback
+        // can put it back. The set never drops below a full set minus one.
+        let free_slots = MAX_SCREENSHOTS_PER_SET.saturating_sub(old_ids.len());
+        let snapshot_dir = std::env::temp_dir().join(format!("asc-replace-set-{}", set_id));
+        let mut kept_ids = old_ids.clone();
+        let mut removed = Vec::new();
+
+        let result = async {
+            let mut uploaded = Vec::new();
+            for (index, file) in files.iter().enumerate() {
+                if index >= free_slots {
+                    if let Some(old_id) = kept_ids.pop() {
+                        let path = self.snapshot_screenshot(&old_id, &snapshot_dir).await?;
+                        self.delete_screenshot(&old_id).await?;
+                        removed.push(path);
+                    }
+                }
+
+                let screenshot = self.upload_screenshot_file(set_id, file).await?;
+                uploaded.push(
+                    self.wait_until_processed(&screenshot, REPLACE_PROCESSING_TIMEOUT)
+                        .await?,
+                );
+            }
+            Ok(uploaded)
+        }
+        .await;
+
+        let uploaded = match result {
+            Ok(uploaded) => uploaded,
+            Err(e) => {
+                let rollback_errors = self.roll_back_set(set_id, &old_ids, &removed).await;
+                if rollback_errors.is_empty() {
+                    let _ = tokio::fs::remove_dir_all(&snapshot_dir).await;
+                    return Err(e);
+                }
+
+                // The snapshots may be the only copies of the screenshots
+                // swapped out, so they stay on disk for the caller.
+                return Err(AppStoreConnectError::RollbackFailed {
+                    source: Box::new(e),
+                    rollback_errors,
+                    snapshot_dir: (!removed.is_empty()).then_some(snapshot_dir),
+                });
+            }
+        };
+        let _ = tokio::fs::remove_dir_all(&snapshot_dir).await;
+
+        for old_id in &kept_ids {
+            self.delete_screenshot(old_id).await?;
+        }
+
+        let new_ids: Vec<&str> = uploaded.iter().map(|s| s.id.as_str()).collect();
+        if !new_ids.is_empty() {
+            self.reorder_screenshots(set_id, &new_ids).await?;
+        }
+
+        Ok(uploaded)
+    }
+
+    async fn snapshot_screenshot(
+        &self,
+        screenshot_id: &str,
+        dir: &Path,
+    ) -> Result<PathBuf, AppStoreConnectError> {
+        tokio::fs::create_dir_all(dir).await?;
+
+        let screenshot = self.get_screenshot(screenshot_id).await?;
+        let extension = file_extension(&screenshot, "png");
+        let url = screenshot
+            .get("attributes")
+            .and_then(|a| a.get("imageAsset"))
+            .and_then(|asset| asset_url(asset, &extension))
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: format!("Screenshot {} has no image to back up", screenshot_id),
+            })?;
+
+        let path = dir.join(format!("{}.{}", screenshot_id, extension));
+        self.base.download_url_to_file(&url, &path).await?;
+
+        Ok(path)
+    }
+
+    // Best effort: every step is attempted and failures are collected so the
+    // caller still sees the error that started the rollback. Screenshots were
+    // swapped out from the end, so restoring in reverse keeps their order.
+    async fn roll_back_set(
+        &self,
+        set_id: &str,
+        old_ids: &[String],
+        removed: &[PathBuf],
+    ) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        match self.get_screenshot_order(set_id).await {
+            Ok(current_ids) => {
+                for id in current_ids.iter().filter(|id| !old_ids.contains(id)) {
+                    if let Err(e) = self.delete_screenshot(id).await {
+                        errors.push(format!("deleting new screenshot {}: {}", id, e));
+                    }
+                }
+            }
+            Err(e) => errors.push(format!("listing set {}: {}", set_id, e)),
+        }
+
+        for path in removed.iter().rev() {
+            if let Err(e) = self.upload_screenshot_file(set_id, path).await {
+                errors.push(format!("restoring {}: {}", path.display(), e));
+            }
+        }
+
+        errors
+    }
+
+    pub async fn get_screenshot_order(
+        &self,
+        screenshot_set_id: &str,
+    ) -> Result<Vec<String>, AppStoreConnectError> {
+        let linkages = self
+            .base
+            .get_all_pages(
+                &format!(
+                    "appScreenshotSets/{}/relationships/appScreenshots",
+                    screenshot_set_id
+                ),
+                None,
+                None,
+            )
+            .await?;
+
+        Ok(linkages
+            .iter()
+            .filter_map(|l| l.get("id").and_then(|i| i.as_str()))
+            .map(|id| id.to_string())
+            .collect())
+    }
+
+    pub async fn reorder_screenshots(
+        &self,
+        screenshot_set_id: &str,
+        ordered_ids: &[&str],
+    ) -> Result<(), AppStoreConnectError> {
+        let mut current = self.get_screenshot_order(screenshot_set_id).await?;
+        let mut requested: Vec<String> = ordered_ids.iter().map(|id| id.to_string()).collect();
+        current.sort();
+        requested.sort();
+
+        if current != requested {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                format!(
+                    "Screenshot order for set {} must list exactly the screenshots in the set",
+                    screenshot_set_id
+                ),
+            )));
+        }
+
+        let data = json!({ "data": linkages("appScreenshots", ordered_ids) });
+
+        self.base
+            .patch(
+                &format!(
+                    "appScreenshotSets/{}/relationships/appScreenshots",
+                    screenshot_set_id
+                ),
+                data,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_preview_sets(
+        &self,
+        localization_id: &str,
+        preview_type: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        if let Some(preview_type) = preview_type {
+            params.insert("filter[previewType]".to_string(), preview_type.to_string());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!(
+                    "appStoreVersionLocalizations/{}/appPreviewSets",
+                    localization_id
+                ),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn create_preview_set(
+        &self,
+        localization_id: &str,
+        preview_type: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "appPreviewSets",
+                "attributes": {
+                    "previewType": preview_type
+                },
+                "relationships": {
+                    "appStoreVersionLocalization": {
+                        "data": {
+                            "type": "appStoreVersionLocalizations",
+                            "id": localization_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("appPreviewSets", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete_preview_set(
+        &self,
+        preview_set_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("appPreviewSets/{}", preview_set_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_previews(
+        &self,
+        preview_set_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("appPreviewSets/{}/appPreviews", preview_set_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_preview(&self, preview_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("appPreviews/{}", preview_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn reserve_preview(
+        &self,
+        preview_set_id: &str,
+        file_name: &str,
+        file_size: u64,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "appPreviews",
+                "attributes": {
+                    "fileName": file_name,
+                    "fileSize": file_size
+                },
+                "relationships": {
+                    "appPreviewSet": {
+                        "data": {
+                            "type": "appPreviewSets",
+                            "id": preview_set_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("appPreviews", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn commit_preview(
+        &self,
+        preview_id: &str,
+        checksum: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.commit_asset(AssetKind::Preview, preview_id, checksum)
+            .await
+    }
+
+    // Previews can be several hundred megabytes; parts are read from disk one
+    // at a time and progress is saved next to the file as they complete.
+    pub async fn upload_preview_file(
+        &self,
+        preview_set_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        let path = path.as_ref();
+        let (file_name, file_size) = upload_file_info(path).await?;
+
+        let reservation = self
+            .reserve_preview(preview_set_id, &file_name, file_size)
+            .await?;
+        self.upload_reserved(AssetKind::Preview, &reservation, path)
+            .await
+    }
+
+    pub async fn resume_preview_upload(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        self.resume_asset_upload(AssetKind::Preview, path.as_ref())
+            .await
+    }
+
+    pub async fn resume_preview_reservation(
+        &self,
+        preview_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<AssetHandle, AppStoreConnectError> {
+        self.resume_reserved_asset(AssetKind::Preview, preview_id, path.as_ref())
+            .await
+    }
+
+    pub async fn set_preview_frame_time_code(
+        &self,
+        preview_id: &str,
+        time_code: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        validate_frame_time_code(time_code)?;
+
+        let data = json!({
+            "data": {
+                "type": "appPreviews",
+                "id": preview_id,
+                "attributes": {
+                    "previewFrameTimeCode": time_code
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("appPreviews/{}", preview_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn wait_for_preview_processing(
+        &self,
+        preview_id: &str,
+        timeout: Duration,
+    ) -> Result<MediaAsset, AppStoreConnectError> {
+        self.wait_for_asset(AssetKind::Preview, preview_id, timeout)
+            .await
+    }
+
+    pub async fn get_asset(
+        &self,
+        kind: AssetKind,
+        asset_id: &str,
+    ) -> Result<MediaAsset, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("{}/{}", kind.resource_type(), asset_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(MediaAsset::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn wait_until_processed(
+        &self,
+        asset: &AssetHandle,
+        timeout: Duration,
+    ) -> Result<MediaAsset, AppStoreConnectError> {
+        self.wait_for_asset(asset.kind, &asset.id, timeout).await
+    }
+
+    async fn wait_for_asset(
+        &self,
+        kind: AssetKind,
+        asset_id: &str,
+        timeout: Duration,
+    ) -> Result<MediaAsset, AppStoreConnectError> {
+        let options = PollOptions::new(timeout, Duration::from_secs(5));
+        let description = format!("{} {} to finish processing", kind.resource_type(), asset_id);
+
+        poll_until(&description, &options, || async {
+            let asset = self.get_asset(kind, asset_id).await?;
+
+            if asset.delivery_state.is_complete() {
+                Ok(Some(asset))
+            } else if asset.delivery_state.is_failed() {
+                Err(AppStoreConnectError::Api {
+                    message: format!(
+                        "{} {} failed processing: {}",
+                        kind.resource_type(),
+                        asset_id,
+                        asset.delivery_state.errors.join("; ")
+                    ),
+                })
+            } else {
+                Ok(None)
+            }
+        })
+        .await
+    }
+
+    pub async fn set_poster_frame(
+        &self,
+        preview_id: &str,
+        time_code: &str,
+        timeout: Duration,
+    ) -> Result<Value, AppStoreConnectError> {
+        validate_frame_time_code(time_code)?;
+        self.wait_for_preview_processing(preview_id, timeout)
+            .await?;
+        self.set_preview_frame_time_code(preview_id, time_code)
+            .await
+    }
+
+    pub async fn download_all(
+        &self,
+        localization_id: &str,
+        dest_dir: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>, AppStoreConnectError> {
+        let dest_dir = dest_dir.as_ref();
+        tokio::fs::create_dir_all(dest_dir).await?;
+        let mut paths = Vec::new();
+
+        let screenshot_sets = self
+            .base
+            .get_all_pages(
+                &format!(
+                    "appStoreVersionLocalizations/{}/appScreenshotSets",
+                    localization_id
+                ),
+                None,
+                None,
+            )
+            .await?;
+
+        for set in &screenshot_sets {
+            let (set_id, display_type) = match set_identity(set, "screenshotDisplayType") {
+                Some(identity) => identity,
+                None => continue,
+            };
+            let screenshots = self
+                .base
+                .get_all_pages(
+                    &format!("appScreenshotSets/{}/appScreenshots", set_id),
+                    None,
+                    None,
+                )
+                .await?;
+
+            for (index, screenshot) in screenshots.iter().enumerate() {
+                let extension = file_extension(screenshot, "png");
+                let url = screenshot
+                    .get("attributes")
+                    .and_then(|a| a.get("imageAsset"))
+                    .and_then(|asset| asset_url(asset, &extension));
+
+                if let Some(url) = url {
+                    let path =
+                        dest_dir.join(format!("{}_{:02}.{}", display_type, index + 1, extension));
+                    self.base.download_url_to_file(&url, &path).await?;
+                    paths.push(path);
+                }
+            }
+        }
+
+        let preview_sets = self.get_preview_sets(localization_id, None).await?;
+
+        for set in &preview_sets {
+            let (set_id, preview_type) = match set_identity(set, "previewType") {
+                Some(identity) => identity,
+                None => continue,
+            };
+            let previews = self.get_previews(&set_id).await?;
+
+            for (index, preview) in previews.iter().enumerate() {
+                let extension = file_extension(preview, "mp4");
+                let url = preview
+                    .get("attributes")
+                    .and_then(|a| a.get("videoUrl"))
+                    .and_then(|u| u.as_str())
+                    .map(|u| u.to_string());
+
+                if let Some(url) = url {
+                    let path =
+                        dest_dir.join(format!("{}_{:02}.{}", preview_type, index + 1, extension));
+                    self.base.download_url_to_file(&url, &path).await?;
+                    paths.push(path);
+                }
+            }
+        }
+
+        Ok(paths)
+    }
+}
+
+pub fn screenshots_from_sets(sets: &[Value], included: &[Value]) -> Vec<Value> {
+    let screenshots: HashMap<&str, &Value> = included
+        .iter()
+        .filter(|r| r.get("type").and_then(|t| t.as_str()) == Some("appScreenshots"))
+        .filter_map(|r| Some((r.get("id")?.as_str()?, r)))
+        .collect();
+
+    let mut all_screenshots = Vec::new();
+    for set in sets {
+        let display_type = set
+            .get("attributes")
+            .and_then(|a| a.get("screenshotDisplayType"))
+            .cloned();
+        let linked = set
+            .get("relationships")
+            .and_then(|r| r.get("appScreenshots"))
+            .and_then(|r| r.get("data"))
+            .and_then(|d| d.as_array());
+
+        for linkage in linked.into_iter().flatten() {
+            let screenshot = linkage
+                .get("id")
+                .and_then(|i| i.as_str())
+                .and_then(|id| screenshots.get(id));
+
+            if let Some(screenshot) = screenshot {
+                let mut screenshot = (*screenshot).clone();
+                if let (Some(display_type), Some(screenshot_obj)) =
+                    (&display_type, screenshot.as_object_mut())
+                {
+                    screenshot_obj.insert("displayType".to_string(), display_type.clone());
+                }
+                all_screenshots.push(screenshot);
+            }
+        }
+    }
+
+    all_screenshots
+}
+
+fn set_identity(set: &Value, type_attribute: &str) -> Option<(String, String)> {
+    let set_id = set.get("id")?.as_str()?.to_string();
+    let set_type = set
+        .get("attributes")?
+        .get(type_attribute)?
+        .as_str()?
+        .to_string();
+    Some((set_id, set_type))
 }
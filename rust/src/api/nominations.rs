@@ -0,0 +1,156 @@
+use crate::base::{linkages, BaseAPI};
+use crate::error::AppStoreConnectError;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct NominationsAPI {
+    base: BaseAPI,
+}
+
+impl NominationsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(
+        &self,
+        submitted: bool,
+        nomination_type: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[submitted]".to_string(), submitted.to_string());
+
+        if let Some(nomination_type) = nomination_type {
+            params.insert("filter[type]".to_string(), nomination_type.to_string());
+        }
+
+        self.base
+            .get_all_pages("nominations", Some(params), None)
+            .await
+    }
+
+    pub async fn get(&self, nomination_id: &str) -> Result<Value, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert(
+            "include".to_string(),
+            "relatedApps,supportedTerritories".to_string(),
+        );
+
+        let response = self
+            .base
+            .get(&format!("nominations/{}", nomination_id), Some(params))
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn create(
+        &self,
+        name: &str,
+        nomination_type: &str,
+        description: &str,
+        publish_start_date: &str,
+        related_app_ids: &[&str],
+        territories: &[&str],
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "nominations",
+                "attributes": {
+                    "name": name,
+                    "type": nomination_type,
+                    "description": description,
+                    "publishStartDate": publish_start_date,
+                    "submitted": false
+                },
+                "relationships": {
+                    "relatedApps": {
+                        "data": linkages("apps", related_app_ids)
+                    },
+                    "supportedTerritories": {
+                        "data": linkages("territories", territories)
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("nominations", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        nomination_id: &str,
+        attributes: Value,
+        related_app_ids: Option<&[&str]>,
+        territories: Option<&[&str]>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut data = json!({
+            "data": {
+                "type": "nominations",
+                "id": nomination_id,
+                "attributes": attributes
+            }
+        });
+
+        let mut relationships = serde_json::Map::new();
+        if let Some(app_ids) = related_app_ids {
+            relationships.insert(
+                "relatedApps".to_string(),
+                json!({ "data": linkages("apps", app_ids) }),
+            );
+        }
+        if let Some(territories) = territories {
+            relationships.insert(
+                "supportedTerritories".to_string(),
+                json!({ "data": linkages("territories", territories) }),
+            );
+        }
+        if !relationships.is_empty() {
+            data["data"]["relationships"] = Value::Object(relationships);
+        }
+
+        let response = self
+            .base
+            .patch(&format!("nominations/{}", nomination_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn submit(&self, nomination_id: &str) -> Result<Value, AppStoreConnectError> {
+        self.update(nomination_id, json!({ "submitted": true }), None, None)
+            .await
+    }
+
+    pub async fn delete(&self, nomination_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("nominations/{}", nomination_id))
+            .await?;
+        Ok(())
+    }
+
+    pub fn get_nomination_types() -> HashMap<&'static str, &'static str> {
+        let mut types = HashMap::new();
+        types.insert("APP_LAUNCH", "App Launch");
+        types.insert("APP_ENHANCEMENTS", "App Enhancements");
+        types.insert("NEW_CONTENT", "New Content");
+        types
+    }
+}
@@ -0,0 +1,242 @@
+use crate::api::promotional_offers::{offer_prices, OfferPrice};
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+use std::path::Path;
+
+#[derive(Clone)]
+pub struct OfferCodesAPI {
+    base: BaseAPI,
+}
+
+impl OfferCodesAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, subscription_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("subscriptions/{}/offerCodes", subscription_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get(&self, offer_code_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("subscriptionOfferCodes/{}", offer_code_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        subscription_id: &str,
+        name: &str,
+        customer_eligibilities: &[&str],
+        offer_eligibility: &str,
+        duration: &str,
+        offer_mode: &str,
+        number_of_periods: u32,
+        prices: &[OfferPrice],
+    ) -> Result<Value, AppStoreConnectError> {
+        if customer_eligibilities.is_empty() {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                "At least one customer eligibility is required for an offer code",
+            )));
+        }
+
+        let (linkages, included) = offer_prices("subscriptionOfferCodePrices", prices);
+
+        let data = json!({
+            "data": {
+                "type": "subscriptionOfferCodes",
+                "attributes": {
+                    "name": name,
+                    "customerEligibilities": customer_eligibilities,
+                    "offerEligibility": offer_eligibility,
+                    "duration": duration,
+                    "offerMode": offer_mode,
+                    "numberOfPeriods": number_of_periods
+                },
+                "relationships": {
+                    "subscription": {
+                        "data": {
+                            "type": "subscriptions",
+                            "id": subscription_id
+                        }
+                    },
+                    "prices": {
+                        "data": linkages
+                    }
+                }
+            },
+            "included": included
+        });
+
+        let response = self.base.post("subscriptionOfferCodes", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn set_active(
+        &self,
+        offer_code_id: &str,
+        active: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "subscriptionOfferCodes",
+                "id": offer_code_id,
+                "attributes": {
+                    "active": active
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("subscriptionOfferCodes/{}", offer_code_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn create_custom_code(
+        &self,
+        offer_code_id: &str,
+        custom_code: &str,
+        number_of_codes: u32,
+        expiration_date: Option<NaiveDate>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({
+            "customCode": custom_code,
+            "numberOfCodes": number_of_codes
+        });
+
+        if let Some(date) = expiration_date {
+            attributes["expirationDate"] = json!(date.format("%Y-%m-%d").to_string());
+        }
+
+        let data = json!({
+            "data": {
+                "type": "subscriptionOfferCodeCustomCodes",
+                "attributes": attributes,
+                "relationships": {
+                    "offerCode": {
+                        "data": {
+                            "type": "subscriptionOfferCodes",
+                            "id": offer_code_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .post("subscriptionOfferCodeCustomCodes", data)
+            .await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_custom_codes(
+        &self,
+        offer_code_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("subscriptionOfferCodes/{}/customCodes", offer_code_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn create_one_time_codes(
+        &self,
+        offer_code_id: &str,
+        number_of_codes: u32,
+        expiration_date: NaiveDate,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "subscriptionOfferCodeOneTimeUseCodes",
+                "attributes": {
+                    "numberOfCodes": number_of_codes,
+                    "expirationDate": expiration_date.format("%Y-%m-%d").to_string()
+                },
+                "relationships": {
+                    "offerCode": {
+                        "data": {
+                            "type": "subscriptionOfferCodes",
+                            "id": offer_code_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .post("subscriptionOfferCodeOneTimeUseCodes", data)
+            .await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_one_time_code_batches(
+        &self,
+        offer_code_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("subscriptionOfferCodes/{}/oneTimeUseCodes", offer_code_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn download_one_time_codes(
+        &self,
+        batch_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<u64, AppStoreConnectError> {
+        self.base
+            .download_to_file(
+                &format!("subscriptionOfferCodeOneTimeUseCodes/{}/values", batch_id),
+                path.as_ref(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,468 @@
+use crate::base::{BaseAPI, V3_BASE_URL};
+use crate::error::{AppStoreConnectError, NotFoundError, ValidationError};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledPrice {
+    pub price_point_id: String,
+    pub start_date: Option<NaiveDate>,
+}
+
+impl ScheduledPrice {
+    pub fn immediate(price_point_id: impl Into<String>) -> Self {
+        Self {
+            price_point_id: price_point_id.into(),
+            start_date: None,
+        }
+    }
+
+    pub fn starting(price_point_id: impl Into<String>, start_date: NaiveDate) -> Self {
+        Self {
+            price_point_id: price_point_id.into(),
+            start_date: Some(start_date),
+        }
+    }
+}
+
+pub(crate) fn manual_prices(
+    price_type: &str,
+    price_point_type: &str,
+    price_point_relationship: &str,
+    prices: &[ScheduledPrice],
+) -> (Vec<Value>, Vec<Value>) {
+    let mut linkages = Vec::new();
+    let mut included = Vec::new();
+
+    for (index, price) in prices.iter().enumerate() {
+        let id = format!("${{price-{}}}", index);
+        linkages.push(json!({ "type": price_type, "id": id }));
+
+        let mut resource = json!({
+            "type": price_type,
+            "id": id,
+            "attributes": {
+                "startDate": price.start_date.map(|d| d.format("%Y-%m-%d").to_string())
+            },
+            "relationships": {}
+        });
+        resource["relationships"][price_point_relationship] = json!({
+            "data": { "type": price_point_type, "id": price.price_point_id }
+        });
+        included.push(resource);
+    }
+
+    (linkages, included)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceTimelineEntry {
+    pub territory: String,
+    pub price_point_id: String,
+    pub customer_price: Option<String>,
+    pub proceeds: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub manual: bool,
+}
+
+pub fn build_price_timeline(prices: &[Value], included: &[Value]) -> Vec<PriceTimelineEntry> {
+    let related_id = |price: &Value, name: &str| {
+        price
+            .get("relationships")
+            .and_then(|r| r.get(name))
+            .and_then(|r| r.get("data"))
+            .and_then(|d| d.get("id"))
+            .and_then(|i| i.as_str())
+            .map(|id| id.to_string())
+    };
+    let date = |price: &Value, name: &str| {
+        price
+            .get("attributes")
+            .and_then(|a| a.get(name))
+            .and_then(|d| d.as_str())
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+    };
+
+    let mut timeline: Vec<PriceTimelineEntry> = prices
+        .iter()
+        .filter_map(|price| {
+            let price_point_id = related_id(price, "appPricePoint")?;
+            let point = included.iter().find(|resource| {
+                resource.get("type").and_then(|t| t.as_str()) == Some("appPricePoints")
+                    && resource.get("id").and_then(|i| i.as_str()) == Some(&price_point_id)
+            });
+            let point_attribute = |name: &str| {
+                point
+                    .and_then(|p| p.get("attributes"))
+                    .and_then(|a| a.get(name))
+                    .and_then(|v| v.as_str())
+                    .map(|v| v.to_string())
+            };
+
+            Some(PriceTimelineEntry {
+                territory: related_id(price, "territory")
+                    .or_else(|| point.and_then(|p| related_id(p, "territory")))
+                    .unwrap_or_default(),
+                customer_price: point_attribute("customerPrice"),
+                proceeds: point_attribute("proceeds"),
+                start_date: date(price, "startDate"),
+                end_date: date(price, "endDate"),
+                manual: price
+                    .get("attributes")
+                    .and_then(|a| a.get("manual"))
+                    .and_then(|m| m.as_bool())
+                    .unwrap_or(false),
+                price_point_id,
+            })
+        })
+        .collect();
+
+    timeline.sort_by(|a, b| {
+        a.territory
+            .cmp(&b.territory)
+            .then_with(|| a.start_date.cmp(&b.start_date))
+    });
+    timeline
+}
+
+// A scheduled price change is also open-ended, so the current price is the
+// entry whose date range covers `date`, not the one without an end date.
+pub fn price_in_effect(
+    timeline: &[PriceTimelineEntry],
+    date: NaiveDate,
+) -> Option<&PriceTimelineEntry> {
+    timeline.iter().find(|entry| {
+        entry.start_date.is_none_or(|start| start <= date)
+            && entry.end_date.is_none_or(|end| date < end)
+    })
+}
+
+pub(crate) fn schedule_base_territory(schedule: &Value) -> Option<&str> {
+    schedule
+        .get("relationships")
+        .and_then(|r| r.get("baseTerritory"))
+        .and_then(|t| t.get("data"))
+        .and_then(|t| t.get("id"))
+        .and_then(|i| i.as_str())
+}
+
+pub fn find_price_point<'a>(points: &'a [Value], customer_price: &str) -> Option<&'a Value> {
+    let wanted = customer_price.parse::<f64>().ok()?;
+
+    points.iter().find(|point| {
+        point
+            .get("attributes")
+            .and_then(|a| a.get("customerPrice"))
+            .and_then(|p| p.as_str())
+            .and_then(|p| p.parse::<f64>().ok())
+            .is_some_and(|price| (price - wanted).abs() < 0.005)
+    })
+}
+
+#[derive(Clone)]
+pub struct PricingAPI {
+    base: BaseAPI,
+}
+
+impl PricingAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_schedule(&self, app_id: &str) -> Result<Option<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "baseTerritory".to_string());
+
+        match self
+            .base
+            .get(&format!("apps/{}/appPriceSchedule", app_id), Some(params))
+            .await
+        {
+            Ok(response) => Ok(response.get("data").cloned().filter(|d| !d.is_null())),
+            Err(AppStoreConnectError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn get_manual_prices(
+        &self,
+        schedule_id: &str,
+        territory: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "appPricePoint,territory".to_string());
+
+        if let Some(territory) = territory {
+            params.insert("filter[territory]".to_string(), territory.to_string());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!("appPriceSchedules/{}/manualPrices", schedule_id),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_automatic_prices(
+        &self,
+        schedule_id: &str,
+        territory: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "appPricePoint,territory".to_string());
+
+        if let Some(territory) = territory {
+            params.insert("filter[territory]".to_string(), territory.to_string());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!("appPriceSchedules/{}/automaticPrices", schedule_id),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_base_price(
+        &self,
+        app_id: &str,
+    ) -> Result<Option<(String, String)>, AppStoreConnectError> {
+        let Some(schedule) = self.get_schedule(app_id).await? else {
+            return Ok(None);
+        };
+
+        let schedule_id = schedule.get("id").and_then(|i| i.as_str());
+        let base_territory = schedule_base_territory(&schedule);
+
+        let (Some(schedule_id), Some(base_territory)) = (schedule_id, base_territory) else {
+            return Ok(None);
+        };
+
+        let timeline = self
+            .get_manual_price_timeline(schedule_id, Some(base_territory))
+            .await?;
+        let today = chrono::Utc::now().date_naive();
+
+        Ok(price_in_effect(&timeline, today)
+            .map(|price| (base_territory.to_string(), price.price_point_id.clone())))
+    }
+
+    pub async fn set_schedule(
+        &self,
+        app_id: &str,
+        base_territory: &str,
+        prices: &[ScheduledPrice],
+    ) -> Result<Value, AppStoreConnectError> {
+        if prices.is_empty() {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                "At least one price is required for a price schedule",
+            )));
+        }
+
+        let (linkages, included) =
+            manual_prices("appPrices", "appPricePoints", "appPricePoint", prices);
+
+        let data = json!({
+            "data": {
+                "type": "appPriceSchedules",
+                "relationships": {
+                    "app": {
+                        "data": { "type": "apps", "id": app_id }
+                    },
+                    "baseTerritory": {
+                        "data": { "type": "territories", "id": base_territory }
+                    },
+                    "manualPrices": {
+                        "data": linkages
+                    }
+                }
+            },
+            "included": included
+        });
+
+        let response = self.base.post("appPriceSchedules", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn set_base_price(
+        &self,
+        app_id: &str,
+        base_territory: &str,
+        price_point_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.set_schedule(
+            app_id,
+            base_territory,
+            &[ScheduledPrice::immediate(price_point_id)],
+        )
+        .await
+    }
+
+    pub async fn price_points(
+        &self,
+        app_id: &str,
+        territory: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[territory]".to_string(), territory.to_string());
+        params.insert("include".to_string(), "territory".to_string());
+
+        self.base
+            .get_all_pages(
+                &format!("apps/{}/appPricePoints", app_id),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn equalizations(
+        &self,
+        price_point_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "territory".to_string());
+
+        self.base
+            .get_all_pages(
+                &format!(
+                    "{}appPricePoints/{}/equalizations",
+                    V3_BASE_URL, price_point_id
+                ),
+                Some(params),
+                None,
+            )
+            .await
+    }
+
+    pub async fn find_base_price_point(
+        &self,
+        app_id: &str,
+        base_territory: &str,
+        base_price: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let points = self.price_points(app_id, base_territory).await?;
+
+        find_price_point(&points, base_price)
+            .cloned()
+            .ok_or_else(|| {
+                AppStoreConnectError::NotFound(NotFoundError::new(format!(
+                    "No price point of {} found in {}",
+                    base_price, base_territory
+                )))
+            })
+    }
+
+    pub async fn equalize_from(
+        &self,
+        app_id: &str,
+        base_territory: &str,
+        base_price: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let base_point = self
+            .find_base_price_point(app_id, base_territory, base_price)
+            .await?;
+        let point_id = base_point
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid price point ID".to_string(),
+            })?;
+
+        let mut points = vec![base_point.clone()];
+        points.extend(self.equalizations(point_id).await?);
+        Ok(points)
+    }
+
+    pub async fn set_worldwide_price(
+        &self,
+        app_id: &str,
+        base_territory: &str,
+        base_price: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let base_point = self
+            .find_base_price_point(app_id, base_territory, base_price)
+            .await?;
+        let point_id = base_point
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid price point ID".to_string(),
+            })?;
+
+        self.set_base_price(app_id, base_territory, point_id).await
+    }
+
+    pub async fn price_history(
+        &self,
+        app_id: &str,
+        territory: Option<&str>,
+    ) -> Result<Vec<PriceTimelineEntry>, AppStoreConnectError> {
+        let Some(schedule) = self.get_schedule(app_id).await? else {
+            return Ok(Vec::new());
+        };
+        let schedule_id = schedule.get("id").and_then(|i| i.as_str()).ok_or_else(|| {
+            AppStoreConnectError::Api {
+                message: "Invalid price schedule ID".to_string(),
+            }
+        })?;
+
+        self.price_timeline(schedule_id, &["manualPrices", "automaticPrices"], territory)
+            .await
+    }
+
+    pub async fn get_manual_price_timeline(
+        &self,
+        schedule_id: &str,
+        territory: Option<&str>,
+    ) -> Result<Vec<PriceTimelineEntry>, AppStoreConnectError> {
+        self.price_timeline(schedule_id, &["manualPrices"], territory)
+            .await
+    }
+
+    async fn price_timeline(
+        &self,
+        schedule_id: &str,
+        relationships: &[&str],
+        territory: Option<&str>,
+    ) -> Result<Vec<PriceTimelineEntry>, AppStoreConnectError> {
+        let mut prices = Vec::new();
+        let mut included = Vec::new();
+
+        for relationship in relationships {
+            let mut params = HashMap::new();
+            params.insert("include".to_string(), "appPricePoint,territory".to_string());
+            params.insert(
+                "fields[appPricePoints]".to_string(),
+                "customerPrice,proceeds,territory".to_string(),
+            );
+            if let Some(territory) = territory {
+                params.insert("filter[territory]".to_string(), territory.to_string());
+            }
+
+            let (data, related) = self
+                .base
+                .get_all_pages_with_included(
+                    &format!("appPriceSchedules/{}/{}", schedule_id, relationship),
+                    Some(params),
+                    None,
+                )
+                .await?;
+            prices.extend(data);
+            included.extend(related);
+        }
+
+        Ok(build_price_timeline(&prices, &included))
+    }
+}
@@ -0,0 +1,486 @@
+use crate::api::certificates::{decode_certificate, Certificate, CertificateInfo};
+use crate::base::{linkages, BaseAPI};
+use crate::error::{AppStoreConnectError, ValidationError};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProfileType {
+    IosAppDevelopment,
+    IosAppStore,
+    IosAppAdhoc,
+    IosAppInhouse,
+    MacAppDevelopment,
+    MacAppStore,
+    MacAppDirect,
+    TvosAppDevelopment,
+    TvosAppStore,
+    TvosAppAdhoc,
+    TvosAppInhouse,
+    MacCatalystAppDevelopment,
+    MacCatalystAppStore,
+    MacCatalystAppDirect,
+}
+
+impl ProfileType {
+    pub const ALL: [ProfileType; 14] = [
+        ProfileType::IosAppDevelopment,
+        ProfileType::IosAppStore,
+        ProfileType::IosAppAdhoc,
+        ProfileType::IosAppInhouse,
+        ProfileType::MacAppDevelopment,
+        ProfileType::MacAppStore,
+        ProfileType::MacAppDirect,
+        ProfileType::TvosAppDevelopment,
+        ProfileType::TvosAppStore,
+        ProfileType::TvosAppAdhoc,
+        ProfileType::TvosAppInhouse,
+        ProfileType::MacCatalystAppDevelopment,
+        ProfileType::MacCatalystAppStore,
+        ProfileType::MacCatalystAppDirect,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProfileType::IosAppDevelopment => "IOS_APP_DEVELOPMENT",
+            ProfileType::IosAppStore => "IOS_APP_STORE",
+            ProfileType::IosAppAdhoc => "IOS_APP_ADHOC",
+            ProfileType::IosAppInhouse => "IOS_APP_INHOUSE",
+            ProfileType::MacAppDevelopment => "MAC_APP_DEVELOPMENT",
+            ProfileType::MacAppStore => "MAC_APP_STORE",
+            ProfileType::MacAppDirect => "MAC_APP_DIRECT",
+            ProfileType::TvosAppDevelopment => "TVOS_APP_DEVELOPMENT",
+            ProfileType::TvosAppStore => "TVOS_APP_STORE",
+            ProfileType::TvosAppAdhoc => "TVOS_APP_ADHOC",
+            ProfileType::TvosAppInhouse => "TVOS_APP_INHOUSE",
+            ProfileType::MacCatalystAppDevelopment => "MAC_CATALYST_APP_DEVELOPMENT",
+            ProfileType::MacCatalystAppStore => "MAC_CATALYST_APP_STORE",
+            ProfileType::MacCatalystAppDirect => "MAC_CATALYST_APP_DIRECT",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+
+    // Development and ad hoc profiles are tied to registered devices; the
+    // other types must not list any.
+    pub fn requires_devices(&self) -> bool {
+        matches!(
+            self,
+            ProfileType::IosAppDevelopment
+                | ProfileType::IosAppAdhoc
+                | ProfileType::MacAppDevelopment
+                | ProfileType::TvosAppDevelopment
+                | ProfileType::TvosAppAdhoc
+                | ProfileType::MacCatalystAppDevelopment
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: Option<String>,
+    pub profile_type: Option<ProfileType>,
+    pub profile_state: Option<String>,
+    pub uuid: Option<String>,
+    pub platform: Option<String>,
+    pub created_date: Option<String>,
+    pub expiration_date: Option<String>,
+    pub profile_content: Option<String>,
+}
+
+impl Profile {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: string_attribute(attributes, "name"),
+            profile_type: string_attribute(attributes, "profileType")
+                .as_deref()
+                .and_then(ProfileType::parse),
+            profile_state: string_attribute(attributes, "profileState"),
+            uuid: string_attribute(attributes, "uuid"),
+            platform: string_attribute(attributes, "platform"),
+            created_date: string_attribute(attributes, "createdDate"),
+            expiration_date: string_attribute(attributes, "expirationDate"),
+            profile_content: string_attribute(attributes, "profileContent"),
+        })
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.profile_state.as_deref() == Some("ACTIVE")
+    }
+
+    pub fn content(&self) -> Result<Vec<u8>, AppStoreConnectError> {
+        let content = self
+            .profile_content
+            .as_deref()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: format!("Profile {} has no profileContent", self.id),
+            })?;
+
+        STANDARD
+            .decode(content.trim())
+            .map_err(|e| AppStoreConnectError::Api {
+                message: format!("Profile {} content is not base64: {}", self.id, e),
+            })
+    }
+
+    pub fn decode(&self) -> Result<ProvisioningProfileInfo, AppStoreConnectError> {
+        decode_provisioning_profile(&self.content()?)
+    }
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewProfile {
+    pub name: String,
+    pub profile_type: ProfileType,
+    pub bundle_id: String,
+    pub certificate_ids: Vec<String>,
+    pub device_ids: Vec<String>,
+}
+
+impl NewProfile {
+    pub fn new(
+        name: impl Into<String>,
+        profile_type: ProfileType,
+        bundle_id: impl Into<String>,
+        certificate_ids: &[&str],
+    ) -> Self {
+        Self {
+            name: name.into(),
+            profile_type,
+            bundle_id: bundle_id.into(),
+            certificate_ids: certificate_ids.iter().map(|id| id.to_string()).collect(),
+            device_ids: Vec::new(),
+        }
+    }
+
+    pub fn with_devices(mut self, device_ids: &[&str]) -> Self {
+        self.device_ids = device_ids.iter().map(|id| id.to_string()).collect();
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), AppStoreConnectError> {
+        let mut issues = Vec::new();
+
+        if self.name.trim().is_empty() {
+            issues.push("name is required".to_string());
+        }
+        if self.certificate_ids.is_empty() {
+            issues.push("at least one certificate is required".to_string());
+        }
+        if self.profile_type.requires_devices() && self.device_ids.is_empty() {
+            issues.push(format!(
+                "{} profiles need at least one device",
+                self.profile_type.as_str()
+            ));
+        }
+        if !self.profile_type.requires_devices() && !self.device_ids.is_empty() {
+            issues.push(format!(
+                "{} profiles cannot include devices",
+                self.profile_type.as_str()
+            ));
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(AppStoreConnectError::Validation(
+                ValidationError::with_issues(
+                    format!("Profile '{}' is not valid", self.name),
+                    issues,
+                ),
+            ))
+        }
+    }
+
+    pub fn to_request(&self) -> Value {
+        let certificate_ids: Vec<&str> =
+            self.certificate_ids.iter().map(|id| id.as_str()).collect();
+        let device_ids: Vec<&str> = self.device_ids.iter().map(|id| id.as_str()).collect();
+
+        let mut relationships = json!({
+            "bundleId": {
+                "data": { "type": "bundleIds", "id": self.bundle_id }
+            },
+            "certificates": {
+                "data": linkages("certificates", &certificate_ids)
+            }
+        });
+        if !device_ids.is_empty() {
+            relationships["devices"] = json!({ "data": linkages("devices", &device_ids) });
+        }
+
+        json!({
+            "data": {
+                "type": "profiles",
+                "attributes": {
+                    "name": self.name,
+                    "profileType": self.profile_type.as_str()
+                },
+                "relationships": relationships
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvisioningProfileInfo {
+    pub name: Option<String>,
+    pub uuid: Option<String>,
+    pub team_identifier: Option<String>,
+    pub application_identifier: Option<String>,
+    pub creation_date: Option<DateTime<Utc>>,
+    pub expiration_date: DateTime<Utc>,
+    pub provisioned_devices: Vec<String>,
+    pub provisions_all_devices: bool,
+    pub developer_certificates: Vec<CertificateInfo>,
+    pub entitlements: Value,
+}
+
+impl ProvisioningProfileInfo {
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expiration_date <= now
+    }
+
+    pub fn entitlement(&self, key: &str) -> Option<&Value> {
+        self.entitlements.get(key)
+    }
+}
+
+fn plist_to_json(value: &plist::Value) -> Value {
+    match value {
+        plist::Value::Dictionary(dict) => Value::Object(
+            dict.iter()
+                .map(|(key, value)| (key.clone(), plist_to_json(value)))
+                .collect::<Map<String, Value>>(),
+        ),
+        plist::Value::Array(items) => Value::Array(items.iter().map(plist_to_json).collect()),
+        plist::Value::Boolean(flag) => json!(flag),
+        plist::Value::Integer(number) => number
+            .as_signed()
+            .map(|n| json!(n))
+            .or_else(|| number.as_unsigned().map(|n| json!(n)))
+            .unwrap_or(Value::Null),
+        plist::Value::Real(number) => json!(number),
+        plist::Value::String(text) => json!(text),
+        plist::Value::Date(date) => json!(plist_date(date).to_rfc3339()),
+        plist::Value::Data(data) => json!(STANDARD.encode(data)),
+        _ => Value::Null,
+    }
+}
+
+fn plist_date(date: &plist::Date) -> DateTime<Utc> {
+    DateTime::<Utc>::from(std::time::SystemTime::from(*date))
+}
+
+// A .mobileprovision is a CMS signed message wrapping an XML plist. The
+// plist is stored unencrypted, so it can be read straight out of the
+// signed data without verifying the signature.
+pub fn decode_provisioning_profile(
+    data: &[u8],
+) -> Result<ProvisioningProfileInfo, AppStoreConnectError> {
+    let invalid = |detail: String| {
+        AppStoreConnectError::Validation(ValidationError::new(format!(
+            "Provisioning profile could not be decoded: {}",
+            detail
+        )))
+    };
+
+    let start = find_bytes(data, b"<?xml").ok_or_else(|| invalid("no plist found".to_string()))?;
+    let end = find_bytes(&data[start..], b"</plist>")
+        .map(|end| start + end + b"</plist>".len())
+        .ok_or_else(|| invalid("plist is truncated".to_string()))?;
+
+    let plist =
+        plist::Value::from_reader_xml(&data[start..end]).map_err(|e| invalid(e.to_string()))?;
+    let dict = plist
+        .as_dictionary()
+        .ok_or_else(|| invalid("plist is not a dictionary".to_string()))?;
+
+    let text = |key: &str| {
+        dict.get(key)
+            .and_then(|v| v.as_string())
+            .map(|v| v.to_string())
+    };
+    let entitlements = dict
+        .get("Entitlements")
+        .map(plist_to_json)
+        .unwrap_or_else(|| json!({}));
+
+    Ok(ProvisioningProfileInfo {
+        name: text("Name"),
+        uuid: text("UUID"),
+        team_identifier: dict
+            .get("TeamIdentifier")
+            .and_then(|v| v.as_array())
+            .and_then(|ids| ids.first())
+            .and_then(|id| id.as_string())
+            .map(|id| id.to_string()),
+        application_identifier: entitlements
+            .get("application-identifier")
+            .or_else(|| entitlements.get("com.apple.application-identifier"))
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string()),
+        creation_date: dict
+            .get("CreationDate")
+            .and_then(|v| v.as_date())
+            .map(|date| plist_date(&date)),
+        expiration_date: dict
+            .get("ExpirationDate")
+            .and_then(|v| v.as_date())
+            .map(|date| plist_date(&date))
+            .ok_or_else(|| invalid("ExpirationDate is missing".to_string()))?,
+        provisioned_devices: dict
+            .get("ProvisionedDevices")
+            .and_then(|v| v.as_array())
+            .map(|devices| {
+                devices
+                    .iter()
+                    .filter_map(|d| d.as_string())
+                    .map(|d| d.to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        provisions_all_devices: dict
+            .get("ProvisionsAllDevices")
+            .and_then(|v| v.as_boolean())
+            .unwrap_or(false),
+        developer_certificates: dict
+            .get("DeveloperCertificates")
+            .and_then(|v| v.as_array())
+            .map(|certificates| {
+                certificates
+                    .iter()
+                    .filter_map(|c| c.as_data())
+                    .filter_map(|der| decode_certificate(der).ok())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        entitlements,
+    })
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[derive(Clone)]
+pub struct ProfilesAPI {
+    base: BaseAPI,
+}
+
+impl ProfilesAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn create(&self, profile: &NewProfile) -> Result<Profile, AppStoreConnectError> {
+        profile.validate()?;
+
+        let response = self.base.post("profiles", profile.to_request()).await?;
+        response
+            .get("data")
+            .and_then(Profile::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_all(
+        &self,
+        profile_types: &[ProfileType],
+        name: Option<&str>,
+        active_only: bool,
+    ) -> Result<Vec<Profile>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if !profile_types.is_empty() {
+            let types: Vec<&str> = profile_types.iter().map(|t| t.as_str()).collect();
+            params.insert("filter[profileType]".to_string(), types.join(","));
+        }
+        if let Some(name) = name {
+            params.insert("filter[name]".to_string(), name.to_string());
+        }
+        if active_only {
+            params.insert("filter[profileState]".to_string(), "ACTIVE".to_string());
+        }
+
+        let profiles = self
+            .base
+            .get_all_pages("profiles", Some(params), None)
+            .await?;
+
+        Ok(profiles.iter().filter_map(Profile::from_value).collect())
+    }
+
+    pub async fn get(&self, profile_id: &str) -> Result<Profile, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("profiles/{}", profile_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(Profile::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_certificates(
+        &self,
+        profile_id: &str,
+    ) -> Result<Vec<Certificate>, AppStoreConnectError> {
+        let certificates = self
+            .base
+            .get_all_pages(&format!("profiles/{}/certificates", profile_id), None, None)
+            .await?;
+
+        Ok(certificates
+            .iter()
+            .filter_map(Certificate::from_value)
+            .collect())
+    }
+
+    pub async fn get_devices(&self, profile_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(&format!("profiles/{}/devices", profile_id), None, None)
+            .await
+    }
+
+    pub async fn download(
+        &self,
+        profile_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<u64, AppStoreConnectError> {
+        let content = self.get(profile_id).await?.content()?;
+        tokio::fs::write(path, &content).await?;
+        Ok(content.len() as u64)
+    }
+
+    pub async fn delete(&self, profile_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("profiles/{}", profile_id))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+const SIGNATURE_SEPARATOR: char = '\u{2063}';
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OfferPrice {
+    pub territory: String,
+    pub price_point_id: String,
+}
+
+impl OfferPrice {
+    pub fn new(territory: impl Into<String>, price_point_id: impl Into<String>) -> Self {
+        Self {
+            territory: territory.into(),
+            price_point_id: price_point_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromotionalOfferSignatureInput {
+    pub app_bundle_id: String,
+    pub key_identifier: String,
+    pub product_identifier: String,
+    pub offer_identifier: String,
+    pub application_username: String,
+    pub nonce: Uuid,
+    pub timestamp: i64,
+}
+
+impl PromotionalOfferSignatureInput {
+    pub fn new(
+        app_bundle_id: impl Into<String>,
+        key_identifier: impl Into<String>,
+        product_identifier: impl Into<String>,
+        offer_identifier: impl Into<String>,
+        application_username: impl Into<String>,
+    ) -> Self {
+        Self {
+            app_bundle_id: app_bundle_id.into(),
+            key_identifier: key_identifier.into(),
+            product_identifier: product_identifier.into(),
+            offer_identifier: offer_identifier.into(),
+            application_username: application_username.into(),
+            nonce: Uuid::new_v4(),
+            timestamp: Utc::now().timestamp_millis(),
+        }
+    }
+
+    pub fn payload(&self) -> String {
+        [
+            self.app_bundle_id.clone(),
+            self.key_identifier.clone(),
+            self.product_identifier.clone(),
+            self.offer_identifier.clone(),
+            self.application_username.to_lowercase(),
+            self.nonce.to_string().to_lowercase(),
+            self.timestamp.to_string(),
+        ]
+        .join(&SIGNATURE_SEPARATOR.to_string())
+    }
+}
+
+pub(crate) fn offer_prices(price_type: &str, prices: &[OfferPrice]) -> (Vec<Value>, Vec<Value>) {
+    let mut linkages = Vec::new();
+    let mut included = Vec::new();
+
+    for (index, price) in prices.iter().enumerate() {
+        let id = format!("${{price-{}}}", index);
+        linkages.push(json!({ "type": price_type, "id": id }));
+        included.push(json!({
+            "type": price_type,
+            "id": id,
+            "relationships": {
+                "territory": {
+                    "data": { "type": "territories", "id": price.territory }
+                },
+                "subscriptionPricePoint": {
+                    "data": { "type": "subscriptionPricePoints", "id": price.price_point_id }
+                }
+            }
+        }));
+    }
+
+    (linkages, included)
+}
+
+#[derive(Clone)]
+pub struct PromotionalOffersAPI {
+    base: BaseAPI,
+}
+
+impl PromotionalOffersAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, subscription_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("subscriptions/{}/promotionalOffers", subscription_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get(&self, offer_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("subscriptionPromotionalOffers/{}", offer_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_prices(&self, offer_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("subscriptionPromotionalOffers/{}/prices", offer_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        subscription_id: &str,
+        name: &str,
+        offer_code: &str,
+        duration: &str,
+        offer_mode: &str,
+        number_of_periods: u32,
+        prices: &[OfferPrice],
+    ) -> Result<Value, AppStoreConnectError> {
+        if offer_mode != "FREE_TRIAL" && prices.is_empty() {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                "Paid promotional offers need at least one territory price",
+            )));
+        }
+
+        let (linkages, included) = offer_prices("subscriptionPromotionalOfferPrices", prices);
+
+        let data = json!({
+            "data": {
+                "type": "subscriptionPromotionalOffers",
+                "attributes": {
+                    "name": name,
+                    "offerCode": offer_code,
+                    "duration": duration,
+                    "offerMode": offer_mode,
+                    "numberOfPeriods": number_of_periods
+                },
+                "relationships": {
+                    "subscription": {
+                        "data": {
+                            "type": "subscriptions",
+                            "id": subscription_id
+                        }
+                    },
+                    "prices": {
+                        "data": linkages
+                    }
+                }
+            },
+            "included": included
+        });
+
+        let response = self
+            .base
+            .post("subscriptionPromotionalOffers", data)
+            .await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update_prices(
+        &self,
+        offer_id: &str,
+        prices: &[OfferPrice],
+    ) -> Result<Value, AppStoreConnectError> {
+        let (linkages, included) = offer_prices("subscriptionPromotionalOfferPrices", prices);
+
+        let data = json!({
+            "data": {
+                "type": "subscriptionPromotionalOffers",
+                "id": offer_id,
+                "relationships": {
+                    "prices": {
+                        "data": linkages
+                    }
+                }
+            },
+            "included": included
+        });
+
+        let response = self
+            .base
+            .patch(&format!("subscriptionPromotionalOffers/{}", offer_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, offer_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("subscriptionPromotionalOffers/{}", offer_id))
+            .await?;
+        Ok(())
+    }
+}
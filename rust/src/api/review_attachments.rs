@@ -0,0 +1,295 @@
+use crate::base::BaseAPI;
+use crate::error::AppStoreConnectError;
+use crate::upload::{
+    md5_file, upload_file_info, upload_parts, RetryOptions, UploadOperation, UploadProgress,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReviewDetail {
+    pub contact_first_name: Option<String>,
+    pub contact_last_name: Option<String>,
+    pub contact_phone: Option<String>,
+    pub contact_email: Option<String>,
+    pub demo_account_required: Option<bool>,
+    pub demo_account_name: Option<String>,
+    pub demo_account_password: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl ReviewDetail {
+    pub fn from_value(value: &Value) -> Self {
+        let attributes = value.get("attributes");
+        let string = |key: &str| {
+            attributes
+                .and_then(|a| a.get(key))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+
+        Self {
+            contact_first_name: string("contactFirstName"),
+            contact_last_name: string("contactLastName"),
+            contact_phone: string("contactPhone"),
+            contact_email: string("contactEmail"),
+            demo_account_required: attributes
+                .and_then(|a| a.get("demoAccountRequired"))
+                .and_then(|v| v.as_bool()),
+            demo_account_name: string("demoAccountName"),
+            demo_account_password: string("demoAccountPassword"),
+            notes: string("notes"),
+        }
+    }
+
+    pub fn to_attributes(&self) -> Value {
+        let mut attributes = json!({});
+        let fields = [
+            ("contactFirstName", &self.contact_first_name),
+            ("contactLastName", &self.contact_last_name),
+            ("contactPhone", &self.contact_phone),
+            ("contactEmail", &self.contact_email),
+            ("demoAccountName", &self.demo_account_name),
+            ("demoAccountPassword", &self.demo_account_password),
+            ("notes", &self.notes),
+        ];
+
+        for (key, value) in fields {
+            if let Some(value) = value {
+                attributes[key] = json!(value);
+            }
+        }
+        if let Some(required) = self.demo_account_required {
+            attributes["demoAccountRequired"] = json!(required);
+        }
+
+        attributes
+    }
+
+    pub fn has_demo_account(&self) -> bool {
+        self.demo_account_name
+            .as_deref()
+            .is_some_and(|name| !name.trim().is_empty())
+            && self
+                .demo_account_password
+                .as_deref()
+                .is_some_and(|password| !password.is_empty())
+    }
+}
+
+#[derive(Clone)]
+pub struct ReviewAttachmentsAPI {
+    base: BaseAPI,
+}
+
+impl ReviewAttachmentsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_review_detail(
+        &self,
+        version_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("appStoreVersions/{}/appStoreReviewDetail", version_id),
+                None,
+            )
+            .await?;
+
+        Ok(response.get("data").cloned().filter(|d| !d.is_null()))
+    }
+
+    pub async fn set_review_detail(
+        &self,
+        version_id: &str,
+        detail: &ReviewDetail,
+    ) -> Result<Value, AppStoreConnectError> {
+        let existing = self.get_review_detail(version_id).await?;
+        let existing_id = existing
+            .as_ref()
+            .and_then(|d| d.get("id"))
+            .and_then(|i| i.as_str());
+
+        let response = match existing_id {
+            Some(review_detail_id) => {
+                let data = json!({
+                    "data": {
+                        "type": "appStoreReviewDetails",
+                        "id": review_detail_id,
+                        "attributes": detail.to_attributes()
+                    }
+                });
+
+                self.base
+                    .patch(&format!("appStoreReviewDetails/{}", review_detail_id), data)
+                    .await?
+            }
+            None => {
+                let data = json!({
+                    "data": {
+                        "type": "appStoreReviewDetails",
+                        "attributes": detail.to_attributes(),
+                        "relationships": {
+                            "appStoreVersion": {
+                                "data": {
+                                    "type": "appStoreVersions",
+                                    "id": version_id
+                                }
+                            }
+                        }
+                    }
+                });
+
+                self.base.post("appStoreReviewDetails", data).await?
+            }
+        };
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_all(
+        &self,
+        review_detail_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!(
+                    "appStoreReviewDetails/{}/appStoreReviewAttachments",
+                    review_detail_id
+                ),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get(&self, attachment_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("appStoreReviewAttachments/{}", attachment_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn reserve(
+        &self,
+        review_detail_id: &str,
+        file_name: &str,
+        file_size: u64,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "appStoreReviewAttachments",
+                "attributes": {
+                    "fileName": file_name,
+                    "fileSize": file_size
+                },
+                "relationships": {
+                    "appStoreReviewDetail": {
+                        "data": {
+                            "type": "appStoreReviewDetails",
+                            "id": review_detail_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("appStoreReviewAttachments", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn commit(
+        &self,
+        attachment_id: &str,
+        source_file_checksum: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "appStoreReviewAttachments",
+                "id": attachment_id,
+                "attributes": {
+                    "uploaded": true,
+                    "sourceFileChecksum": source_file_checksum
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(
+                &format!("appStoreReviewAttachments/{}", attachment_id),
+                data,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn upload(
+        &self,
+        review_detail_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let path = path.as_ref();
+        let (file_name, file_size) = upload_file_info(path).await?;
+
+        let reservation = self
+            .reserve(review_detail_id, &file_name, file_size)
+            .await?;
+        let attachment_id = reservation
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid attachment ID".to_string(),
+            })?;
+
+        let operations = UploadOperation::from_resource(&reservation);
+        upload_parts(
+            &self.base,
+            &operations,
+            path,
+            &mut UploadProgress::new(attachment_id),
+            &RetryOptions::default(),
+            None,
+        )
+        .await?;
+
+        self.commit(attachment_id, &md5_file(path).await?).await
+    }
+
+    pub async fn delete(&self, attachment_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("appStoreReviewAttachments/{}", attachment_id))
+            .await?;
+        Ok(())
+    }
+}
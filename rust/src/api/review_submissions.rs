@@ -0,0 +1,394 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+pub const ACTIVE_SUBMISSION_STATES: &[&str] =
+    &["WAITING_FOR_REVIEW", "IN_REVIEW", "UNRESOLVED_ISSUES"];
+
+// App Review sends a rejected submission back as UNRESOLVED_ISSUES; the
+// reviewSubmissions resource has no separate REJECTED state.
+pub const REJECTED_SUBMISSION_STATES: &[&str] = &["UNRESOLVED_ISSUES"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewSubmission {
+    pub id: String,
+    pub state: String,
+    pub platform: Option<String>,
+    pub submitted_date: Option<String>,
+}
+
+impl ReviewSubmission {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+        let text = |key: &str| {
+            attributes
+                .and_then(|a| a.get(key))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            state: text("state")?,
+            platform: text("platform"),
+            submitted_date: text("submittedDate"),
+        })
+    }
+
+    pub fn is_rejected(&self) -> bool {
+        REJECTED_SUBMISSION_STATES.contains(&self.state.as_str())
+    }
+}
+
+#[derive(Clone)]
+pub struct ReviewSubmissionsAPI {
+    base: BaseAPI,
+}
+
+impl ReviewSubmissionsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(
+        &self,
+        app_id: &str,
+        states: Option<&[&str]>,
+        platform: Option<&str>,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[app]".to_string(), app_id.to_string());
+
+        if let Some(states) = states {
+            params.insert("filter[state]".to_string(), states.join(","));
+        }
+        if let Some(platform) = platform {
+            params.insert("filter[platform]".to_string(), platform.to_string());
+        }
+
+        self.base
+            .get_all_pages("reviewSubmissions", Some(params), None)
+            .await
+    }
+
+    pub async fn get(&self, submission_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("reviewSubmissions/{}", submission_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_state(&self, submission_id: &str) -> Result<String, AppStoreConnectError> {
+        let submission = self.get(submission_id).await?;
+
+        Ok(submission
+            .get("attributes")
+            .and_then(|a| a.get("state"))
+            .and_then(|s| s.as_str())
+            .unwrap_or("UNKNOWN")
+            .to_string())
+    }
+
+    pub async fn get_items(&self, submission_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("reviewSubmissions/{}/items", submission_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        app_id: &str,
+        platform: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "reviewSubmissions",
+                "attributes": {
+                    "platform": platform.unwrap_or("IOS")
+                },
+                "relationships": {
+                    "app": {
+                        "data": {
+                            "type": "apps",
+                            "id": app_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("reviewSubmissions", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn add_item(
+        &self,
+        submission_id: &str,
+        relationship: &str,
+        resource_type: &str,
+        resource_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "reviewSubmissionItems",
+                "relationships": {
+                    "reviewSubmission": {
+                        "data": {
+                            "type": "reviewSubmissions",
+                            "id": submission_id
+                        }
+                    },
+                    relationship: {
+                        "data": {
+                            "type": resource_type,
+                            "id": resource_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("reviewSubmissionItems", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn add_version(
+        &self,
+        submission_id: &str,
+        version_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.add_item(
+            submission_id,
+            "appStoreVersion",
+            "appStoreVersions",
+            version_id,
+        )
+        .await
+    }
+
+    pub async fn add_app_event(
+        &self,
+        submission_id: &str,
+        app_event_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.add_item(submission_id, "appEvent", "appEvents", app_event_id)
+            .await
+    }
+
+    pub async fn submit_in_app_purchase(
+        &self,
+        in_app_purchase_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "inAppPurchaseSubmissions",
+                "relationships": {
+                    "inAppPurchaseV2": {
+                        "data": {
+                            "type": "inAppPurchases",
+                            "id": in_app_purchase_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("inAppPurchaseSubmissions", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn submit(&self, submission_id: &str) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "reviewSubmissions",
+                "id": submission_id,
+                "attributes": {
+                    "submitted": true
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("reviewSubmissions/{}", submission_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn cancel(&self, submission_id: &str) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "reviewSubmissions",
+                "id": submission_id,
+                "attributes": {
+                    "canceled": true
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("reviewSubmissions/{}", submission_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn remove_item(&self, item_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("reviewSubmissionItems/{}", item_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_active(
+        &self,
+        app_id: &str,
+        platform: Option<&str>,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let active = self
+            .get_all(app_id, Some(ACTIVE_SUBMISSION_STATES), platform)
+            .await?;
+
+        Ok(active.into_iter().next())
+    }
+
+    pub async fn cancel_active(
+        &self,
+        app_id: &str,
+        platform: Option<&str>,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let Some(submission) = self.get_active(app_id, platform).await? else {
+            return Ok(None);
+        };
+
+        let submission_id = submission
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid review submission ID".to_string(),
+            })?;
+
+        self.cancel(submission_id).await.map(Some)
+    }
+
+    pub async fn get_or_create_open(
+        &self,
+        app_id: &str,
+        platform: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let open = self
+            .get_all(app_id, Some(&["READY_FOR_REVIEW"]), Some(platform))
+            .await?;
+
+        match open.into_iter().next() {
+            Some(submission) => Ok(submission),
+            None => self.create(app_id, Some(platform)).await,
+        }
+    }
+
+    pub async fn submit_version(&self, version_id: &str) -> Result<Value, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "app".to_string());
+
+        let response = self
+            .base
+            .get(&format!("appStoreVersions/{}", version_id), Some(params))
+            .await?;
+        let version = response.get("data").cloned().unwrap_or_default();
+
+        let app_id = version
+            .get("relationships")
+            .and_then(|r| r.get("app"))
+            .and_then(|a| a.get("data"))
+            .and_then(|d| d.get("id"))
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| {
+                AppStoreConnectError::Validation(ValidationError::new(format!(
+                    "Could not determine the app for version {}",
+                    version_id
+                )))
+            })?;
+        let platform = version
+            .get("attributes")
+            .and_then(|a| a.get("platform"))
+            .and_then(|p| p.as_str())
+            .unwrap_or("IOS");
+
+        let submission = self.get_or_create_open(app_id, platform).await?;
+        let submission_id = submission
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid review submission ID".to_string(),
+            })?;
+
+        // A retry after a partial failure finds the version already attached;
+        // adding it again would be rejected with a conflict.
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "appStoreVersion".to_string());
+        let items = self
+            .base
+            .get_all_pages(
+                &format!("reviewSubmissions/{}/items", submission_id),
+                Some(params),
+                None,
+            )
+            .await?;
+
+        if !has_item(&items, "appStoreVersion", version_id) {
+            self.add_version(submission_id, version_id).await?;
+        }
+        self.submit(submission_id).await
+    }
+}
+
+pub fn has_item(items: &[Value], relationship: &str, resource_id: &str) -> bool {
+    items.iter().any(|item| {
+        item.get("relationships")
+            .and_then(|r| r.get(relationship))
+            .and_then(|r| r.get("data"))
+            .and_then(|d| d.get("id"))
+            .and_then(|i| i.as_str())
+            == Some(resource_id)
+    })
+}
@@ -0,0 +1,573 @@
+use crate::base::{next_page, BaseAPI};
+use crate::csv::push_csv_row;
+use crate::error::{AppStoreConnectError, ValidationError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReviewSort {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+    HighestRating,
+    LowestRating,
+}
+
+impl ReviewSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewSort::NewestFirst => "-createdDate",
+            ReviewSort::OldestFirst => "createdDate",
+            ReviewSort::HighestRating => "-rating",
+            ReviewSort::LowestRating => "rating",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReviewFilter {
+    pub territory: Option<String>,
+    pub rating: Option<u8>,
+    pub sort: ReviewSort,
+}
+
+impl ReviewFilter {
+    fn params(&self) -> Result<HashMap<String, String>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "response".to_string());
+        params.insert("sort".to_string(), self.sort.as_str().to_string());
+
+        if let Some(territory) = &self.territory {
+            params.insert("filter[territory]".to_string(), territory.clone());
+        }
+        if let Some(rating) = self.rating {
+            if !(1..=5).contains(&rating) {
+                return Err(AppStoreConnectError::Validation(ValidationError::new(
+                    format!(
+                        "Review rating filter must be between 1 and 5, got {}",
+                        rating
+                    ),
+                )));
+            }
+            params.insert("filter[rating]".to_string(), rating.to_string());
+        }
+
+        Ok(params)
+    }
+}
+
+pub const REVIEW_CSV_HEADER: [&str; 8] = [
+    "id",
+    "rating",
+    "title",
+    "body",
+    "reviewer_nickname",
+    "territory",
+    "created_date",
+    "response",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewExportFormat {
+    Csv,
+    JsonLines,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewResponse {
+    pub id: String,
+    pub response_body: String,
+    pub last_modified_date: Option<DateTime<Utc>>,
+    pub state: Option<String>,
+}
+
+impl ReviewResponse {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            response_body: string_attribute(attributes, "responseBody").unwrap_or_default(),
+            last_modified_date: date_attribute(attributes, "lastModifiedDate"),
+            state: string_attribute(attributes, "state"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomerReview {
+    pub id: String,
+    pub rating: u8,
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub reviewer_nickname: Option<String>,
+    pub territory: Option<String>,
+    pub created_date: Option<DateTime<Utc>>,
+    pub response: Option<ReviewResponse>,
+}
+
+impl CustomerReview {
+    pub fn from_value(value: &Value, included: &[Value]) -> Option<Self> {
+        let attributes = value.get("attributes");
+        let response_id = value
+            .get("relationships")
+            .and_then(|r| r.get("response"))
+            .and_then(|r| r.get("data"))
+            .and_then(|d| d.get("id"))
+            .and_then(|id| id.as_str());
+        let response = response_id.and_then(|response_id| {
+            included
+                .iter()
+                .find(|resource| {
+                    resource.get("type").and_then(|t| t.as_str()) == Some("customerReviewResponses")
+                        && resource.get("id").and_then(|id| id.as_str()) == Some(response_id)
+                })
+                .and_then(ReviewResponse::from_value)
+        });
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            rating: attributes
+                .and_then(|a| a.get("rating"))
+                .and_then(|r| r.as_u64())
+                .unwrap_or_default() as u8,
+            title: string_attribute(attributes, "title"),
+            body: string_attribute(attributes, "body"),
+            reviewer_nickname: string_attribute(attributes, "reviewerNickname"),
+            territory: string_attribute(attributes, "territory"),
+            created_date: date_attribute(attributes, "createdDate"),
+            response,
+        })
+    }
+
+    pub fn push_csv_row(&self, csv: &mut String) {
+        let rating = self.rating.to_string();
+        let created_date = self
+            .created_date
+            .map(|d| d.to_rfc3339())
+            .unwrap_or_default();
+        let response = self
+            .response
+            .as_ref()
+            .map(|r| r.response_body.as_str())
+            .unwrap_or_default();
+
+        push_csv_row(
+            csv,
+            &[
+                &self.id,
+                &rating,
+                self.title.as_deref().unwrap_or_default(),
+                self.body.as_deref().unwrap_or_default(),
+                self.reviewer_nickname.as_deref().unwrap_or_default(),
+                self.territory.as_deref().unwrap_or_default(),
+                &created_date,
+                response,
+            ],
+        );
+    }
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+fn date_attribute(attributes: Option<&Value>, key: &str) -> Option<DateTime<Utc>> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+        .map(|d| d.with_timezone(&Utc))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TerritoryRating {
+    pub territory: String,
+    pub review_count: usize,
+    pub average_rating: f64,
+    pub distribution: [usize; 5],
+}
+
+impl TerritoryRating {
+    fn add(&mut self, rating: u8) {
+        if !(1..=5).contains(&rating) {
+            return;
+        }
+        let total = self.average_rating * self.review_count as f64 + f64::from(rating);
+        self.review_count += 1;
+        self.average_rating = total / self.review_count as f64;
+        self.distribution[usize::from(rating) - 1] += 1;
+    }
+}
+
+// Averages customer reviews only. Star ratings left without a written review
+// are not exposed by the API, so this is a biased sample and will not match
+// the rating shown on the App Store product page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RatingsSummary {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub overall: TerritoryRating,
+    pub territories: Vec<TerritoryRating>,
+}
+
+impl RatingsSummary {
+    pub fn from_reviews(
+        reviews: &[CustomerReview],
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> Self {
+        let mut overall = TerritoryRating {
+            territory: "ALL".to_string(),
+            ..Default::default()
+        };
+        let mut by_territory: BTreeMap<String, TerritoryRating> = BTreeMap::new();
+
+        let in_window = |review: &&CustomerReview| match review.created_date {
+            Some(created) => {
+                since.is_none_or(|since| created >= since)
+                    && until.is_none_or(|until| created < until)
+            }
+            None => since.is_none() && until.is_none(),
+        };
+
+        for review in reviews.iter().filter(in_window) {
+            let territory = review
+                .territory
+                .clone()
+                .unwrap_or_else(|| "UNKNOWN".to_string());
+            by_territory
+                .entry(territory.clone())
+                .or_insert_with(|| TerritoryRating {
+                    territory,
+                    ..Default::default()
+                })
+                .add(review.rating);
+            overall.add(review.rating);
+        }
+
+        Self {
+            since,
+            until,
+            overall,
+            territories: by_territory.into_values().collect(),
+        }
+    }
+
+    pub fn territory(&self, territory: &str) -> Option<&TerritoryRating> {
+        self.territories.iter().find(|t| t.territory == territory)
+    }
+}
+
+pub const MAX_RESPONSE_LENGTH: usize = 5970;
+
+const TEMPLATE_PLACEHOLDERS: &[&str] = &["reviewer_name", "rating", "app_name", "territory"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseTemplate {
+    pub body: String,
+    pub app_name: String,
+    #[serde(default)]
+    pub variants: HashMap<String, String>,
+    #[serde(default = "default_reviewer_name")]
+    pub fallback_name: String,
+}
+
+pub fn validate_response_length(body: &str) -> Result<(), AppStoreConnectError> {
+    let length = body.chars().count();
+    if length > MAX_RESPONSE_LENGTH {
+        return Err(AppStoreConnectError::Validation(ValidationError::new(
+            format!(
+                "Review response is {} characters (limit {})",
+                length, MAX_RESPONSE_LENGTH
+            ),
+        )));
+    }
+    Ok(())
+}
+
+fn default_reviewer_name() -> String {
+    "there".to_string()
+}
+
+impl ResponseTemplate {
+    pub fn new(body: impl Into<String>, app_name: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            app_name: app_name.into(),
+            variants: HashMap::new(),
+            fallback_name: default_reviewer_name(),
+        }
+    }
+
+    pub fn with_variant(mut self, territory: impl Into<String>, body: impl Into<String>) -> Self {
+        self.variants.insert(territory.into(), body.into());
+        self
+    }
+
+    pub fn render(&self, review: &CustomerReview) -> Result<String, AppStoreConnectError> {
+        let body = review
+            .territory
+            .as_ref()
+            .and_then(|territory| self.variants.get(territory))
+            .unwrap_or(&self.body);
+
+        let mut rendered = String::new();
+        let mut rest = body.as_str();
+        while let Some(start) = rest.find('{') {
+            rendered.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find('}') else {
+                rendered.push_str(&rest[start..]);
+                rest = "";
+                break;
+            };
+            let name = &rest[start + 1..start + end];
+            let value = match name {
+                "reviewer_name" => review
+                    .reviewer_nickname
+                    .clone()
+                    .filter(|n| !n.trim().is_empty())
+                    .unwrap_or_else(|| self.fallback_name.clone()),
+                "rating" => review.rating.to_string(),
+                "app_name" => self.app_name.clone(),
+                "territory" => review.territory.clone().unwrap_or_default(),
+                other => {
+                    return Err(AppStoreConnectError::Validation(ValidationError::new(
+                        format!(
+                            "Unknown placeholder {{{}}} in response template (expected one of {})",
+                            other,
+                            TEMPLATE_PLACEHOLDERS.join(", ")
+                        ),
+                    )))
+                }
+            };
+            rendered.push_str(&value);
+            rest = &rest[start + end + 1..];
+        }
+        rendered.push_str(rest);
+
+        validate_response_length(&rendered)?;
+        Ok(rendered)
+    }
+}
+
+#[derive(Clone)]
+pub struct ReviewsAPI {
+    base: BaseAPI,
+}
+
+impl ReviewsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_reviews(
+        &self,
+        app_id: &str,
+        filter: &ReviewFilter,
+    ) -> Result<Vec<CustomerReview>, AppStoreConnectError> {
+        let (data, included) = self
+            .base
+            .get_all_pages_with_included(
+                &format!("apps/{}/customerReviews", app_id),
+                Some(filter.params()?),
+                None,
+            )
+            .await?;
+
+        Ok(data
+            .iter()
+            .filter_map(|review| CustomerReview::from_value(review, &included))
+            .collect())
+    }
+
+    pub async fn create_response(
+        &self,
+        review_id: &str,
+        response_body: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        validate_response_length(response_body)?;
+
+        let data = json!({
+            "data": {
+                "type": "customerReviewResponses",
+                "attributes": {
+                    "responseBody": response_body
+                },
+                "relationships": {
+                    "review": {
+                        "data": {
+                            "type": "customerReviews",
+                            "id": review_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("customerReviewResponses", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete_response(&self, response_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("customerReviewResponses/{}", response_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_template(
+        &self,
+        review: &CustomerReview,
+        template: &ResponseTemplate,
+    ) -> Result<Value, AppStoreConnectError> {
+        let body = template.render(review)?;
+        self.create_response(&review.id, &body).await
+    }
+
+    pub async fn fetch_since(
+        &self,
+        app_id: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<CustomerReview>, AppStoreConnectError> {
+        let mut pages = ReviewPages::new(app_id, &ReviewFilter::default())?;
+        let mut reviews = Vec::new();
+
+        while let Some(page) = pages.next(&self.base).await? {
+            // Pages are sorted newest first, so the first review at or before the
+            // cutoff means everything after it has already been seen.
+            for review in page {
+                match review.created_date {
+                    Some(created) if created <= since => return Ok(reviews),
+                    _ => reviews.push(review),
+                }
+            }
+        }
+
+        Ok(reviews)
+    }
+
+    pub async fn ratings_summary(
+        &self,
+        app_id: &str,
+        since: DateTime<Utc>,
+    ) -> Result<RatingsSummary, AppStoreConnectError> {
+        let reviews = self.fetch_since(app_id, since).await?;
+        Ok(RatingsSummary::from_reviews(&reviews, Some(since), None))
+    }
+
+    pub async fn export(
+        &self,
+        app_id: &str,
+        format: ReviewExportFormat,
+        dest: impl AsRef<Path>,
+    ) -> Result<usize, AppStoreConnectError> {
+        let mut file = tokio::fs::File::create(dest).await?;
+        let mut pages = ReviewPages::new(app_id, &ReviewFilter::default())?;
+        let mut count = 0;
+
+        if format == ReviewExportFormat::Csv {
+            let mut header = String::new();
+            push_csv_row(&mut header, &REVIEW_CSV_HEADER);
+            file.write_all(header.as_bytes()).await?;
+        }
+
+        while let Some(page) = pages.next(&self.base).await? {
+            let mut chunk = String::new();
+            for review in &page {
+                match format {
+                    ReviewExportFormat::Csv => review.push_csv_row(&mut chunk),
+                    ReviewExportFormat::JsonLines => {
+                        chunk.push_str(&serde_json::to_string(review)?);
+                        chunk.push('\n');
+                    }
+                }
+            }
+            file.write_all(chunk.as_bytes()).await?;
+            count += page.len();
+        }
+
+        file.flush().await?;
+        Ok(count)
+    }
+
+    pub async fn get_review(
+        &self,
+        review_id: &str,
+    ) -> Result<CustomerReview, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("include".to_string(), "response".to_string());
+
+        let response = self
+            .base
+            .get(&format!("customerReviews/{}", review_id), Some(params))
+            .await?;
+        let included = response
+            .get("included")
+            .and_then(|i| i.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        response
+            .get("data")
+            .and_then(|data| CustomerReview::from_value(data, &included))
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+}
+
+struct ReviewPages {
+    next: Option<(String, HashMap<String, String>)>,
+}
+
+impl ReviewPages {
+    fn new(app_id: &str, filter: &ReviewFilter) -> Result<Self, AppStoreConnectError> {
+        let mut params = filter.params()?;
+        params.insert("limit".to_string(), "200".to_string());
+
+        Ok(Self {
+            next: Some((format!("apps/{}/customerReviews", app_id), params)),
+        })
+    }
+
+    async fn next(
+        &mut self,
+        base: &BaseAPI,
+    ) -> Result<Option<Vec<CustomerReview>>, AppStoreConnectError> {
+        let Some((endpoint, params)) = self.next.take() else {
+            return Ok(None);
+        };
+
+        let response = base.get(&endpoint, Some(params)).await?;
+        let included = response
+            .get("included")
+            .and_then(|i| i.as_array())
+            .cloned()
+            .unwrap_or_default();
+        let page = response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .map(|data| {
+                data.iter()
+                    .filter_map(|review| CustomerReview::from_value(review, &included))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.next = next_page(&response);
+        Ok(Some(page))
+    }
+}
@@ -0,0 +1,166 @@
+use crate::base::BaseAPI;
+use crate::error::AppStoreConnectError;
+use crate::reports::{gunzip, parse_sales_report, SalesReportRow, SalesReportStream};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SalesReportFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl SalesReportFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SalesReportFrequency::Daily => "DAILY",
+            SalesReportFrequency::Weekly => "WEEKLY",
+            SalesReportFrequency::Monthly => "MONTHLY",
+            SalesReportFrequency::Yearly => "YEARLY",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SalesReportQuery {
+    pub vendor_number: String,
+    pub frequency: SalesReportFrequency,
+    pub report_date: String,
+    pub report_type: String,
+    pub report_sub_type: String,
+    pub version: Option<String>,
+}
+
+impl SalesReportQuery {
+    pub fn summary(
+        vendor_number: impl Into<String>,
+        frequency: SalesReportFrequency,
+        report_date: impl Into<String>,
+    ) -> Self {
+        Self {
+            vendor_number: vendor_number.into(),
+            frequency,
+            report_date: report_date.into(),
+            report_type: "SALES".to_string(),
+            report_sub_type: "SUMMARY".to_string(),
+            version: None,
+        }
+    }
+
+    fn params(&self) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        params.insert(
+            "filter[vendorNumber]".to_string(),
+            self.vendor_number.clone(),
+        );
+        params.insert(
+            "filter[frequency]".to_string(),
+            self.frequency.as_str().to_string(),
+        );
+        params.insert("filter[reportDate]".to_string(), self.report_date.clone());
+        params.insert("filter[reportType]".to_string(), self.report_type.clone());
+        params.insert(
+            "filter[reportSubType]".to_string(),
+            self.report_sub_type.clone(),
+        );
+
+        if let Some(version) = &self.version {
+            params.insert("filter[version]".to_string(), version.clone());
+        }
+
+        params
+    }
+}
+
+#[derive(Clone)]
+pub struct SalesReportsAPI {
+    base: BaseAPI,
+}
+
+impl SalesReportsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn download_sales_report(
+        &self,
+        query: &SalesReportQuery,
+    ) -> Result<String, AppStoreConnectError> {
+        let compressed = self
+            .base
+            .get_raw("salesReports", Some(query.params()), "application/a-gzip")
+            .await?;
+        gunzip(&compressed)
+    }
+
+    pub async fn get_sales_report(
+        &self,
+        query: &SalesReportQuery,
+    ) -> Result<Vec<SalesReportRow>, AppStoreConnectError> {
+        let content = self.download_sales_report(query).await?;
+        parse_sales_report(&content)
+    }
+
+    pub async fn download_sales_report_to_file(
+        &self,
+        query: &SalesReportQuery,
+        path: impl AsRef<Path>,
+    ) -> Result<u64, AppStoreConnectError> {
+        self.base
+            .download_query_to_file(
+                "salesReports",
+                Some(query.params()),
+                Some("application/a-gzip"),
+                path.as_ref(),
+            )
+            .await
+    }
+
+    pub async fn stream_sales_report(
+        &self,
+        query: &SalesReportQuery,
+        path: impl AsRef<Path>,
+    ) -> Result<SalesReportStream, AppStoreConnectError> {
+        self.download_sales_report_to_file(query, path.as_ref())
+            .await?;
+        SalesReportStream::open(path).await
+    }
+
+    pub async fn download_finance_report(
+        &self,
+        vendor_number: &str,
+        region_code: &str,
+        fiscal_month: &str,
+    ) -> Result<String, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert(
+            "filter[vendorNumber]".to_string(),
+            vendor_number.to_string(),
+        );
+        params.insert("filter[regionCode]".to_string(), region_code.to_string());
+        params.insert("filter[reportDate]".to_string(), fiscal_month.to_string());
+        params.insert("filter[reportType]".to_string(), "FINANCIAL".to_string());
+
+        let compressed = self
+            .base
+            .get_raw("financeReports", Some(params), "application/a-gzip")
+            .await?;
+        gunzip(&compressed)
+    }
+
+    pub async fn save_finance_report(
+        &self,
+        vendor_number: &str,
+        region_code: &str,
+        fiscal_month: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<(), AppStoreConnectError> {
+        let content = self
+            .download_finance_report(vendor_number, region_code, fiscal_month)
+            .await?;
+        tokio::fs::write(path, content).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::base::{linkages, BaseAPI, V2_BASE_URL};
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde_json::{json, Value};
+
+#[derive(Clone)]
+pub struct SandboxAPI {
+    base: BaseAPI,
+}
+
+impl SandboxAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_testers(&self) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(&format!("{}sandboxTesters", V2_BASE_URL), None, None)
+            .await
+    }
+
+    pub async fn update_tester(
+        &self,
+        tester_id: &str,
+        territory: Option<&str>,
+        interrupt_purchases: Option<bool>,
+        subscription_renewal_rate: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({});
+
+        if let Some(territory) = territory {
+            attributes["territory"] = json!(territory);
+        }
+        if let Some(interrupt) = interrupt_purchases {
+            attributes["interruptPurchases"] = json!(interrupt);
+        }
+        if let Some(rate) = subscription_renewal_rate {
+            attributes["subscriptionRenewalRate"] = json!(rate);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "sandboxTesters",
+                "id": tester_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self
+            .base
+            .patch(
+                &format!("{}sandboxTesters/{}", V2_BASE_URL, tester_id),
+                data,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn clear_purchase_history(
+        &self,
+        tester_ids: &[&str],
+    ) -> Result<Value, AppStoreConnectError> {
+        if tester_ids.is_empty() {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                "At least one sandbox tester is required to clear purchase history",
+            )));
+        }
+
+        let data = json!({
+            "data": {
+                "type": "sandboxTestersClearPurchaseHistoryRequest",
+                "relationships": {
+                    "sandboxTesters": {
+                        "data": linkages("sandboxTesters", tester_ids)
+                    }
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .post(
+                &format!("{}sandboxTestersClearPurchaseHistoryRequest", V2_BASE_URL),
+                data,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn clear_all_purchase_history(&self) -> Result<Value, AppStoreConnectError> {
+        let testers = self.get_testers().await?;
+        let tester_ids: Vec<&str> = testers
+            .iter()
+            .filter_map(|t| t.get("id").and_then(|i| i.as_str()))
+            .collect();
+
+        self.clear_purchase_history(&tester_ids).await
+    }
+}
@@ -0,0 +1,284 @@
+use crate::base::BaseAPI;
+use crate::bulk::{BulkAction, BulkResult};
+use crate::error::AppStoreConnectError;
+use crate::snapshot::index_by_locale;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct SubscriptionGroupsAPI {
+    base: BaseAPI,
+}
+
+impl SubscriptionGroupsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, app_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(&format!("apps/{}/subscriptionGroups", app_id), None, None)
+            .await
+    }
+
+    pub async fn get(&self, group_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("subscriptionGroups/{}", group_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn find_by_reference_name(
+        &self,
+        app_id: &str,
+        reference_name: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let groups = self.get_all(app_id).await?;
+
+        Ok(groups.into_iter().find(|g| {
+            g.get("attributes")
+                .and_then(|a| a.get("referenceName"))
+                .and_then(|n| n.as_str())
+                == Some(reference_name)
+        }))
+    }
+
+    pub async fn create(
+        &self,
+        app_id: &str,
+        reference_name: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "subscriptionGroups",
+                "attributes": {
+                    "referenceName": reference_name
+                },
+                "relationships": {
+                    "app": {
+                        "data": {
+                            "type": "apps",
+                            "id": app_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("subscriptionGroups", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        group_id: &str,
+        reference_name: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "subscriptionGroups",
+                "id": group_id,
+                "attributes": {
+                    "referenceName": reference_name
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("subscriptionGroups/{}", group_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, group_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("subscriptionGroups/{}", group_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_localizations(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!(
+                    "subscriptionGroups/{}/subscriptionGroupLocalizations",
+                    group_id
+                ),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get_subscriptions(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("subscriptionGroups/{}/subscriptions", group_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn create_localization(
+        &self,
+        group_id: &str,
+        locale: &str,
+        name: &str,
+        custom_app_name: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({
+            "locale": locale,
+            "name": name
+        });
+
+        if let Some(custom_app_name) = custom_app_name {
+            attributes["customAppName"] = json!(custom_app_name);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "subscriptionGroupLocalizations",
+                "attributes": attributes,
+                "relationships": {
+                    "subscriptionGroup": {
+                        "data": {
+                            "type": "subscriptionGroups",
+                            "id": group_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .post("subscriptionGroupLocalizations", data)
+            .await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update_localization(
+        &self,
+        localization_id: &str,
+        name: Option<&str>,
+        custom_app_name: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({});
+
+        if let Some(name) = name {
+            attributes["name"] = json!(name);
+        }
+        if let Some(custom_app_name) = custom_app_name {
+            attributes["customAppName"] = json!(custom_app_name);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "subscriptionGroupLocalizations",
+                "id": localization_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self
+            .base
+            .patch(
+                &format!("subscriptionGroupLocalizations/{}", localization_id),
+                data,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete_localization(
+        &self,
+        localization_id: &str,
+    ) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!(
+                "subscriptionGroupLocalizations/{}",
+                localization_id
+            ))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn bulk_update_localizations(
+        &self,
+        group_id: &str,
+        localizations: HashMap<String, Value>,
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
+        let existing = index_by_locale(self.get_localizations(group_id).await?);
+        let mut results = Vec::new();
+
+        for (locale, attributes) in localizations {
+            let field = |name: &str| attributes.get(name).and_then(|v| v.as_str());
+
+            let result = if let Some((localization_id, _)) = existing.get(&locale) {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Updated,
+                    self.update_localization(
+                        localization_id,
+                        field("name"),
+                        field("customAppName"),
+                    )
+                    .await,
+                )
+            } else {
+                let created = match field("name") {
+                    Some(name) => {
+                        self.create_localization(group_id, &locale, name, field("customAppName"))
+                            .await
+                    }
+                    None => Err(AppStoreConnectError::Api {
+                        message: "A name is required to create a localization".to_string(),
+                    }),
+                };
+
+                BulkResult::from_result(&locale, BulkAction::Created, created)
+            };
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+}
@@ -0,0 +1,340 @@
+use crate::base::{linkages, BaseAPI};
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+pub const DRAFT_SUBSCRIPTION_STATES: &[&str] = &["MISSING_METADATA", "READY_TO_SUBMIT"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubscriptionPeriod {
+    OneWeek,
+    OneMonth,
+    TwoMonths,
+    ThreeMonths,
+    SixMonths,
+    OneYear,
+}
+
+impl SubscriptionPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubscriptionPeriod::OneWeek => "ONE_WEEK",
+            SubscriptionPeriod::OneMonth => "ONE_MONTH",
+            SubscriptionPeriod::TwoMonths => "TWO_MONTHS",
+            SubscriptionPeriod::ThreeMonths => "THREE_MONTHS",
+            SubscriptionPeriod::SixMonths => "SIX_MONTHS",
+            SubscriptionPeriod::OneYear => "ONE_YEAR",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SubscriptionsAPI {
+    base: BaseAPI,
+}
+
+impl SubscriptionsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, group_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("subscriptionGroups/{}/subscriptions", group_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get(&self, subscription_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("subscriptions/{}", subscription_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_state(&self, subscription_id: &str) -> Result<String, AppStoreConnectError> {
+        let subscription = self.get(subscription_id).await?;
+
+        subscription
+            .get("attributes")
+            .and_then(|a| a.get("state"))
+            .and_then(|s| s.as_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Subscription is missing a state".to_string(),
+            })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        group_id: &str,
+        name: &str,
+        product_id: &str,
+        subscription_period: SubscriptionPeriod,
+        group_level: Option<u32>,
+        review_note: Option<&str>,
+        family_sharable: Option<bool>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({
+            "name": name,
+            "productId": product_id,
+            "subscriptionPeriod": subscription_period.as_str()
+        });
+
+        if let Some(level) = group_level {
+            attributes["groupLevel"] = json!(level);
+        }
+        if let Some(note) = review_note {
+            attributes["reviewNote"] = json!(note);
+        }
+        if let Some(sharable) = family_sharable {
+            attributes["familySharable"] = json!(sharable);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "subscriptions",
+                "attributes": attributes,
+                "relationships": {
+                    "group": {
+                        "data": {
+                            "type": "subscriptionGroups",
+                            "id": group_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("subscriptions", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        subscription_id: &str,
+        name: Option<&str>,
+        subscription_period: Option<SubscriptionPeriod>,
+        group_level: Option<u32>,
+        review_note: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let mut attributes = json!({});
+
+        if let Some(name) = name {
+            attributes["name"] = json!(name);
+        }
+        if let Some(period) = subscription_period {
+            attributes["subscriptionPeriod"] = json!(period.as_str());
+        }
+        if let Some(level) = group_level {
+            attributes["groupLevel"] = json!(level);
+        }
+        if let Some(note) = review_note {
+            attributes["reviewNote"] = json!(note);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "subscriptions",
+                "id": subscription_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("subscriptions/{}", subscription_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, subscription_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("subscriptions/{}", subscription_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete_draft(&self, subscription_id: &str) -> Result<(), AppStoreConnectError> {
+        let state = self.get_state(subscription_id).await?;
+
+        if !DRAFT_SUBSCRIPTION_STATES.contains(&state.as_str()) {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                format!(
+                    "Subscription {} is in state {} and can no longer be deleted",
+                    subscription_id, state
+                ),
+            )));
+        }
+
+        self.delete(subscription_id).await
+    }
+
+    pub async fn get_availability(
+        &self,
+        subscription_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        match self
+            .base
+            .get(
+                &format!("subscriptions/{}/subscriptionAvailability", subscription_id),
+                None,
+            )
+            .await
+        {
+            Ok(response) => Ok(response.get("data").cloned().filter(|d| !d.is_null())),
+            Err(AppStoreConnectError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn is_available_in_new_territories(
+        &self,
+        subscription_id: &str,
+    ) -> Result<bool, AppStoreConnectError> {
+        let availability = self.get_availability(subscription_id).await?;
+
+        Ok(availability
+            .as_ref()
+            .and_then(|a| a.get("attributes"))
+            .and_then(|a| a.get("availableInNewTerritories"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false))
+    }
+
+    pub async fn get_available_territories(
+        &self,
+        subscription_id: &str,
+    ) -> Result<Vec<String>, AppStoreConnectError> {
+        let availability_id = match self.get_availability(subscription_id).await? {
+            Some(availability) => availability
+                .get("id")
+                .and_then(|i| i.as_str())
+                .map(|id| id.to_string())
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: "Invalid availability ID".to_string(),
+                })?,
+            None => return Ok(Vec::new()),
+        };
+
+        let territories = self
+            .base
+            .get_all_pages(
+                &format!(
+                    "subscriptionAvailabilities/{}/availableTerritories",
+                    availability_id
+                ),
+                None,
+                None,
+            )
+            .await?;
+
+        Ok(territories
+            .iter()
+            .filter_map(|t| t.get("id").and_then(|i| i.as_str()))
+            .map(|id| id.to_string())
+            .collect())
+    }
+
+    pub async fn set_availability(
+        &self,
+        subscription_id: &str,
+        territories: &[&str],
+        available_in_new_territories: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "subscriptionAvailabilities",
+                "attributes": {
+                    "availableInNewTerritories": available_in_new_territories
+                },
+                "relationships": {
+                    "subscription": {
+                        "data": { "type": "subscriptions", "id": subscription_id }
+                    },
+                    "availableTerritories": {
+                        "data": linkages("territories", territories)
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("subscriptionAvailabilities", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn set_family_sharable(
+        &self,
+        subscription_id: &str,
+        family_sharable: bool,
+    ) -> Result<Value, AppStoreConnectError> {
+        let current = self.get(subscription_id).await?;
+        let enabled = current
+            .get("attributes")
+            .and_then(|a| a.get("familySharable"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        if enabled == family_sharable {
+            return Ok(current);
+        }
+        if enabled && !family_sharable {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                format!(
+                "Family Sharing is already enabled for subscription {} and cannot be turned off",
+                subscription_id
+            ),
+            )));
+        }
+
+        let data = json!({
+            "data": {
+                "type": "subscriptions",
+                "id": subscription_id,
+                "attributes": {
+                    "familySharable": true
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .patch(&format!("subscriptions/{}", subscription_id), data)
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+}
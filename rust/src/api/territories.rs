@@ -0,0 +1,264 @@
+use crate::base::BaseAPI;
+use crate::error::AppStoreConnectError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub const COUNTRY_TERRITORIES: &[(&str, &str)] = &[
+    ("AE", "ARE"),
+    ("AF", "AFG"),
+    ("AG", "ATG"),
+    ("AI", "AIA"),
+    ("AL", "ALB"),
+    ("AM", "ARM"),
+    ("AO", "AGO"),
+    ("AR", "ARG"),
+    ("AT", "AUT"),
+    ("AU", "AUS"),
+    ("AZ", "AZE"),
+    ("BA", "BIH"),
+    ("BB", "BRB"),
+    ("BE", "BEL"),
+    ("BF", "BFA"),
+    ("BG", "BGR"),
+    ("BH", "BHR"),
+    ("BJ", "BEN"),
+    ("BM", "BMU"),
+    ("BN", "BRN"),
+    ("BO", "BOL"),
+    ("BR", "BRA"),
+    ("BS", "BHS"),
+    ("BT", "BTN"),
+    ("BW", "BWA"),
+    ("BY", "BLR"),
+    ("BZ", "BLZ"),
+    ("CA", "CAN"),
+    ("CD", "COD"),
+    ("CG", "COG"),
+    ("CH", "CHE"),
+    ("CI", "CIV"),
+    ("CL", "CHL"),
+    ("CM", "CMR"),
+    ("CN", "CHN"),
+    ("CO", "COL"),
+    ("CR", "CRI"),
+    ("CV", "CPV"),
+    ("CY", "CYP"),
+    ("CZ", "CZE"),
+    ("DE", "DEU"),
+    ("DK", "DNK"),
+    ("DM", "DMA"),
+    ("DO", "DOM"),
+    ("DZ", "DZA"),
+    ("EC", "ECU"),
+    ("EE", "EST"),
+    ("EG", "EGY"),
+    ("ES", "ESP"),
+    ("FI", "FIN"),
+    ("FJ", "FJI"),
+    ("FM", "FSM"),
+    ("FR", "FRA"),
+    ("GA", "GAB"),
+    ("GB", "GBR"),
+    ("GD", "GRD"),
+    ("GE", "GEO"),
+    ("GH", "GHA"),
+    ("GM", "GMB"),
+    ("GR", "GRC"),
+    ("GT", "GTM"),
+    ("GW", "GNB"),
+    ("GY", "GUY"),
+    ("HK", "HKG"),
+    ("HN", "HND"),
+    ("HR", "HRV"),
+    ("HU", "HUN"),
+    ("ID", "IDN"),
+    ("IE", "IRL"),
+    ("IL", "ISR"),
+    ("IN", "IND"),
+    ("IQ", "IRQ"),
+    ("IS", "ISL"),
+    ("IT", "ITA"),
+    ("JM", "JAM"),
+    ("JO", "JOR"),
+    ("JP", "JPN"),
+    ("KE", "KEN"),
+    ("KG", "KGZ"),
+    ("KH", "KHM"),
+    ("KN", "KNA"),
+    ("KR", "KOR"),
+    ("KW", "KWT"),
+    ("KY", "CYM"),
+    ("KZ", "KAZ"),
+    ("LA", "LAO"),
+    ("LB", "LBN"),
+    ("LC", "LCA"),
+    ("LK", "LKA"),
+    ("LR", "LBR"),
+    ("LT", "LTU"),
+    ("LU", "LUX"),
+    ("LV", "LVA"),
+    ("LY", "LBY"),
+    ("MA", "MAR"),
+    ("MD", "MDA"),
+    ("ME", "MNE"),
+    ("MG", "MDG"),
+    ("MK", "MKD"),
+    ("ML", "MLI"),
+    ("MM", "MMR"),
+    ("MN", "MNG"),
+    ("MO", "MAC"),
+    ("MR", "MRT"),
+    ("MS", "MSR"),
+    ("MT", "MLT"),
+    ("MU", "MUS"),
+    ("MV", "MDV"),
+    ("MW", "MWI"),
+    ("MX", "MEX"),
+    ("MY", "MYS"),
+    ("MZ", "MOZ"),
+    ("NA", "NAM"),
+    ("NE", "NER"),
+    ("NG", "NGA"),
+    ("NI", "NIC"),
+    ("NL", "NLD"),
+    ("NO", "NOR"),
+    ("NP", "NPL"),
+    ("NR", "NRU"),
+    ("NZ", "NZL"),
+    ("OM", "OMN"),
+    ("PA", "PAN"),
+    ("PE", "PER"),
+    ("PG", "PNG"),
+    ("PH", "PHL"),
+    ("PK", "PAK"),
+    ("PL", "POL"),
+    ("PT", "PRT"),
+    ("PW", "PLW"),
+    ("PY", "PRY"),
+    ("QA", "QAT"),
+    ("RO", "ROU"),
+    ("RS", "SRB"),
+    ("RU", "RUS"),
+    ("RW", "RWA"),
+    ("SA", "SAU"),
+    ("SB", "SLB"),
+    ("SC", "SYC"),
+    ("SE", "SWE"),
+    ("SG", "SGP"),
+    ("SI", "SVN"),
+    ("SK", "SVK"),
+    ("SL", "SLE"),
+    ("SN", "SEN"),
+    ("SR", "SUR"),
+    ("ST", "STP"),
+    ("SV", "SLV"),
+    ("SZ", "SWZ"),
+    ("TC", "TCA"),
+    ("TD", "TCD"),
+    ("TH", "THA"),
+    ("TJ", "TJK"),
+    ("TM", "TKM"),
+    ("TN", "TUN"),
+    ("TO", "TON"),
+    ("TR", "TUR"),
+    ("TT", "TTO"),
+    ("TW", "TWN"),
+    ("TZ", "TZA"),
+    ("UA", "UKR"),
+    ("UG", "UGA"),
+    ("US", "USA"),
+    ("UY", "URY"),
+    ("UZ", "UZB"),
+    ("VC", "VCT"),
+    ("VE", "VEN"),
+    ("VG", "VGB"),
+    ("VN", "VNM"),
+    ("VU", "VUT"),
+    ("XK", "XKS"),
+    ("YE", "YEM"),
+    ("ZA", "ZAF"),
+    ("ZM", "ZMB"),
+    ("ZW", "ZWE"),
+];
+
+pub fn territory_for_country(country_code: &str) -> Option<&'static str> {
+    let code = country_code.to_uppercase();
+    COUNTRY_TERRITORIES
+        .iter()
+        .find(|(country, _)| *country == code)
+        .map(|(_, territory)| *territory)
+}
+
+pub fn country_for_territory(territory_id: &str) -> Option<&'static str> {
+    let id = territory_id.to_uppercase();
+    COUNTRY_TERRITORIES
+        .iter()
+        .find(|(_, territory)| *territory == id)
+        .map(|(country, _)| *country)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Territory {
+    pub id: String,
+    pub currency: Option<String>,
+    pub country_code: Option<String>,
+}
+
+impl Territory {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let id = value.get("id").and_then(|i| i.as_str())?.to_string();
+        let currency = value
+            .get("attributes")
+            .and_then(|a| a.get("currency"))
+            .and_then(|c| c.as_str())
+            .map(|c| c.to_string());
+
+        Some(Self {
+            country_code: country_for_territory(&id).map(|c| c.to_string()),
+            id,
+            currency,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct TerritoriesAPI {
+    base: BaseAPI,
+}
+
+impl TerritoriesAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self) -> Result<Vec<Territory>, AppStoreConnectError> {
+        let territories = self.base.get_all_pages("territories", None, None).await?;
+
+        Ok(territories
+            .iter()
+            .filter_map(Territory::from_value)
+            .collect())
+    }
+
+    pub async fn currencies(&self) -> Result<HashMap<String, String>, AppStoreConnectError> {
+        Ok(self
+            .get_all()
+            .await?
+            .into_iter()
+            .filter_map(|t| t.currency.map(|currency| (t.id, currency)))
+            .collect())
+    }
+
+    pub async fn for_currency(
+        &self,
+        currency: &str,
+    ) -> Result<Vec<Territory>, AppStoreConnectError> {
+        Ok(self
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|t| t.currency.as_deref() == Some(currency))
+            .collect())
+    }
+}
@@ -0,0 +1,279 @@
+use crate::api::beta_build_localizations::BetaBuildLocalizationsAPI;
+use crate::api::builds::BuildsAPI;
+use crate::base::{linkages, BaseAPI};
+use crate::bulk::BulkAction;
+use crate::error::AppStoreConnectError;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const PROCESSING_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildFilter {
+    pub version: String,
+    pub build_number: String,
+}
+
+impl BuildFilter {
+    pub fn new(version: impl Into<String>, build_number: impl Into<String>) -> Self {
+        Self {
+            version: version.into(),
+            build_number: build_number.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StepOutcome {
+    Done,
+    Skipped(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DistributionStep {
+    pub step: String,
+    pub outcome: StepOutcome,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DistributionReport {
+    pub build_id: Option<String>,
+    pub steps: Vec<DistributionStep>,
+}
+
+impl DistributionReport {
+    fn record(&mut self, step: impl Into<String>, outcome: StepOutcome) {
+        self.steps.push(DistributionStep {
+            step: step.into(),
+            outcome,
+        });
+    }
+
+    fn record_result<T>(
+        &mut self,
+        step: impl Into<String>,
+        result: Result<T, AppStoreConnectError>,
+    ) {
+        let outcome = match result {
+            Ok(_) => StepOutcome::Done,
+            Err(e) => StepOutcome::Failed(e.to_string()),
+        };
+        self.record(step, outcome);
+    }
+
+    pub fn failures(&self) -> Vec<&DistributionStep> {
+        self.steps
+            .iter()
+            .filter(|s| matches!(s.outcome, StepOutcome::Failed(_)))
+            .collect()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.build_id.is_some() && self.failures().is_empty()
+    }
+}
+
+#[derive(Clone)]
+pub struct TestFlightAPI {
+    base: BaseAPI,
+    builds: BuildsAPI,
+    beta_build_localizations: BetaBuildLocalizationsAPI,
+}
+
+impl TestFlightAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self {
+            builds: BuildsAPI::new(base.clone()),
+            beta_build_localizations: BetaBuildLocalizationsAPI::new(base.clone()),
+            base,
+        }
+    }
+
+    pub async fn get_groups(&self, app_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[app]".to_string(), app_id.to_string());
+
+        self.base
+            .get_all_pages("betaGroups", Some(params), None)
+            .await
+    }
+
+    pub async fn add_builds_to_group(
+        &self,
+        group_id: &str,
+        build_ids: &[&str],
+    ) -> Result<(), AppStoreConnectError> {
+        let data = json!({ "data": linkages("builds", build_ids) });
+
+        self.base
+            .post(
+                &format!("betaGroups/{}/relationships/builds", group_id),
+                data,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_beta_review_submission(
+        &self,
+        build_id: &str,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("builds/{}/betaAppReviewSubmission", build_id),
+                None,
+            )
+            .await?;
+
+        Ok(response.get("data").cloned().filter(|d| !d.is_null()))
+    }
+
+    pub async fn submit_for_beta_review(
+        &self,
+        build_id: &str,
+    ) -> Result<Value, AppStoreConnectError> {
+        let data = json!({
+            "data": {
+                "type": "betaAppReviewSubmissions",
+                "relationships": {
+                    "build": {
+                        "data": {
+                            "type": "builds",
+                            "id": build_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("betaAppReviewSubmissions", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn distribute(
+        &self,
+        app_id: &str,
+        build_filter: &BuildFilter,
+        groups: &[&str],
+        whats_new: Option<HashMap<String, String>>,
+    ) -> Result<DistributionReport, AppStoreConnectError> {
+        let mut report = DistributionReport::default();
+
+        let build = match self
+            .builds
+            .wait_for_processing(
+                app_id,
+                &build_filter.version,
+                &build_filter.build_number,
+                PROCESSING_TIMEOUT,
+            )
+            .await
+        {
+            Ok(build) => build,
+            Err(e) => {
+                report.record("wait for processing", StepOutcome::Failed(e.to_string()));
+                return Ok(report);
+            }
+        };
+        report.record("wait for processing", StepOutcome::Done);
+
+        let build_id = build
+            .get("id")
+            .and_then(|i| i.as_str())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid build ID".to_string(),
+            })?
+            .to_string();
+        report.build_id = Some(build_id.clone());
+
+        match whats_new {
+            Some(notes) => {
+                let mut results = self
+                    .beta_build_localizations
+                    .bulk_update(&build_id, notes)
+                    .await?;
+                results.sort_by(|a, b| a.locale.cmp(&b.locale));
+
+                for result in results {
+                    let outcome = match result.action {
+                        BulkAction::Failed(error) => StepOutcome::Failed(error),
+                        _ => StepOutcome::Done,
+                    };
+                    report.record(format!("what to test [{}]", result.locale), outcome);
+                }
+            }
+            None => report.record(
+                "what to test",
+                StepOutcome::Skipped("No notes provided".to_string()),
+            ),
+        }
+
+        let app_groups = self.get_groups(app_id).await?;
+        let mut needs_beta_review = false;
+
+        for group in groups {
+            let matched = app_groups.iter().find(|g| {
+                g.get("id").and_then(|i| i.as_str()) == Some(*group)
+                    || g.get("attributes")
+                        .and_then(|a| a.get("name"))
+                        .and_then(|n| n.as_str())
+                        == Some(*group)
+            });
+            let step = format!("assign group {}", group);
+
+            let Some(matched) = matched else {
+                report.record(
+                    step,
+                    StepOutcome::Failed("Beta group not found".to_string()),
+                );
+                continue;
+            };
+
+            let group_id = matched
+                .get("id")
+                .and_then(|i| i.as_str())
+                .unwrap_or_default();
+            let is_internal = matched
+                .get("attributes")
+                .and_then(|a| a.get("isInternalGroup"))
+                .and_then(|i| i.as_bool())
+                .unwrap_or(false);
+
+            let result = self.add_builds_to_group(group_id, &[&build_id]).await;
+            if result.is_ok() && !is_internal {
+                needs_beta_review = true;
+            }
+            report.record_result(step, result);
+        }
+
+        if !needs_beta_review {
+            report.record(
+                "beta review",
+                StepOutcome::Skipped("No external groups assigned".to_string()),
+            );
+            return Ok(report);
+        }
+
+        let (_, external_state) = self.builds.get_beta_states(&build_id).await?;
+        if external_state == "READY_FOR_BETA_SUBMISSION" {
+            let result = self.submit_for_beta_review(&build_id).await;
+            report.record_result("beta review", result);
+        } else {
+            report.record(
+                "beta review",
+                StepOutcome::Skipped(format!("External build state is {}", external_state)),
+            );
+        }
+
+        Ok(report)
+    }
+}
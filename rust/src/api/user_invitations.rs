@@ -0,0 +1,238 @@
+use crate::api::users::{bool_attribute, role_names, roles_attribute, UserRole};
+use crate::base::{linkages, BaseAPI};
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserInvitation {
+    pub id: String,
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub roles: Vec<UserRole>,
+    pub all_apps_visible: bool,
+    pub provisioning_allowed: bool,
+    pub expiration_date: Option<String>,
+}
+
+impl UserInvitation {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            email: string_attribute(attributes, "email"),
+            first_name: string_attribute(attributes, "firstName"),
+            last_name: string_attribute(attributes, "lastName"),
+            roles: roles_attribute(attributes),
+            all_apps_visible: bool_attribute(attributes, "allAppsVisible"),
+            provisioning_allowed: bool_attribute(attributes, "provisioningAllowed"),
+            expiration_date: string_attribute(attributes, "expirationDate"),
+        })
+    }
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewUserInvitation {
+    pub email: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub roles: Vec<UserRole>,
+    pub all_apps_visible: bool,
+    pub provisioning_allowed: bool,
+    pub visible_app_ids: Vec<String>,
+}
+
+impl NewUserInvitation {
+    pub fn new(
+        email: impl Into<String>,
+        first_name: impl Into<String>,
+        last_name: impl Into<String>,
+        roles: &[UserRole],
+    ) -> Self {
+        Self {
+            email: email.into(),
+            first_name: first_name.into(),
+            last_name: last_name.into(),
+            roles: roles.to_vec(),
+            all_apps_visible: true,
+            provisioning_allowed: false,
+            visible_app_ids: Vec::new(),
+        }
+    }
+
+    // Limiting an invitation to specific apps only makes sense with
+    // allAppsVisible off, so selecting apps turns it off.
+    pub fn with_visible_apps<I, S>(mut self, app_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.visible_app_ids = app_ids.into_iter().map(Into::into).collect();
+        self.all_apps_visible = false;
+        self
+    }
+
+    pub fn with_provisioning_allowed(mut self, allowed: bool) -> Self {
+        self.provisioning_allowed = allowed;
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), AppStoreConnectError> {
+        let mut issues = Vec::new();
+
+        if !self.email.contains('@') {
+            issues.push(format!("'{}' is not an email address", self.email));
+        }
+        if self.first_name.trim().is_empty() || self.last_name.trim().is_empty() {
+            issues.push("first and last name are required".to_string());
+        }
+        if self.roles.is_empty() {
+            issues.push("at least one role is required".to_string());
+        }
+        if self.roles.contains(&UserRole::AccountHolder) {
+            issues.push("the ACCOUNT_HOLDER role cannot be granted by invitation".to_string());
+        }
+        if !self.all_apps_visible && self.visible_app_ids.is_empty() {
+            issues.push(
+                "at least one visible app is required when not all apps are visible".to_string(),
+            );
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(AppStoreConnectError::Validation(
+                ValidationError::with_issues(
+                    format!("Invitation for {} is not valid", self.email),
+                    issues,
+                ),
+            ))
+        }
+    }
+
+    pub fn to_request(&self) -> Value {
+        let mut data = json!({
+            "data": {
+                "type": "userInvitations",
+                "attributes": {
+                    "email": self.email,
+                    "firstName": self.first_name,
+                    "lastName": self.last_name,
+                    "roles": role_names(&self.roles),
+                    "allAppsVisible": self.all_apps_visible,
+                    "provisioningAllowed": self.provisioning_allowed
+                }
+            }
+        });
+
+        if !self.all_apps_visible {
+            let app_ids: Vec<&str> = self.visible_app_ids.iter().map(|id| id.as_str()).collect();
+            data["data"]["relationships"] = json!({
+                "visibleApps": { "data": linkages("apps", &app_ids) }
+            });
+        }
+
+        data
+    }
+}
+
+#[derive(Clone)]
+pub struct UserInvitationsAPI {
+    base: BaseAPI,
+}
+
+impl UserInvitationsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn create(
+        &self,
+        invitation: &NewUserInvitation,
+    ) -> Result<UserInvitation, AppStoreConnectError> {
+        invitation.validate()?;
+
+        let response = self
+            .base
+            .post("userInvitations", invitation.to_request())
+            .await?;
+
+        response
+            .get("data")
+            .and_then(UserInvitation::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_all(
+        &self,
+        email: Option<&str>,
+        roles: &[UserRole],
+    ) -> Result<Vec<UserInvitation>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if let Some(email) = email {
+            params.insert("filter[email]".to_string(), email.to_string());
+        }
+        if !roles.is_empty() {
+            params.insert("filter[roles]".to_string(), role_names(roles).join(","));
+        }
+
+        let invitations = self
+            .base
+            .get_all_pages("userInvitations", Some(params), None)
+            .await?;
+
+        Ok(invitations
+            .iter()
+            .filter_map(UserInvitation::from_value)
+            .collect())
+    }
+
+    pub async fn get(&self, invitation_id: &str) -> Result<UserInvitation, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(&format!("userInvitations/{}", invitation_id), None)
+            .await?;
+
+        response
+            .get("data")
+            .and_then(UserInvitation::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    // Invitation visibleApps are fixed at creation; to change them, cancel
+    // the invitation and send a new one.
+    pub async fn get_visible_apps(
+        &self,
+        invitation_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!("userInvitations/{}/visibleApps", invitation_id),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn cancel(&self, invitation_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("userInvitations/{}", invitation_id))
+            .await?;
+        Ok(())
+    }
+}
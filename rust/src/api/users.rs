@@ -0,0 +1,277 @@
+use crate::base::{linkages, BaseAPI};
+use crate::error::AppStoreConnectError;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UserRole {
+    Admin,
+    Finance,
+    AccountHolder,
+    Sales,
+    Marketing,
+    AppManager,
+    Developer,
+    AccessToReports,
+    CustomerSupport,
+    CreateApps,
+    CloudManagedDeveloperId,
+    CloudManagedAppDistribution,
+    GenerateIndividualKeys,
+}
+
+impl UserRole {
+    pub const ALL: [UserRole; 13] = [
+        UserRole::Admin,
+        UserRole::Finance,
+        UserRole::AccountHolder,
+        UserRole::Sales,
+        UserRole::Marketing,
+        UserRole::AppManager,
+        UserRole::Developer,
+        UserRole::AccessToReports,
+        UserRole::CustomerSupport,
+        UserRole::CreateApps,
+        UserRole::CloudManagedDeveloperId,
+        UserRole::CloudManagedAppDistribution,
+        UserRole::GenerateIndividualKeys,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserRole::Admin => "ADMIN",
+            UserRole::Finance => "FINANCE",
+            UserRole::AccountHolder => "ACCOUNT_HOLDER",
+            UserRole::Sales => "SALES",
+            UserRole::Marketing => "MARKETING",
+            UserRole::AppManager => "APP_MANAGER",
+            UserRole::Developer => "DEVELOPER",
+            UserRole::AccessToReports => "ACCESS_TO_REPORTS",
+            UserRole::CustomerSupport => "CUSTOMER_SUPPORT",
+            UserRole::CreateApps => "CREATE_APPS",
+            UserRole::CloudManagedDeveloperId => "CLOUD_MANAGED_DEVELOPER_ID",
+            UserRole::CloudManagedAppDistribution => "CLOUD_MANAGED_APP_DISTRIBUTION",
+            UserRole::GenerateIndividualKeys => "GENERATE_INDIVIDUAL_KEYS",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|role| role.as_str() == value)
+    }
+}
+
+pub fn role_names(roles: &[UserRole]) -> Vec<&'static str> {
+    roles.iter().map(|role| role.as_str()).collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamUser {
+    pub id: String,
+    pub username: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub roles: Vec<UserRole>,
+    pub all_apps_visible: bool,
+    pub provisioning_allowed: bool,
+}
+
+impl TeamUser {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let attributes = value.get("attributes");
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            username: string_attribute(attributes, "username"),
+            first_name: string_attribute(attributes, "firstName"),
+            last_name: string_attribute(attributes, "lastName"),
+            roles: roles_attribute(attributes),
+            all_apps_visible: bool_attribute(attributes, "allAppsVisible"),
+            provisioning_allowed: bool_attribute(attributes, "provisioningAllowed"),
+        })
+    }
+
+    pub fn has_role(&self, role: UserRole) -> bool {
+        self.roles.contains(&role)
+    }
+}
+
+pub(crate) fn roles_attribute(attributes: Option<&Value>) -> Vec<UserRole> {
+    attributes
+        .and_then(|a| a.get("roles"))
+        .and_then(|r| r.as_array())
+        .map(|roles| {
+            roles
+                .iter()
+                .filter_map(|r| r.as_str())
+                .filter_map(UserRole::parse)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn string_attribute(attributes: Option<&Value>, key: &str) -> Option<String> {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+pub(crate) fn bool_attribute(attributes: Option<&Value>, key: &str) -> bool {
+    attributes
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+#[derive(Clone)]
+pub struct UsersAPI {
+    base: BaseAPI,
+}
+
+impl UsersAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(
+        &self,
+        roles: &[UserRole],
+        username: Option<&str>,
+    ) -> Result<Vec<TeamUser>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+
+        if !roles.is_empty() {
+            params.insert("filter[roles]".to_string(), role_names(roles).join(","));
+        }
+        if let Some(username) = username {
+            params.insert("filter[username]".to_string(), username.to_string());
+        }
+
+        let users = self.base.get_all_pages("users", Some(params), None).await?;
+
+        Ok(users.iter().filter_map(TeamUser::from_value).collect())
+    }
+
+    pub async fn get(&self, user_id: &str) -> Result<TeamUser, AppStoreConnectError> {
+        let response = self.base.get(&format!("users/{}", user_id), None).await?;
+
+        response
+            .get("data")
+            .and_then(TeamUser::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn update(
+        &self,
+        user_id: &str,
+        roles: Option<&[UserRole]>,
+        all_apps_visible: Option<bool>,
+        provisioning_allowed: Option<bool>,
+    ) -> Result<TeamUser, AppStoreConnectError> {
+        let mut attributes = json!({});
+
+        if let Some(roles) = roles {
+            attributes["roles"] = json!(role_names(roles));
+        }
+        if let Some(visible) = all_apps_visible {
+            attributes["allAppsVisible"] = json!(visible);
+        }
+        if let Some(allowed) = provisioning_allowed {
+            attributes["provisioningAllowed"] = json!(allowed);
+        }
+
+        let data = json!({
+            "data": {
+                "type": "users",
+                "id": user_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self.base.patch(&format!("users/{}", user_id), data).await?;
+
+        response
+            .get("data")
+            .and_then(TeamUser::from_value)
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn remove(&self, user_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base.delete(&format!("users/{}", user_id)).await?;
+        Ok(())
+    }
+
+    pub async fn get_visible_apps(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(&format!("users/{}/visibleApps", user_id), None, None)
+            .await
+    }
+
+    // Apple ignores visibleApps while allAppsVisible is on, so callers
+    // restricting a user should also update() with all_apps_visible false.
+    pub async fn add_visible_apps(
+        &self,
+        user_id: &str,
+        app_ids: &[&str],
+    ) -> Result<(), AppStoreConnectError> {
+        let data = json!({ "data": linkages("apps", app_ids) });
+
+        self.base
+            .post(
+                &format!("users/{}/relationships/visibleApps", user_id),
+                data,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_visible_apps(
+        &self,
+        user_id: &str,
+        app_ids: &[&str],
+    ) -> Result<(), AppStoreConnectError> {
+        let data = json!({ "data": linkages("apps", app_ids) });
+
+        self.base
+            .patch(
+                &format!("users/{}/relationships/visibleApps", user_id),
+                data,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn remove_visible_apps(
+        &self,
+        user_id: &str,
+        app_ids: &[&str],
+    ) -> Result<(), AppStoreConnectError> {
+        let data = json!({ "data": linkages("apps", app_ids) });
+
+        self.base
+            .delete_with_data(
+                &format!("users/{}/relationships/visibleApps", user_id),
+                data,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn find_by_username(
+        &self,
+        username: &str,
+    ) -> Result<Option<TeamUser>, AppStoreConnectError> {
+        let users = self.get_all(&[], Some(username)).await?;
+        Ok(users
+            .into_iter()
+            .find(|user| user.username.as_deref() == Some(username)))
+    }
+}
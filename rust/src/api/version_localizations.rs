@@ -0,0 +1,370 @@
+use crate::base::BaseAPI;
+use crate::bulk::{export_by_locale, BulkAction, BulkResult};
+use crate::error::AppStoreConnectError;
+use crate::locale::normalize_locale;
+use crate::metadata::{check_field_limits, validate_field_limits, FieldUpdate};
+use crate::snapshot::VERSION_LOCALIZATION_FIELDS;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WhatsNew {
+    All(String),
+    PerLocale {
+        texts: HashMap<String, String>,
+        default: Option<String>,
+    },
+}
+
+impl WhatsNew {
+    pub fn for_locale(&self, locale: &str) -> Option<&str> {
+        match self {
+            WhatsNew::All(text) => Some(text),
+            WhatsNew::PerLocale { texts, default } => {
+                texts.get(locale).or(default.as_ref()).map(|t| t.as_str())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VersionLocalizationUpdate {
+    pub description: FieldUpdate<String>,
+    pub keywords: FieldUpdate<String>,
+    pub marketing_url: FieldUpdate<String>,
+    pub promotional_text: FieldUpdate<String>,
+    pub support_url: FieldUpdate<String>,
+    pub whats_new: FieldUpdate<String>,
+}
+
+impl VersionLocalizationUpdate {
+    pub fn from_value(attributes: &Value) -> Self {
+        Self {
+            description: FieldUpdate::from_attribute(attributes, "description"),
+            keywords: FieldUpdate::from_attribute(attributes, "keywords"),
+            marketing_url: FieldUpdate::from_attribute(attributes, "marketingUrl"),
+            promotional_text: FieldUpdate::from_attribute(attributes, "promotionalText"),
+            support_url: FieldUpdate::from_attribute(attributes, "supportUrl"),
+            whats_new: FieldUpdate::from_attribute(attributes, "whatsNew"),
+        }
+    }
+
+    pub fn to_attributes(&self) -> Value {
+        let mut attributes = json!({});
+        self.description.write(&mut attributes, "description");
+        self.keywords.write(&mut attributes, "keywords");
+        self.marketing_url.write(&mut attributes, "marketingUrl");
+        self.promotional_text
+            .write(&mut attributes, "promotionalText");
+        self.support_url.write(&mut attributes, "supportUrl");
+        self.whats_new.write(&mut attributes, "whatsNew");
+        attributes
+    }
+}
+
+#[derive(Clone)]
+pub struct VersionLocalizationsAPI {
+    base: BaseAPI,
+}
+
+impl VersionLocalizationsAPI {
+    pub fn new(base: BaseAPI) -> Self {
+        Self { base }
+    }
+
+    pub async fn get_all(&self, version_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        self.base
+            .get_all_pages(
+                &format!(
+                    "appStoreVersions/{}/appStoreVersionLocalizations",
+                    version_id
+                ),
+                None,
+                None,
+            )
+            .await
+    }
+
+    pub async fn get(&self, localization_id: &str) -> Result<Value, AppStoreConnectError> {
+        let response = self
+            .base
+            .get(
+                &format!("appStoreVersionLocalizations/{}", localization_id),
+                None,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        version_id: &str,
+        locale: &str,
+        description: Option<&str>,
+        keywords: Option<&str>,
+        marketing_url: Option<&str>,
+        promotional_text: Option<&str>,
+        support_url: Option<&str>,
+        whats_new: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let locale = normalize_locale(locale)?;
+        let mut attributes = json!({ "locale": locale });
+
+        if let Some(description) = description {
+            attributes["description"] = json!(description);
+        }
+        if let Some(keywords) = keywords {
+            attributes["keywords"] = json!(keywords);
+        }
+        if let Some(url) = marketing_url {
+            attributes["marketingUrl"] = json!(url);
+        }
+        if let Some(text) = promotional_text {
+            attributes["promotionalText"] = json!(text);
+        }
+        if let Some(url) = support_url {
+            attributes["supportUrl"] = json!(url);
+        }
+        if let Some(text) = whats_new {
+            attributes["whatsNew"] = json!(text);
+        }
+
+        validate_field_limits(&locale, &attributes)?;
+
+        let data = json!({
+            "data": {
+                "type": "appStoreVersionLocalizations",
+                "attributes": attributes,
+                "relationships": {
+                    "appStoreVersion": {
+                        "data": {
+                            "type": "appStoreVersions",
+                            "id": version_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self.base.post("appStoreVersionLocalizations", data).await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        localization_id: &str,
+        description: Option<&str>,
+        keywords: Option<&str>,
+        marketing_url: Option<&str>,
+        promotional_text: Option<&str>,
+        support_url: Option<&str>,
+        whats_new: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let update = VersionLocalizationUpdate {
+            description: description.into(),
+            keywords: keywords.into(),
+            marketing_url: marketing_url.into(),
+            promotional_text: promotional_text.into(),
+            support_url: support_url.into(),
+            whats_new: whats_new.into(),
+        };
+        self.update_fields(localization_id, &update).await
+    }
+
+    pub async fn update_fields(
+        &self,
+        localization_id: &str,
+        update: &VersionLocalizationUpdate,
+    ) -> Result<Value, AppStoreConnectError> {
+        let attributes = update.to_attributes();
+
+        if !check_field_limits(&attributes).is_empty() {
+            // Only looked up on failure, so the error names the locale rather
+            // than the opaque localization ID.
+            let locale = self
+                .get(localization_id)
+                .await
+                .ok()
+                .and_then(|l| {
+                    l.get("attributes")?
+                        .get("locale")?
+                        .as_str()
+                        .map(String::from)
+                })
+                .unwrap_or_else(|| format!("localization {}", localization_id));
+            validate_field_limits(&locale, &attributes)?;
+        }
+
+        let data = json!({
+            "data": {
+                "type": "appStoreVersionLocalizations",
+                "id": localization_id,
+                "attributes": attributes
+            }
+        });
+
+        let response = self
+            .base
+            .patch(
+                &format!("appStoreVersionLocalizations/{}", localization_id),
+                data,
+            )
+            .await?;
+
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn delete(&self, localization_id: &str) -> Result<(), AppStoreConnectError> {
+        self.base
+            .delete(&format!("appStoreVersionLocalizations/{}", localization_id))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn bulk_update(
+        &self,
+        version_id: &str,
+        localizations: HashMap<String, Value>,
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
+        let existing = self.get_all(version_id).await?;
+        let mut existing_by_locale = HashMap::new();
+
+        for loc in existing {
+            if let (Some(locale), Some(id)) = (
+                loc.get("attributes")
+                    .and_then(|a| a.get("locale"))
+                    .and_then(|l| l.as_str()),
+                loc.get("id").and_then(|i| i.as_str()),
+            ) {
+                existing_by_locale.insert(locale.to_string(), id.to_string());
+            }
+        }
+
+        let mut results = Vec::new();
+
+        for (requested, attributes) in localizations {
+            let locale = match normalize_locale(&requested) {
+                Ok(locale) => locale,
+                Err(e) => {
+                    results.push(BulkResult::failed(requested, e));
+                    continue;
+                }
+            };
+            if let Err(e) = validate_field_limits(&locale, &attributes) {
+                results.push(BulkResult::failed(&locale, e));
+                continue;
+            }
+
+            let result = if let Some(localization_id) = existing_by_locale.get(&locale) {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Updated,
+                    self.update_from_value(localization_id, &attributes).await,
+                )
+            } else {
+                BulkResult::from_result(
+                    &locale,
+                    BulkAction::Created,
+                    self.create_from_value(version_id, &locale, &attributes)
+                        .await,
+                )
+            };
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    pub async fn bulk_export(
+        &self,
+        version_id: &str,
+    ) -> Result<HashMap<String, Value>, AppStoreConnectError> {
+        let localizations = self.get_all(version_id).await?;
+        Ok(export_by_locale(localizations, VERSION_LOCALIZATION_FIELDS))
+    }
+
+    pub async fn set_whats_new_all(
+        &self,
+        version_id: &str,
+        whats_new: &WhatsNew,
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
+        let mut results = Vec::new();
+
+        for localization in self.get_all(version_id).await? {
+            let (Some(locale), Some(localization_id)) = (
+                localization
+                    .get("attributes")
+                    .and_then(|a| a.get("locale"))
+                    .and_then(|l| l.as_str()),
+                localization.get("id").and_then(|i| i.as_str()),
+            ) else {
+                continue;
+            };
+            let Some(text) = whats_new.for_locale(locale) else {
+                continue;
+            };
+
+            let result = BulkResult::from_result(
+                locale,
+                BulkAction::Updated,
+                self.update(localization_id, None, None, None, None, None, Some(text))
+                    .await,
+            );
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    async fn update_from_value(
+        &self,
+        localization_id: &str,
+        attributes: &Value,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.update_fields(
+            localization_id,
+            &VersionLocalizationUpdate::from_value(attributes),
+        )
+        .await
+    }
+
+    async fn create_from_value(
+        &self,
+        version_id: &str,
+        locale: &str,
+        attributes: &Value,
+    ) -> Result<Value, AppStoreConnectError> {
+        let field = |name: &str| attributes.get(name).and_then(|v| v.as_str());
+
+        self.create(
+            version_id,
+            locale,
+            field("description"),
+            field("keywords"),
+            field("marketingUrl"),
+            field("promotionalText"),
+            field("supportUrl"),
+            field("whatsNew"),
+        )
+        .await
+    }
+}
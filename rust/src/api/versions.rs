@@ -1,6 +1,134 @@
 use crate::base::BaseAPI;
-use crate::error::AppStoreConnectError;
+use crate::error::{AppStoreConnectError, NotFoundError, ValidationError};
+use crate::metadata::FieldUpdate;
+use crate::polling::{poll_until, PollOptions};
+use chrono::{DateTime, SecondsFormat, Timelike, Utc};
 use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionBump {
+    Major,
+    Minor,
+    Patch,
+}
+
+pub fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let parts: Option<Vec<u64>> = version
+        .trim()
+        .split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect();
+
+    parts.filter(|p| !p.is_empty() && p.len() <= 3)
+}
+
+pub fn bump_version(version: &str, bump: VersionBump) -> Result<String, AppStoreConnectError> {
+    let mut parts = parse_version(version).ok_or_else(|| {
+        AppStoreConnectError::Validation(ValidationError::new(format!(
+            "Version string '{}' is not in major.minor.patch form",
+            version
+        )))
+    })?;
+
+    let index = match bump {
+        VersionBump::Major => 0,
+        VersionBump::Minor => 1,
+        VersionBump::Patch => 2,
+    };
+
+    while parts.len() <= index {
+        parts.push(0);
+    }
+
+    parts[index] += 1;
+    for part in parts.iter_mut().skip(index + 1) {
+        *part = 0;
+    }
+
+    Ok(parts
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>()
+        .join("."))
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VersionUpdate {
+    pub version_string: FieldUpdate<String>,
+    pub copyright: FieldUpdate<String>,
+    pub release_type: FieldUpdate<String>,
+    pub earliest_release_date: FieldUpdate<DateTime<Utc>>,
+    pub uses_idfa: FieldUpdate<bool>,
+    pub is_watch_only: FieldUpdate<bool>,
+    pub downloadable: FieldUpdate<bool>,
+}
+
+impl VersionUpdate {
+    pub fn to_attributes(&self) -> Value {
+        let mut attributes = json!({});
+        self.version_string.write(&mut attributes, "versionString");
+        self.copyright.write(&mut attributes, "copyright");
+        self.release_type.write(&mut attributes, "releaseType");
+        match &self.earliest_release_date {
+            FieldUpdate::Keep => {}
+            FieldUpdate::Clear => attributes["earliestReleaseDate"] = Value::Null,
+            FieldUpdate::Set(date) => {
+                attributes["earliestReleaseDate"] =
+                    json!(date.to_rfc3339_opts(SecondsFormat::Secs, true))
+            }
+        }
+        self.uses_idfa.write(&mut attributes, "usesIdfa");
+        self.is_watch_only.write(&mut attributes, "isWatchOnly");
+        self.downloadable.write(&mut attributes, "downloadable");
+        attributes
+    }
+}
+
+pub fn validate_release_date(date: &DateTime<Utc>) -> Result<(), AppStoreConnectError> {
+    if *date <= Utc::now() {
+        return Err(AppStoreConnectError::Validation(ValidationError::new(
+            format!("Earliest release date {} must be in the future", date),
+        )));
+    }
+
+    if date.minute() != 0 || date.second() != 0 || date.nanosecond() != 0 {
+        return Err(AppStoreConnectError::Validation(ValidationError::new(
+            format!(
+                "Earliest release date {} must be aligned to the start of an hour",
+                date
+            ),
+        )));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct VersionFilter {
+    pub platform: Option<String>,
+    pub app_store_states: Vec<String>,
+    pub version_string: Option<String>,
+    pub limit: Option<u32>,
+}
+
+impl VersionFilter {
+    pub fn platform(mut self, platform: impl Into<String>) -> Self {
+        self.platform = Some(platform.into());
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.app_store_states.push(state.into());
+        self
+    }
+
+    pub fn version_string(mut self, version_string: impl Into<String>) -> Self {
+        self.version_string = Some(version_string.into());
+        self
+    }
+}
 
 #[derive(Clone)]
 pub struct VersionsAPI {
@@ -13,16 +141,36 @@ impl VersionsAPI {
     }
 
     pub async fn get_all(&self, app_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
-        let response = self
-            .base
-            .get(&format!("apps/{}/appStoreVersions", app_id), None)
-            .await?;
+        self.list(app_id, &VersionFilter::default()).await
+    }
+
+    pub async fn list(
+        &self,
+        app_id: &str,
+        filter: &VersionFilter,
+    ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
 
-        if let Some(data) = response.get("data").and_then(|d| d.as_array()) {
-            Ok(data.clone())
-        } else {
-            Ok(Vec::new())
+        if let Some(platform) = &filter.platform {
+            params.insert("filter[platform]".to_string(), platform.clone());
         }
+        if !filter.app_store_states.is_empty() {
+            params.insert(
+                "filter[appStoreState]".to_string(),
+                filter.app_store_states.join(","),
+            );
+        }
+        if let Some(version_string) = &filter.version_string {
+            params.insert("filter[versionString]".to_string(), version_string.clone());
+        }
+
+        self.base
+            .get_all_pages(
+                &format!("apps/{}/appStoreVersions", app_id),
+                Some(params),
+                filter.limit,
+            )
+            .await
     }
 
     pub async fn get(&self, version_id: &str) -> Result<Value, AppStoreConnectError> {
@@ -113,46 +261,88 @@ impl VersionsAPI {
             })
     }
 
+    pub async fn get_latest_version_string(
+        &self,
+        app_id: &str,
+        platform: &str,
+    ) -> Result<Option<String>, AppStoreConnectError> {
+        let versions = self
+            .list(app_id, &VersionFilter::default().platform(platform))
+            .await?;
+
+        Ok(versions
+            .iter()
+            .filter_map(|v| {
+                v.get("attributes")
+                    .and_then(|a| a.get("versionString"))
+                    .and_then(|s| s.as_str())
+            })
+            .filter_map(|s| parse_version(s).map(|parsed| (parsed, s)))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, s)| s.to_string()))
+    }
+
+    pub async fn create_next(
+        &self,
+        app_id: &str,
+        bump: VersionBump,
+        platform: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let platform = platform.unwrap_or("IOS");
+        let current = self
+            .get_latest_version_string(app_id, platform)
+            .await?
+            .ok_or_else(|| {
+                AppStoreConnectError::Validation(ValidationError::new(format!(
+                    "App {} has no existing {} version to bump",
+                    app_id, platform
+                )))
+            })?;
+
+        let next = bump_version(&current, bump)?;
+        self.create(app_id, &next, Some(platform), None, None).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub async fn update(
         &self,
         version_id: &str,
         version_string: Option<&str>,
         copyright: Option<&str>,
         release_type: Option<&str>,
-        earliest_release_date: Option<&str>,
+        earliest_release_date: Option<DateTime<Utc>>,
         uses_idfa: Option<bool>,
         is_watch_only: Option<bool>,
         downloadable: Option<bool>,
     ) -> Result<Value, AppStoreConnectError> {
-        let mut attributes = json!({});
+        let update = VersionUpdate {
+            version_string: version_string.into(),
+            copyright: copyright.into(),
+            release_type: release_type.into(),
+            earliest_release_date: earliest_release_date
+                .map(FieldUpdate::Set)
+                .unwrap_or_default(),
+            uses_idfa: uses_idfa.into(),
+            is_watch_only: is_watch_only.into(),
+            downloadable: downloadable.into(),
+        };
+        self.update_fields(version_id, &update).await
+    }
 
-        if let Some(version_string) = version_string {
-            attributes["versionString"] = json!(version_string);
-        }
-        if let Some(copyright) = copyright {
-            attributes["copyright"] = json!(copyright);
-        }
-        if let Some(release_type) = release_type {
-            attributes["releaseType"] = json!(release_type);
-        }
-        if let Some(earliest_release_date) = earliest_release_date {
-            attributes["earliestReleaseDate"] = json!(earliest_release_date);
-        }
-        if let Some(uses_idfa) = uses_idfa {
-            attributes["usesIdfa"] = json!(uses_idfa);
-        }
-        if let Some(is_watch_only) = is_watch_only {
-            attributes["isWatchOnly"] = json!(is_watch_only);
-        }
-        if let Some(downloadable) = downloadable {
-            attributes["downloadable"] = json!(downloadable);
+    pub async fn update_fields(
+        &self,
+        version_id: &str,
+        update: &VersionUpdate,
+    ) -> Result<Value, AppStoreConnectError> {
+        if let FieldUpdate::Set(date) = &update.earliest_release_date {
+            validate_release_date(date)?;
         }
 
         let data = json!({
             "data": {
                 "type": "appStoreVersions",
                 "id": version_id,
-                "attributes": attributes
+                "attributes": update.to_attributes()
             }
         });
 
@@ -169,6 +359,9 @@ impl VersionsAPI {
             })
     }
 
+    #[deprecated(
+        note = "appStoreVersionSubmissions is deprecated; use ReviewSubmissionsAPI::submit_version"
+    )]
     pub async fn submit_for_review(&self, version_id: &str) -> Result<Value, AppStoreConnectError> {
         let data = json!({
             "data": {
@@ -193,6 +386,132 @@ impl VersionsAPI {
             })
     }
 
+    pub async fn schedule_release(
+        &self,
+        version_id: &str,
+        release_date: DateTime<Utc>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let update = VersionUpdate {
+            release_type: FieldUpdate::Set("SCHEDULED".to_string()),
+            earliest_release_date: FieldUpdate::Set(release_date),
+            ..Default::default()
+        };
+        self.update_fields(version_id, &update).await
+    }
+
+    pub async fn clear_schedule(&self, version_id: &str) -> Result<Value, AppStoreConnectError> {
+        let update = VersionUpdate {
+            release_type: FieldUpdate::Set("MANUAL".to_string()),
+            earliest_release_date: FieldUpdate::Clear,
+            ..Default::default()
+        };
+        self.update_fields(version_id, &update).await
+    }
+
+    pub async fn release_now(&self, version_id: &str) -> Result<Value, AppStoreConnectError> {
+        let version = self.get(version_id).await?;
+        let state = version
+            .get("attributes")
+            .and_then(|a| a.get("appStoreState"))
+            .and_then(|s| s.as_str())
+            .unwrap_or("UNKNOWN");
+
+        if state != "PENDING_DEVELOPER_RELEASE" {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                format!(
+                    "Version {} cannot be released from state {}; expected PENDING_DEVELOPER_RELEASE",
+                    version_id, state
+                ),
+            )));
+        }
+
+        let data = json!({
+            "data": {
+                "type": "appStoreVersionReleaseRequests",
+                "relationships": {
+                    "appStoreVersion": {
+                        "data": {
+                            "type": "appStoreVersions",
+                            "id": version_id
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = self
+            .base
+            .post("appStoreVersionReleaseRequests", data)
+            .await?;
+        response
+            .get("data")
+            .cloned()
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: "Invalid response format".to_string(),
+            })
+    }
+
+    pub async fn get_state(&self, version_id: &str) -> Result<String, AppStoreConnectError> {
+        let version = self.get(version_id).await?;
+
+        Ok(version
+            .get("attributes")
+            .and_then(|a| a.get("appStoreState"))
+            .and_then(|s| s.as_str())
+            .unwrap_or("UNKNOWN")
+            .to_string())
+    }
+
+    pub async fn wait_for_state(
+        &self,
+        version_id: &str,
+        target_states: &[&str],
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<Value, AppStoreConnectError> {
+        let options = PollOptions::new(timeout, poll_interval);
+        let description = format!(
+            "version {} to reach {}",
+            version_id,
+            target_states.join("/")
+        );
+
+        poll_until(&description, &options, || async {
+            let version = self.get(version_id).await?;
+            let state = version
+                .get("attributes")
+                .and_then(|a| a.get("appStoreState"))
+                .and_then(|s| s.as_str())
+                .unwrap_or_default();
+
+            Ok(target_states.contains(&state).then_some(version))
+        })
+        .await
+    }
+
+    pub async fn wait_for_state_change(
+        &self,
+        version_id: &str,
+        from_states: &[&str],
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<Value, AppStoreConnectError> {
+        let options = PollOptions::new(timeout, poll_interval);
+        let description = format!("version {} to leave {}", version_id, from_states.join("/"));
+
+        poll_until(&description, &options, || async {
+            let version = self.get(version_id).await?;
+            let state = version
+                .get("attributes")
+                .and_then(|a| a.get("appStoreState"))
+                .and_then(|s| s.as_str())
+                .unwrap_or_default();
+
+            Ok((!from_states.contains(&state)).then_some(version))
+        })
+        .await
+    }
+
     pub async fn get_build(&self, version_id: &str) -> Result<Option<Value>, AppStoreConnectError> {
         let response = self
             .base
@@ -221,4 +540,89 @@ impl VersionsAPI {
             )
             .await
     }
+
+    pub async fn find_latest_build(
+        &self,
+        app_id: &str,
+        version_string: &str,
+        platform: &str,
+        min_build_version: Option<&str>,
+    ) -> Result<Option<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[app]".to_string(), app_id.to_string());
+        params.insert("filter[processingState]".to_string(), "VALID".to_string());
+        params.insert("filter[expired]".to_string(), "false".to_string());
+        params.insert(
+            "filter[preReleaseVersion.version]".to_string(),
+            version_string.to_string(),
+        );
+        params.insert(
+            "filter[preReleaseVersion.platform]".to_string(),
+            platform.to_string(),
+        );
+        params.insert("sort".to_string(), "-uploadedDate".to_string());
+
+        let builds = self
+            .base
+            .get_all_pages("builds", Some(params), None)
+            .await?;
+        let min_build = min_build_version.and_then(parse_version);
+
+        Ok(builds
+            .into_iter()
+            .filter_map(|build| {
+                let number = build
+                    .get("attributes")
+                    .and_then(|a| a.get("version"))
+                    .and_then(|v| v.as_str())
+                    .and_then(parse_version)?;
+                Some((number, build))
+            })
+            .filter(|(number, _)| min_build.as_ref().is_none_or(|min| number >= min))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, build)| build))
+    }
+
+    pub async fn attach_latest_build(
+        &self,
+        version_id: &str,
+        app_id: &str,
+        min_version_string: Option<&str>,
+    ) -> Result<Value, AppStoreConnectError> {
+        let version = self.get(version_id).await?;
+        let attribute = |name: &str| {
+            version
+                .get("attributes")
+                .and_then(|a| a.get(name))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+
+        let version_string =
+            attribute("versionString").ok_or_else(|| AppStoreConnectError::Api {
+                message: "Version is missing a version string".to_string(),
+            })?;
+        let platform = attribute("platform").unwrap_or_else(|| "IOS".to_string());
+
+        let build = self
+            .find_latest_build(app_id, &version_string, &platform, min_version_string)
+            .await?
+            .ok_or_else(|| {
+                AppStoreConnectError::NotFound(NotFoundError::new(format!(
+                    "No processed build found for version {} ({})",
+                    version_string, platform
+                )))
+            })?;
+
+        let build_id =
+            build
+                .get("id")
+                .and_then(|i| i.as_str())
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: "Invalid build ID".to_string(),
+                })?;
+
+        self.set_build(version_id, build_id).await?;
+        Ok(build)
+    }
 }
@@ -2,19 +2,26 @@ use crate::auth::Auth;
 use crate::error::{
     AppStoreConnectError, ConflictError, NotFoundError, RateLimitError, ValidationError,
 };
+use crate::rate_limit::RateLimiter;
+use crate::upload::UploadOperation;
 use reqwest::{Method, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::path::Path;
+use tokio::io::AsyncWriteExt;
 use url::Url;
 
 const BASE_URL: &str = "https://api.appstoreconnect.apple.com/v1/";
+pub(crate) const V2_BASE_URL: &str = "https://api.appstoreconnect.apple.com/v2/";
+pub(crate) const V3_BASE_URL: &str = "https://api.appstoreconnect.apple.com/v3/";
 
 #[derive(Clone)]
 pub struct BaseAPI {
     auth: Auth,
     client: reqwest::Client,
     base_url: Url,
+    rate_limiter: RateLimiter,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -59,9 +66,14 @@ impl BaseAPI {
             auth,
             client,
             base_url,
+            rate_limiter: RateLimiter::unlimited(),
         })
     }
 
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
     pub async fn request(
         &self,
         method: Method,
@@ -74,6 +86,7 @@ impl BaseAPI {
             .join(endpoint)
             .map_err(|e| AppStoreConnectError::Unknown(format!("Invalid endpoint: {}", e)))?;
 
+        self.rate_limiter.acquire().await;
         let headers = self.auth.headers().await?;
         let mut request = self.client.request(method, url).headers(headers);
 
@@ -178,13 +191,147 @@ impl BaseAPI {
         self.request(Method::DELETE, endpoint, None, None).await
     }
 
+    pub async fn delete_with_data(
+        &self,
+        endpoint: &str,
+        data: Value,
+    ) -> Result<Value, AppStoreConnectError> {
+        self.request(Method::DELETE, endpoint, Some(data), None)
+            .await
+    }
+
+    pub async fn upload_part(
+        &self,
+        operation: &UploadOperation,
+        chunk: Vec<u8>,
+    ) -> Result<(), AppStoreConnectError> {
+        let method = Method::from_bytes(operation.method.as_bytes())
+            .map_err(|e| AppStoreConnectError::Unknown(format!("Invalid upload method: {}", e)))?;
+
+        let mut request = self.client.request(method, &operation.url);
+        for (name, value) in &operation.request_headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+
+        // Keep the status on the error so callers can tell a 5xx worth retrying
+        // from a rejected part.
+        request.body(chunk).send().await?.error_for_status()?;
+        Ok(())
+    }
+
+    pub async fn download(&self, url: &str) -> Result<Vec<u8>, AppStoreConnectError> {
+        let response = self.client.get(url).send().await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(AppStoreConnectError::Api {
+                message: format!("Download failed with status {}", status),
+            });
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    pub async fn get_raw(
+        &self,
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+        accept: &str,
+    ) -> Result<Vec<u8>, AppStoreConnectError> {
+        let response = self.send_download(endpoint, params, Some(accept)).await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    pub async fn download_url_to_file(
+        &self,
+        url: &str,
+        path: &Path,
+    ) -> Result<u64, AppStoreConnectError> {
+        let response = self.client.get(url).send().await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(AppStoreConnectError::Api {
+                message: format!("Download failed with status {}", status),
+            });
+        }
+
+        write_response_to_file(response, path).await
+    }
+
+    pub async fn download_to_file(
+        &self,
+        endpoint: &str,
+        path: &Path,
+    ) -> Result<u64, AppStoreConnectError> {
+        self.download_query_to_file(endpoint, None, None, path)
+            .await
+    }
+
+    pub async fn download_query_to_file(
+        &self,
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+        accept: Option<&str>,
+        path: &Path,
+    ) -> Result<u64, AppStoreConnectError> {
+        let response = self.send_download(endpoint, params, accept).await?;
+        write_response_to_file(response, path).await
+    }
+
+    async fn send_download(
+        &self,
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+        accept: Option<&str>,
+    ) -> Result<Response, AppStoreConnectError> {
+        let url = self
+            .base_url
+            .join(endpoint)
+            .map_err(|e| AppStoreConnectError::Unknown(format!("Invalid endpoint: {}", e)))?;
+
+        self.rate_limiter.acquire().await;
+        let headers = self.auth.headers().await?;
+        let mut request = self.client.get(url).headers(headers);
+
+        if let Some(accept) = accept {
+            request = request.header(reqwest::header::ACCEPT, accept);
+        }
+        if let Some(params) = params {
+            request = request.query(&params);
+        }
+
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            self.handle_response(response).await?;
+            return Err(AppStoreConnectError::Api {
+                message: "Download failed".to_string(),
+            });
+        }
+
+        Ok(response)
+    }
+
     pub async fn get_all_pages(
         &self,
         endpoint: &str,
         params: Option<HashMap<String, String>>,
         limit: Option<u32>,
     ) -> Result<Vec<Value>, AppStoreConnectError> {
+        let (data, _) = self
+            .get_all_pages_with_included(endpoint, params, limit)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn get_all_pages_with_included(
+        &self,
+        endpoint: &str,
+        params: Option<HashMap<String, String>>,
+        limit: Option<u32>,
+    ) -> Result<(Vec<Value>, Vec<Value>), AppStoreConnectError> {
         let mut all_results = Vec::new();
+        let mut all_included = Vec::new();
         let mut current_params = params.unwrap_or_default();
 
         if let Some(limit) = limit {
@@ -203,26 +350,52 @@ impl BaseAPI {
             if let Some(data) = response.get("data").and_then(|d| d.as_array()) {
                 all_results.extend(data.iter().cloned());
             }
+            if let Some(included) = response.get("included").and_then(|i| i.as_array()) {
+                all_included.extend(included.iter().cloned());
+            }
 
-            if let Some(links) = response.get("links").and_then(|l| l.as_object()) {
-                if let Some(next_url) = links.get("next").and_then(|n| n.as_str()) {
-                    if let Ok(url) = Url::parse(next_url) {
-                        current_endpoint = url.path().trim_start_matches("/v1/").to_string();
-                        current_params.clear();
-                        for (key, value) in url.query_pairs() {
-                            current_params.insert(key.to_string(), value.to_string());
-                        }
-                    } else {
-                        break;
-                    }
-                } else {
-                    break;
+            match next_page(&response) {
+                Some((endpoint, params)) => {
+                    current_endpoint = endpoint;
+                    current_params = params;
                 }
-            } else {
-                break;
+                None => break,
             }
         }
 
-        Ok(all_results)
+        Ok((all_results, all_included))
     }
 }
+
+async fn write_response_to_file(
+    mut response: Response,
+    path: &Path,
+) -> Result<u64, AppStoreConnectError> {
+    let mut file = tokio::fs::File::create(path).await?;
+    let mut written = 0u64;
+
+    while let Some(chunk) = response.chunk().await? {
+        file.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+    file.flush().await?;
+
+    Ok(written)
+}
+
+pub fn next_page(response: &Value) -> Option<(String, HashMap<String, String>)> {
+    let next_url = response.get("links")?.get("next")?.as_str()?;
+    let url = Url::parse(next_url).ok()?;
+    let params = url
+        .query_pairs()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+
+    Some((url.path().trim_start_matches("/v1/").to_string(), params))
+}
+
+pub fn linkages(resource_type: &str, ids: &[&str]) -> Vec<Value> {
+    ids.iter()
+        .map(|id| serde_json::json!({ "type": resource_type, "id": id }))
+        .collect()
+}
@@ -0,0 +1,89 @@
+use crate::error::AppStoreConnectError;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkAction {
+    Created,
+    Updated,
+    Failed(String),
+}
+
+impl fmt::Display for BulkAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BulkAction::Created => write!(f, "created"),
+            BulkAction::Updated => write!(f, "updated"),
+            BulkAction::Failed(error) => write!(f, "failed: {}", error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkResult {
+    pub locale: String,
+    pub action: BulkAction,
+    pub data: Option<Value>,
+}
+
+impl BulkResult {
+    pub fn from_result(
+        locale: impl Into<String>,
+        action: BulkAction,
+        result: Result<Value, AppStoreConnectError>,
+    ) -> Self {
+        match result {
+            Ok(data) => Self {
+                locale: locale.into(),
+                action,
+                data: Some(data),
+            },
+            Err(e) => Self::failed(locale, e),
+        }
+    }
+
+    pub fn failed(locale: impl Into<String>, error: impl ToString) -> Self {
+        Self {
+            locale: locale.into(),
+            action: BulkAction::Failed(error.to_string()),
+            data: None,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        !matches!(self.action, BulkAction::Failed(_))
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match &self.action {
+            BulkAction::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+pub fn failures(results: &[BulkResult]) -> Vec<&BulkResult> {
+    results.iter().filter(|r| !r.is_success()).collect()
+}
+
+pub(crate) fn export_by_locale(
+    localizations: Vec<Value>,
+    fields: &[&str],
+) -> HashMap<String, Value> {
+    localizations
+        .into_iter()
+        .filter_map(|localization| {
+            let attributes = localization.get("attributes")?;
+            let locale = attributes.get("locale")?.as_str()?.to_string();
+            let exported: Map<String, Value> = fields
+                .iter()
+                .filter_map(|field| {
+                    let value = attributes.get(*field)?;
+                    (!value.is_null()).then(|| (field.to_string(), value.clone()))
+                })
+                .collect();
+            Some((locale, Value::Object(exported)))
+        })
+        .collect()
+}
@@ -1,10 +1,33 @@
 use crate::api::{
-    apps::AppsAPI, categories::CategoriesAPI, localizations::LocalizationsAPI, media::MediaAPI,
-    versions::VersionsAPI,
+    age_ratings::AgeRatingsAPI, alternative_distribution::AlternativeDistributionAPI,
+    analytics_reports::AnalyticsReportsAPI, apps::AppsAPI, availability::AvailabilityAPI,
+    beta_app_localizations::BetaAppLocalizationsAPI,
+    beta_build_localizations::BetaBuildLocalizationsAPI, beta_feedback::BetaFeedbackAPI,
+    beta_license_agreements::BetaLicenseAgreementsAPI, beta_testers::BetaTestersAPI,
+    builds::BuildsAPI, bundle_ids::BundleIdsAPI, categories::CategoriesAPI,
+    certificates::CertificatesAPI, ci::CiAPI, devices::DevicesAPI,
+    encryption_declarations::EncryptionDeclarationsAPI, eulas::EulasAPI,
+    game_center::GameCenterAPI, in_app_purchase_localizations::InAppPurchaseLocalizationsAPI,
+    in_app_purchases::InAppPurchasesAPI, localizations::LocalizationsAPI, media::MediaAPI,
+    nominations::NominationsAPI, offer_codes::OfferCodesAPI, pricing::PricingAPI,
+    profiles::ProfilesAPI, promotional_offers::PromotionalOffersAPI,
+    review_attachments::ReviewAttachmentsAPI, review_submissions::ReviewSubmissionsAPI,
+    reviews::ReviewsAPI, sales_reports::SalesReportsAPI, sandbox::SandboxAPI,
+    subscription_groups::SubscriptionGroupsAPI, subscriptions::SubscriptionsAPI,
+    territories::TerritoriesAPI, testflight::TestFlightAPI, user_invitations::UserInvitationsAPI,
+    users::UsersAPI, version_localizations::VersionLocalizationsAPI, versions::VersionsAPI,
 };
 use crate::auth::Auth;
 use crate::base::BaseAPI;
+use crate::bulk::BulkResult;
 use crate::error::AppStoreConnectError;
+use crate::events::Events;
+use crate::lint::LintReport;
+use crate::listing::{ImportOptions, ImportReport, StoreListing};
+use crate::plan::{DesiredState, Plan};
+use crate::signing::SigningAudit;
+use crate::snapshot::{ApplyOptions, ApplyReport, Snapshot, EDITABLE_VERSION_STATES};
+use crate::watcher::{ReviewHandler, ReviewWatcher, Watcher};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::env;
@@ -17,6 +40,42 @@ pub struct Client {
     versions_api: VersionsAPI,
     media_api: MediaAPI,
     categories_api: CategoriesAPI,
+    availability_api: AvailabilityAPI,
+    eulas_api: EulasAPI,
+    nominations_api: NominationsAPI,
+    version_localizations_api: VersionLocalizationsAPI,
+    review_submissions_api: ReviewSubmissionsAPI,
+    review_attachments_api: ReviewAttachmentsAPI,
+    encryption_declarations_api: EncryptionDeclarationsAPI,
+    alternative_distribution_api: AlternativeDistributionAPI,
+    builds_api: BuildsAPI,
+    beta_testers_api: BetaTestersAPI,
+    beta_build_localizations_api: BetaBuildLocalizationsAPI,
+    beta_app_localizations_api: BetaAppLocalizationsAPI,
+    beta_license_agreements_api: BetaLicenseAgreementsAPI,
+    beta_feedback_api: BetaFeedbackAPI,
+    testflight_api: TestFlightAPI,
+    sandbox_api: SandboxAPI,
+    in_app_purchase_localizations_api: InAppPurchaseLocalizationsAPI,
+    in_app_purchases_api: InAppPurchasesAPI,
+    subscription_groups_api: SubscriptionGroupsAPI,
+    subscriptions_api: SubscriptionsAPI,
+    promotional_offers_api: PromotionalOffersAPI,
+    offer_codes_api: OfferCodesAPI,
+    pricing_api: PricingAPI,
+    territories_api: TerritoriesAPI,
+    reviews_api: ReviewsAPI,
+    analytics_reports_api: AnalyticsReportsAPI,
+    sales_reports_api: SalesReportsAPI,
+    users_api: UsersAPI,
+    user_invitations_api: UserInvitationsAPI,
+    bundle_ids_api: BundleIdsAPI,
+    certificates_api: CertificatesAPI,
+    devices_api: DevicesAPI,
+    profiles_api: ProfilesAPI,
+    game_center_api: GameCenterAPI,
+    ci_api: CiAPI,
+    age_ratings_api: AgeRatingsAPI,
 }
 
 impl Client {
@@ -34,6 +93,42 @@ impl Client {
             versions_api: VersionsAPI::new(base.clone()),
             media_api: MediaAPI::new(base.clone()),
             categories_api: CategoriesAPI::new(base.clone()),
+            availability_api: AvailabilityAPI::new(base.clone()),
+            eulas_api: EulasAPI::new(base.clone()),
+            nominations_api: NominationsAPI::new(base.clone()),
+            version_localizations_api: VersionLocalizationsAPI::new(base.clone()),
+            review_submissions_api: ReviewSubmissionsAPI::new(base.clone()),
+            review_attachments_api: ReviewAttachmentsAPI::new(base.clone()),
+            encryption_declarations_api: EncryptionDeclarationsAPI::new(base.clone()),
+            alternative_distribution_api: AlternativeDistributionAPI::new(base.clone()),
+            builds_api: BuildsAPI::new(base.clone()),
+            beta_testers_api: BetaTestersAPI::new(base.clone()),
+            beta_build_localizations_api: BetaBuildLocalizationsAPI::new(base.clone()),
+            beta_app_localizations_api: BetaAppLocalizationsAPI::new(base.clone()),
+            beta_license_agreements_api: BetaLicenseAgreementsAPI::new(base.clone()),
+            beta_feedback_api: BetaFeedbackAPI::new(base.clone()),
+            testflight_api: TestFlightAPI::new(base.clone()),
+            sandbox_api: SandboxAPI::new(base.clone()),
+            in_app_purchase_localizations_api: InAppPurchaseLocalizationsAPI::new(base.clone()),
+            in_app_purchases_api: InAppPurchasesAPI::new(base.clone()),
+            subscription_groups_api: SubscriptionGroupsAPI::new(base.clone()),
+            subscriptions_api: SubscriptionsAPI::new(base.clone()),
+            promotional_offers_api: PromotionalOffersAPI::new(base.clone()),
+            offer_codes_api: OfferCodesAPI::new(base.clone()),
+            pricing_api: PricingAPI::new(base.clone()),
+            territories_api: TerritoriesAPI::new(base.clone()),
+            reviews_api: ReviewsAPI::new(base.clone()),
+            analytics_reports_api: AnalyticsReportsAPI::new(base.clone()),
+            sales_reports_api: SalesReportsAPI::new(base.clone()),
+            users_api: UsersAPI::new(base.clone()),
+            user_invitations_api: UserInvitationsAPI::new(base.clone()),
+            bundle_ids_api: BundleIdsAPI::new(base.clone()),
+            certificates_api: CertificatesAPI::new(base.clone()),
+            devices_api: DevicesAPI::new(base.clone()),
+            profiles_api: ProfilesAPI::new(base.clone()),
+            game_center_api: GameCenterAPI::new(base.clone()),
+            ci_api: CiAPI::new(base.clone()),
+            age_ratings_api: AgeRatingsAPI::new(base.clone()),
             base,
         })
     }
@@ -50,6 +145,13 @@ impl Client {
         Self::new(key_id, issuer_id, private_key_path).await
     }
 
+    // Throttling is off unless requested; all API handles of this client share
+    // the bucket, so concurrent uploads and polling draw from one budget.
+    pub fn with_rate_limit(self, requests_per_hour: u32, burst: u32) -> Self {
+        self.base.rate_limiter().configure(requests_per_hour, burst);
+        self
+    }
+
     pub fn apps(&self) -> &AppsAPI {
         &self.apps_api
     }
@@ -70,6 +172,154 @@ impl Client {
         &self.categories_api
     }
 
+    pub fn availability(&self) -> &AvailabilityAPI {
+        &self.availability_api
+    }
+
+    pub fn eulas(&self) -> &EulasAPI {
+        &self.eulas_api
+    }
+
+    pub fn nominations(&self) -> &NominationsAPI {
+        &self.nominations_api
+    }
+
+    pub fn version_localizations(&self) -> &VersionLocalizationsAPI {
+        &self.version_localizations_api
+    }
+
+    pub fn review_submissions(&self) -> &ReviewSubmissionsAPI {
+        &self.review_submissions_api
+    }
+
+    pub fn review_attachments(&self) -> &ReviewAttachmentsAPI {
+        &self.review_attachments_api
+    }
+
+    pub fn encryption_declarations(&self) -> &EncryptionDeclarationsAPI {
+        &self.encryption_declarations_api
+    }
+
+    pub fn alternative_distribution(&self) -> &AlternativeDistributionAPI {
+        &self.alternative_distribution_api
+    }
+
+    pub fn builds(&self) -> &BuildsAPI {
+        &self.builds_api
+    }
+
+    pub fn beta_testers(&self) -> &BetaTestersAPI {
+        &self.beta_testers_api
+    }
+
+    pub fn beta_build_localizations(&self) -> &BetaBuildLocalizationsAPI {
+        &self.beta_build_localizations_api
+    }
+
+    pub fn beta_app_localizations(&self) -> &BetaAppLocalizationsAPI {
+        &self.beta_app_localizations_api
+    }
+
+    pub fn beta_license_agreements(&self) -> &BetaLicenseAgreementsAPI {
+        &self.beta_license_agreements_api
+    }
+
+    pub fn beta_feedback(&self) -> &BetaFeedbackAPI {
+        &self.beta_feedback_api
+    }
+
+    pub fn testflight(&self) -> &TestFlightAPI {
+        &self.testflight_api
+    }
+
+    pub fn sandbox(&self) -> &SandboxAPI {
+        &self.sandbox_api
+    }
+
+    pub fn in_app_purchase_localizations(&self) -> &InAppPurchaseLocalizationsAPI {
+        &self.in_app_purchase_localizations_api
+    }
+
+    pub fn in_app_purchases(&self) -> &InAppPurchasesAPI {
+        &self.in_app_purchases_api
+    }
+
+    pub fn subscription_groups(&self) -> &SubscriptionGroupsAPI {
+        &self.subscription_groups_api
+    }
+
+    pub fn subscriptions(&self) -> &SubscriptionsAPI {
+        &self.subscriptions_api
+    }
+
+    pub fn promotional_offers(&self) -> &PromotionalOffersAPI {
+        &self.promotional_offers_api
+    }
+
+    pub fn offer_codes(&self) -> &OfferCodesAPI {
+        &self.offer_codes_api
+    }
+
+    pub fn pricing(&self) -> &PricingAPI {
+        &self.pricing_api
+    }
+
+    pub fn territories(&self) -> &TerritoriesAPI {
+        &self.territories_api
+    }
+
+    pub fn reviews(&self) -> &ReviewsAPI {
+        &self.reviews_api
+    }
+
+    pub fn analytics_reports(&self) -> &AnalyticsReportsAPI {
+        &self.analytics_reports_api
+    }
+
+    pub fn sales_reports(&self) -> &SalesReportsAPI {
+        &self.sales_reports_api
+    }
+
+    pub fn users(&self) -> &UsersAPI {
+        &self.users_api
+    }
+
+    pub fn user_invitations(&self) -> &UserInvitationsAPI {
+        &self.user_invitations_api
+    }
+
+    pub fn bundle_ids(&self) -> &BundleIdsAPI {
+        &self.bundle_ids_api
+    }
+
+    pub fn certificates(&self) -> &CertificatesAPI {
+        &self.certificates_api
+    }
+
+    pub fn devices(&self) -> &DevicesAPI {
+        &self.devices_api
+    }
+
+    pub fn profiles(&self) -> &ProfilesAPI {
+        &self.profiles_api
+    }
+
+    pub fn game_center(&self) -> &GameCenterAPI {
+        &self.game_center_api
+    }
+
+    pub fn ci(&self) -> &CiAPI {
+        &self.ci_api
+    }
+
+    pub fn age_ratings(&self) -> &AgeRatingsAPI {
+        &self.age_ratings_api
+    }
+
+    pub fn base(&self) -> &BaseAPI {
+        &self.base
+    }
+
     pub async fn get_app_by_bundle_id(
         &self,
         bundle_id: &str,
@@ -81,7 +331,7 @@ impl Client {
         &self,
         app_id: &str,
         localizations: HashMap<String, Value>,
-    ) -> Result<HashMap<String, Value>, AppStoreConnectError> {
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
         let app_infos = self.apps().get_app_infos(app_id).await?;
 
         if app_infos.is_empty() {
@@ -102,6 +352,47 @@ impl Client {
             .await
     }
 
+    pub async fn update_version_localizations(
+        &self,
+        app_id: &str,
+        localizations: HashMap<String, Value>,
+    ) -> Result<Vec<BulkResult>, AppStoreConnectError> {
+        let version_id = self.editable_version_id(app_id).await?;
+
+        self.version_localizations()
+            .bulk_update(&version_id, localizations)
+            .await
+    }
+
+    pub async fn export_version_localizations(
+        &self,
+        app_id: &str,
+    ) -> Result<HashMap<String, Value>, AppStoreConnectError> {
+        let version_id = self.editable_version_id(app_id).await?;
+
+        self.version_localizations().bulk_export(&version_id).await
+    }
+
+    async fn editable_version_id(&self, app_id: &str) -> Result<String, AppStoreConnectError> {
+        let versions = self.versions().get_all(app_id).await?;
+
+        versions
+            .iter()
+            .find(|version| {
+                version
+                    .get("attributes")
+                    .and_then(|a| a.get("appStoreState"))
+                    .and_then(|s| s.as_str())
+                    .is_some_and(|state| EDITABLE_VERSION_STATES.contains(&state))
+            })
+            .and_then(|version| version.get("id"))
+            .and_then(|id| id.as_str())
+            .map(|id| id.to_string())
+            .ok_or_else(|| AppStoreConnectError::Api {
+                message: format!("No editable version found for app {}", app_id),
+            })
+    }
+
     pub async fn get_current_version(
         &self,
         app_id: &str,
@@ -123,6 +414,113 @@ impl Client {
     }
 
     pub async fn submit_for_review(&self, version_id: &str) -> Result<Value, AppStoreConnectError> {
-        self.versions().submit_for_review(version_id).await
+        self.review_submissions().submit_version(version_id).await
+    }
+
+    pub async fn export_snapshot(&self, app_id: &str) -> Result<Snapshot, AppStoreConnectError> {
+        Snapshot::capture(self, app_id).await
+    }
+
+    pub async fn export_snapshot_to(
+        &self,
+        app_id: &str,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Snapshot, AppStoreConnectError> {
+        let snapshot = self.export_snapshot(app_id).await?;
+        snapshot.save(path).await?;
+        Ok(snapshot)
+    }
+
+    pub async fn export_localizations_yaml(
+        &self,
+        app_id: &str,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<StoreListing, AppStoreConnectError> {
+        let listing = StoreListing::capture(self, app_id).await?;
+        listing.save(path).await?;
+        Ok(listing)
+    }
+
+    pub async fn import_localizations_yaml(
+        &self,
+        app_id: &str,
+        path: impl AsRef<std::path::Path>,
+        options: ImportOptions,
+    ) -> Result<ImportReport, AppStoreConnectError> {
+        let listing = StoreListing::load(path).await?;
+        listing.import(self, app_id, &options).await
+    }
+
+    pub async fn export_localizations_csv(
+        &self,
+        app_id: &str,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<StoreListing, AppStoreConnectError> {
+        let listing = StoreListing::capture(self, app_id).await?;
+        listing.save_csv(path).await?;
+        Ok(listing)
+    }
+
+    pub async fn import_localizations_csv(
+        &self,
+        app_id: &str,
+        path: impl AsRef<std::path::Path>,
+        options: ImportOptions,
+    ) -> Result<ImportReport, AppStoreConnectError> {
+        let listing = StoreListing::load_csv(app_id, path).await?;
+        listing.import(self, app_id, &options).await
+    }
+
+    pub async fn apply_snapshot(
+        &self,
+        app_id: &str,
+        snapshot: &Snapshot,
+        options: ApplyOptions,
+    ) -> Result<ApplyReport, AppStoreConnectError> {
+        snapshot.apply(self, app_id, &options).await
+    }
+
+    pub async fn plan(
+        &self,
+        app_id: &str,
+        desired: &DesiredState,
+    ) -> Result<Plan, AppStoreConnectError> {
+        Plan::compute(self, app_id, desired).await
+    }
+
+    pub async fn apply_plan(&self, plan: &Plan) -> Result<ApplyReport, AppStoreConnectError> {
+        plan.apply(self).await
+    }
+
+    pub fn watcher(&self, interval: std::time::Duration) -> Watcher {
+        Watcher::new(self.clone(), interval)
+    }
+
+    pub fn events(&self, interval: std::time::Duration) -> Events {
+        Events::new(self.clone(), interval)
+    }
+
+    pub fn review_watcher(&self, app_id: &str, interval: std::time::Duration) -> ReviewWatcher {
+        ReviewWatcher::new(self.clone(), app_id, interval)
+    }
+
+    pub async fn watch_reviews<H: ReviewHandler>(
+        &self,
+        app_id: &str,
+        interval: std::time::Duration,
+        handler: H,
+    ) {
+        self.review_watcher(app_id, interval).run(handler).await
+    }
+
+    pub async fn lint(&self, app_id: &str) -> Result<LintReport, AppStoreConnectError> {
+        LintReport::run(self, app_id).await
+    }
+
+    pub async fn signing_audit(
+        &self,
+        within_days: i64,
+    ) -> Result<SigningAudit, AppStoreConnectError> {
+        SigningAudit::run(self, within_days).await
     }
 }
@@ -0,0 +1,66 @@
+use crate::error::{AppStoreConnectError, ValidationError};
+use std::fmt::Write;
+
+pub(crate) fn push_csv_row(csv: &mut String, cells: &[&str]) {
+    for (i, cell) in cells.iter().enumerate() {
+        if i > 0 {
+            csv.push(',');
+        }
+        if cell.contains([',', '"', '\n', '\r']) {
+            let _ = write!(csv, "\"{}\"", cell.replace('"', "\"\""));
+        } else {
+            csv.push_str(cell);
+        }
+    }
+    csv.push('\n');
+}
+
+pub(crate) fn parse_csv(content: &str) -> Result<Vec<(usize, Vec<String>)>, AppStoreConnectError> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_start = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    cell.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if cell.is_empty() => in_quotes = true,
+            '\n' if in_quotes => {
+                line += 1;
+                cell.push(c);
+            }
+            ',' if !in_quotes => row.push(std::mem::take(&mut cell)),
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' if !in_quotes => {
+                row.push(std::mem::take(&mut cell));
+                rows.push((row_start, std::mem::take(&mut row)));
+                line += 1;
+                row_start = line;
+            }
+            _ => cell.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(AppStoreConnectError::Validation(ValidationError::new(
+            format!("line {}: unterminated quoted value", row_start),
+        )));
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push((row_start, row));
+    }
+
+    Ok(rows)
+}
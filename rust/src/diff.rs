@@ -0,0 +1,324 @@
+use crate::snapshot::{FieldMap, Snapshot};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DiffScope {
+    App,
+    AppInfoLocalization {
+        locale: String,
+    },
+    Categories,
+    Version {
+        platform: String,
+        version_string: String,
+    },
+    VersionLocalization {
+        platform: String,
+        version_string: String,
+        locale: String,
+    },
+    InAppPurchaseLocalization {
+        iap_id: String,
+        locale: String,
+    },
+    Availability,
+    SubscriptionAvailability {
+        subscription_id: String,
+    },
+    Territory {
+        territory: String,
+    },
+    Pricing,
+}
+
+impl fmt::Display for DiffScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffScope::App => write!(f, "app"),
+            DiffScope::AppInfoLocalization { locale } => write!(f, "app info [{}]", locale),
+            DiffScope::Categories => write!(f, "categories"),
+            DiffScope::Version {
+                platform,
+                version_string,
+            } => write!(f, "version {} ({})", version_string, platform),
+            DiffScope::VersionLocalization {
+                platform,
+                version_string,
+                locale,
+            } => write!(f, "version {} ({}) [{}]", version_string, platform, locale),
+            DiffScope::InAppPurchaseLocalization { iap_id, locale } => {
+                write!(f, "in-app purchase {} [{}]", iap_id, locale)
+            }
+            DiffScope::Availability => write!(f, "availability"),
+            DiffScope::SubscriptionAvailability { subscription_id } => {
+                write!(f, "subscription {} availability", subscription_id)
+            }
+            DiffScope::Territory { territory } => write!(f, "territory {}", territory),
+            DiffScope::Pricing => write!(f, "pricing"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub scope: DiffScope,
+    pub field: String,
+    pub kind: ChangeKind,
+    pub old: Option<Value>,
+    pub new: Option<Value>,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let render = |value: &Option<Value>| match value {
+            Some(Value::String(s)) => format!("{:?}", s),
+            Some(other) => other.to_string(),
+            None => "<none>".to_string(),
+        };
+
+        match self.kind {
+            ChangeKind::Added => {
+                write!(f, "+ {} {}: {}", self.scope, self.field, render(&self.new))
+            }
+            ChangeKind::Removed => {
+                write!(f, "- {} {}: {}", self.scope, self.field, render(&self.old))
+            }
+            ChangeKind::Modified => write!(
+                f,
+                "~ {} {}: {} -> {}",
+                self.scope,
+                self.field,
+                render(&self.old),
+                render(&self.new)
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    pub changes: Vec<FieldChange>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn for_locale(&self, locale: &str) -> Vec<&FieldChange> {
+        self.changes
+            .iter()
+            .filter(|c| match &c.scope {
+                DiffScope::AppInfoLocalization { locale: l }
+                | DiffScope::VersionLocalization { locale: l, .. }
+                | DiffScope::InAppPurchaseLocalization { locale: l, .. } => l == locale,
+                _ => false,
+            })
+            .collect()
+    }
+
+    fn push(
+        &mut self,
+        scope: DiffScope,
+        field: impl Into<String>,
+        old: Option<&Value>,
+        new: Option<&Value>,
+    ) {
+        let kind = match (old, new) {
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(_), None) => ChangeKind::Removed,
+            _ => ChangeKind::Modified,
+        };
+
+        self.changes.push(FieldChange {
+            scope,
+            field: field.into(),
+            kind,
+            old: old.cloned(),
+            new: new.cloned(),
+        });
+    }
+
+    fn diff_fields(&mut self, scope: DiffScope, old: &FieldMap, new: &FieldMap) {
+        let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+
+        for key in keys {
+            let old_value = old.get(key).filter(|v| !v.is_null());
+            let new_value = new.get(key).filter(|v| !v.is_null());
+
+            if old_value != new_value {
+                self.push(scope.clone(), key.as_str(), old_value, new_value);
+            }
+        }
+    }
+
+    pub(crate) fn diff_locales(
+        &mut self,
+        old: &BTreeMap<String, FieldMap>,
+        new: &BTreeMap<String, FieldMap>,
+        scope: impl Fn(&str) -> DiffScope,
+    ) {
+        let empty = FieldMap::new();
+        let locales: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+
+        for locale in locales {
+            let old_fields = old.get(locale).unwrap_or(&empty);
+            let new_fields = new.get(locale).unwrap_or(&empty);
+            self.diff_fields(scope(locale), old_fields, new_fields);
+        }
+    }
+}
+
+impl fmt::Display for SnapshotDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes");
+        }
+
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+impl Snapshot {
+    pub fn diff(&self, other: &Snapshot) -> SnapshotDiff {
+        let mut diff = SnapshotDiff::default();
+
+        diff.diff_fields(DiffScope::App, &self.app, &other.app);
+
+        let empty_info = BTreeMap::new();
+        let old_info = self.editable_app_info();
+        let new_info = other.editable_app_info();
+
+        diff.diff_locales(
+            old_info.map(|i| &i.localizations).unwrap_or(&empty_info),
+            new_info.map(|i| &i.localizations).unwrap_or(&empty_info),
+            |locale| DiffScope::AppInfoLocalization {
+                locale: locale.to_string(),
+            },
+        );
+
+        let old_categories = old_info.map(|i| category_fields(&i.categories));
+        let new_categories = new_info.map(|i| category_fields(&i.categories));
+        diff.diff_fields(
+            DiffScope::Categories,
+            &old_categories.unwrap_or_default(),
+            &new_categories.unwrap_or_default(),
+        );
+
+        let version_keys: BTreeSet<(&String, &String)> = self
+            .versions
+            .iter()
+            .chain(other.versions.iter())
+            .map(|v| (&v.platform, &v.version_string))
+            .collect();
+
+        for (platform, version_string) in version_keys {
+            let old_version = self.version(version_string, platform);
+            let new_version = other.version(version_string, platform);
+            let empty_fields = FieldMap::new();
+
+            diff.diff_fields(
+                DiffScope::Version {
+                    platform: platform.clone(),
+                    version_string: version_string.clone(),
+                },
+                old_version.map(|v| &v.attributes).unwrap_or(&empty_fields),
+                new_version.map(|v| &v.attributes).unwrap_or(&empty_fields),
+            );
+
+            diff.diff_locales(
+                old_version.map(|v| &v.localizations).unwrap_or(&empty_info),
+                new_version.map(|v| &v.localizations).unwrap_or(&empty_info),
+                |locale| DiffScope::VersionLocalization {
+                    platform: platform.clone(),
+                    version_string: version_string.clone(),
+                    locale: locale.to_string(),
+                },
+            );
+        }
+
+        if self.availability.available_in_new_territories
+            != other.availability.available_in_new_territories
+        {
+            diff.push(
+                DiffScope::Availability,
+                "availableInNewTerritories",
+                Some(&Value::Bool(self.availability.available_in_new_territories)),
+                Some(&Value::Bool(
+                    other.availability.available_in_new_territories,
+                )),
+            );
+        }
+
+        let old_territories: BTreeSet<&String> = self.availability.territories.iter().collect();
+        let new_territories: BTreeSet<&String> = other.availability.territories.iter().collect();
+        let available = Value::Bool(true);
+
+        for territory in old_territories.difference(&new_territories) {
+            diff.push(
+                DiffScope::Territory {
+                    territory: territory.to_string(),
+                },
+                "available",
+                Some(&available),
+                None,
+            );
+        }
+        for territory in new_territories.difference(&old_territories) {
+            diff.push(
+                DiffScope::Territory {
+                    territory: territory.to_string(),
+                },
+                "available",
+                None,
+                Some(&available),
+            );
+        }
+
+        if self.pricing != other.pricing {
+            let old = self
+                .pricing
+                .as_ref()
+                .and_then(|p| serde_json::to_value(p).ok());
+            let new = other
+                .pricing
+                .as_ref()
+                .and_then(|p| serde_json::to_value(p).ok());
+            diff.push(DiffScope::Pricing, "schedule", old.as_ref(), new.as_ref());
+        }
+
+        diff
+    }
+}
+
+pub(crate) fn category_fields(categories: &Value) -> FieldMap {
+    let mut fields = FieldMap::new();
+
+    if let Some(obj) = categories.as_object() {
+        for (key, value) in obj {
+            let value = value.get("id").unwrap_or(value);
+            if !value.is_null() {
+                fields.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    fields
+}
@@ -17,12 +17,21 @@ pub enum AppStoreConnectError {
     #[error("Conflict occurred: {0}")]
     Conflict(#[from] ConflictError),
 
+    #[error("Timed out: {0}")]
+    Timeout(#[from] TimeoutError),
+
+    #[error("Invalid binary: {0}")]
+    InvalidBinary(#[from] InvalidBinaryError),
+
     #[error("HTTP request failed: {0}")]
     Http(#[from] reqwest::Error),
 
     #[error("JSON parsing failed: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("YAML parsing failed: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
     #[error("Environment variable error: {0}")]
     Env(#[from] std::env::VarError),
 
@@ -35,6 +44,20 @@ pub enum AppStoreConnectError {
     #[error("API error: {message}")]
     Api { message: String },
 
+    #[error(
+        "{source} (rollback also failed: {}){}",
+        .rollback_errors.join("; "),
+        .snapshot_dir
+            .as_ref()
+            .map(|dir| format!("; removed screenshots kept in {}", dir.display()))
+            .unwrap_or_default()
+    )]
+    RollbackFailed {
+        source: Box<AppStoreConnectError>,
+        rollback_errors: Vec<String>,
+        snapshot_dir: Option<std::path::PathBuf>,
+    },
+
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
@@ -85,12 +108,21 @@ impl NotFoundError {
 #[error("Validation failed: {message}")]
 pub struct ValidationError {
     pub message: String,
+    pub issues: Vec<String>,
 }
 
 impl ValidationError {
     pub fn new(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
+            issues: Vec::new(),
+        }
+    }
+
+    pub fn with_issues(message: impl Into<String>, issues: Vec<String>) -> Self {
+        Self {
+            message: format!("{}: {}", message.into(), issues.join("; ")),
+            issues,
         }
     }
 }
@@ -108,3 +140,39 @@ impl ConflictError {
         }
     }
 }
+
+#[derive(Error, Debug)]
+#[error("Timed out: {message}")]
+pub struct TimeoutError {
+    pub message: String,
+}
+
+impl TimeoutError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Build {version} ({build_number}) finished processing as {processing_state}")]
+pub struct InvalidBinaryError {
+    pub version: String,
+    pub build_number: String,
+    pub processing_state: String,
+}
+
+impl InvalidBinaryError {
+    pub fn new(
+        version: impl Into<String>,
+        build_number: impl Into<String>,
+        processing_state: impl Into<String>,
+    ) -> Self {
+        Self {
+            version: version.into(),
+            build_number: build_number.into(),
+            processing_state: processing_state.into(),
+        }
+    }
+}
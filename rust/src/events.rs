@@ -0,0 +1,370 @@
+use crate::api::reviews::CustomerReview;
+use crate::client::Client;
+use crate::error::AppStoreConnectError;
+use crate::polling::{rate_limit_delay, wait_for_tick};
+use crate::watcher::{poll_reviews, MemoryStateStore, ReviewEvent, StateStore};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+const RECENT_BUILDS: u32 = 50;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Subscription {
+    VersionStates { app_id: String },
+    BuildProcessing { app_id: String },
+    ReviewSubmissions { app_id: String },
+    CustomerReviews { app_id: String },
+}
+
+impl Subscription {
+    pub fn version_states(app_id: impl Into<String>) -> Self {
+        Subscription::VersionStates {
+            app_id: app_id.into(),
+        }
+    }
+
+    pub fn build_processing(app_id: impl Into<String>) -> Self {
+        Subscription::BuildProcessing {
+            app_id: app_id.into(),
+        }
+    }
+
+    pub fn review_submissions(app_id: impl Into<String>) -> Self {
+        Subscription::ReviewSubmissions {
+            app_id: app_id.into(),
+        }
+    }
+
+    pub fn customer_reviews(app_id: impl Into<String>) -> Self {
+        Subscription::CustomerReviews {
+            app_id: app_id.into(),
+        }
+    }
+
+    pub fn app_id(&self) -> &str {
+        match self {
+            Subscription::VersionStates { app_id }
+            | Subscription::BuildProcessing { app_id }
+            | Subscription::ReviewSubmissions { app_id }
+            | Subscription::CustomerReviews { app_id } => app_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AppEvent {
+    VersionStateChanged {
+        app_id: String,
+        version_id: String,
+        version_string: Option<String>,
+        from: Option<String>,
+        to: String,
+    },
+    BuildProcessingChanged {
+        app_id: String,
+        build_id: String,
+        build_version: Option<String>,
+        from: Option<String>,
+        to: String,
+    },
+    ReviewSubmissionChanged {
+        app_id: String,
+        submission_id: String,
+        from: Option<String>,
+        to: String,
+    },
+    NewReview {
+        app_id: String,
+        review: CustomerReview,
+    },
+    ReviewUpdated {
+        app_id: String,
+        review: CustomerReview,
+    },
+    Error {
+        subscription: Subscription,
+        message: String,
+    },
+}
+
+impl AppEvent {
+    pub fn new_state(&self) -> Option<&str> {
+        match self {
+            AppEvent::VersionStateChanged { to, .. }
+            | AppEvent::BuildProcessingChanged { to, .. }
+            | AppEvent::ReviewSubmissionChanged { to, .. } => Some(to.as_str()),
+            _ => None,
+        }
+    }
+
+    // The first time a resource is seen there is no previous state to compare
+    // against; handlers usually want to ignore these on a fresh store.
+    pub fn is_initial(&self) -> bool {
+        matches!(
+            self,
+            AppEvent::VersionStateChanged { from: None, .. }
+                | AppEvent::BuildProcessingChanged { from: None, .. }
+                | AppEvent::ReviewSubmissionChanged { from: None, .. }
+        )
+    }
+}
+
+#[async_trait]
+pub trait AppEventHandler: Send + Sync {
+    async fn handle(&self, event: &AppEvent);
+}
+
+#[derive(Clone)]
+pub struct Events {
+    client: Client,
+    interval: Duration,
+    review_lookback: chrono::Duration,
+    subscriptions: Vec<Subscription>,
+    store: Arc<dyn StateStore>,
+}
+
+impl Events {
+    pub fn new(client: Client, interval: Duration) -> Self {
+        Self {
+            client,
+            interval,
+            review_lookback: chrono::Duration::days(7),
+            subscriptions: Vec::new(),
+            store: Arc::new(MemoryStateStore::new()),
+        }
+    }
+
+    pub fn with_store(mut self, store: impl StateStore + 'static) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    pub fn with_review_lookback(mut self, lookback: chrono::Duration) -> Self {
+        self.review_lookback = lookback;
+        self
+    }
+
+    pub fn subscribe(mut self, subscription: Subscription) -> Self {
+        if !self.subscriptions.contains(&subscription) {
+            self.subscriptions.push(subscription);
+        }
+        self
+    }
+
+    pub fn subscriptions(&self) -> &[Subscription] {
+        &self.subscriptions
+    }
+
+    pub async fn poll_once(&self) -> Vec<AppEvent> {
+        self.poll_round().await.0
+    }
+
+    // A rate limit ends the round early and is reported as an `Error` event,
+    // so handlers can tell that polling has stalled.
+    async fn poll_round(&self) -> (Vec<AppEvent>, bool) {
+        let mut events = Vec::new();
+
+        for subscription in &self.subscriptions {
+            match self.poll_subscription(subscription).await {
+                Ok(new_events) => events.extend(new_events),
+                Err(e @ AppStoreConnectError::RateLimit(_)) => {
+                    events.push(AppEvent::Error {
+                        subscription: subscription.clone(),
+                        message: format!("Polling paused: {}", e),
+                    });
+                    return (events, true);
+                }
+                Err(e) => events.push(AppEvent::Error {
+                    subscription: subscription.clone(),
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        (events, false)
+    }
+
+    pub async fn run<H: AppEventHandler>(self, handler: H) {
+        let mut ticker = tokio::time::interval(self.interval);
+        let mut backoff = None;
+
+        loop {
+            wait_for_tick(&mut ticker, backoff).await;
+            let (events, rate_limited) = self.poll_round().await;
+            backoff = rate_limited.then(|| rate_limit_delay(self.interval, backoff));
+
+            for event in events {
+                handler.handle(&event).await;
+            }
+        }
+    }
+
+    pub fn spawn(self) -> (JoinHandle<()>, mpsc::UnboundedReceiver<AppEvent>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            let mut backoff = None;
+
+            loop {
+                wait_for_tick(&mut ticker, backoff).await;
+                let (events, rate_limited) = self.poll_round().await;
+                backoff = rate_limited.then(|| rate_limit_delay(self.interval, backoff));
+
+                for event in events {
+                    if sender.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        (handle, receiver)
+    }
+
+    async fn poll_subscription(
+        &self,
+        subscription: &Subscription,
+    ) -> Result<Vec<AppEvent>, AppStoreConnectError> {
+        let app_id = subscription.app_id().to_string();
+        let mut events = Vec::new();
+
+        match subscription {
+            Subscription::VersionStates { .. } => {
+                for version in self.client.versions().get_all(&app_id).await? {
+                    let Some((version_id, state)) = identity(&version, "appStoreState") else {
+                        continue;
+                    };
+                    let key = format!("version:{}", version_id);
+                    if let Some(from) = state_transition(self.store.as_ref(), &key, &state).await? {
+                        events.push(AppEvent::VersionStateChanged {
+                            app_id: app_id.clone(),
+                            version_string: attribute(&version, "versionString"),
+                            version_id,
+                            from,
+                            to: state,
+                        });
+                    }
+                }
+            }
+            Subscription::BuildProcessing { .. } => {
+                for build in self.recent_builds(&app_id).await? {
+                    let Some((build_id, state)) = identity(&build, "processingState") else {
+                        continue;
+                    };
+                    let key = format!("build:{}", build_id);
+                    if let Some(from) = state_transition(self.store.as_ref(), &key, &state).await? {
+                        events.push(AppEvent::BuildProcessingChanged {
+                            app_id: app_id.clone(),
+                            build_version: attribute(&build, "version"),
+                            build_id,
+                            from,
+                            to: state,
+                        });
+                    }
+                }
+            }
+            Subscription::ReviewSubmissions { .. } => {
+                let submissions = self
+                    .client
+                    .review_submissions()
+                    .get_all(&app_id, None, None)
+                    .await?;
+                for submission in submissions {
+                    let Some((submission_id, state)) = identity(&submission, "state") else {
+                        continue;
+                    };
+                    let key = format!("submission:{}", submission_id);
+                    if let Some(from) = state_transition(self.store.as_ref(), &key, &state).await? {
+                        events.push(AppEvent::ReviewSubmissionChanged {
+                            app_id: app_id.clone(),
+                            submission_id,
+                            from,
+                            to: state,
+                        });
+                    }
+                }
+            }
+            Subscription::CustomerReviews { .. } => {
+                let reviews = poll_reviews(
+                    &self.client,
+                    self.store.as_ref(),
+                    &app_id,
+                    self.review_lookback,
+                )
+                .await?;
+                for event in reviews {
+                    events.push(match event {
+                        ReviewEvent::New(review) => AppEvent::NewReview {
+                            app_id: app_id.clone(),
+                            review,
+                        },
+                        ReviewEvent::Updated(review) => AppEvent::ReviewUpdated {
+                            app_id: app_id.clone(),
+                            review,
+                        },
+                        ReviewEvent::Error(message) => AppEvent::Error {
+                            subscription: subscription.clone(),
+                            message,
+                        },
+                    });
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    // Only the newest builds can still be processing, so one page is enough
+    // and avoids walking an app's entire build history every tick.
+    async fn recent_builds(&self, app_id: &str) -> Result<Vec<Value>, AppStoreConnectError> {
+        let mut params = HashMap::new();
+        params.insert("filter[app]".to_string(), app_id.to_string());
+        params.insert("sort".to_string(), "-uploadedDate".to_string());
+        params.insert("limit".to_string(), RECENT_BUILDS.to_string());
+
+        let response = self.client.base().get("builds", Some(params)).await?;
+        Ok(response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+// Returns the previous state when `state` differs from what was last stored,
+// recording the new one so a restart doesn't emit the same change again.
+pub(crate) async fn state_transition(
+    store: &dyn StateStore,
+    key: &str,
+    state: &str,
+) -> Result<Option<Option<String>>, AppStoreConnectError> {
+    let previous = store.get(key).await?;
+    if previous.as_deref() == Some(state) {
+        return Ok(None);
+    }
+
+    store.set(key, state).await?;
+    Ok(Some(previous))
+}
+
+fn identity(resource: &Value, state_attribute: &str) -> Option<(String, String)> {
+    Some((
+        resource.get("id")?.as_str()?.to_string(),
+        attribute(resource, state_attribute)?,
+    ))
+}
+
+fn attribute(resource: &Value, key: &str) -> Option<String> {
+    resource
+        .get("attributes")
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
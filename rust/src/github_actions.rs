@@ -0,0 +1,362 @@
+use crate::api::review_submissions::ReviewSubmission;
+use crate::api::testflight::{DistributionReport, StepOutcome};
+use crate::bulk::{BulkAction, BulkResult};
+use crate::error::AppStoreConnectError;
+use crate::lint::{LintReport, Severity};
+use crate::pipeline::{PipelineReport, StepStatus};
+use crate::signing::{SigningAssetKind, SigningAudit, SigningIssueKind};
+use std::fmt;
+use std::io::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotationLevel {
+    Error,
+    Warning,
+    Notice,
+}
+
+impl AnnotationLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnnotationLevel::Error => "error",
+            AnnotationLevel::Warning => "warning",
+            AnnotationLevel::Notice => "notice",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Annotation {
+    pub level: AnnotationLevel,
+    pub title: Option<String>,
+    pub message: String,
+}
+
+impl Annotation {
+    pub fn error(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            level: AnnotationLevel::Error,
+            title: Some(title.into()),
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            level: AnnotationLevel::Warning,
+            title: Some(title.into()),
+            message: message.into(),
+        }
+    }
+}
+
+// Workflow commands are line based, so newlines and the `::` separator have
+// to be percent-encoded.
+impl fmt::Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "::{}", self.level.as_str())?;
+        if let Some(title) = &self.title {
+            let title = escape_data(title).replace(':', "%3A").replace(',', "%2C");
+            write!(f, " title={}", title)?;
+        }
+        write!(f, "::{}", escape_data(&self.message))
+    }
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+pub trait ActionsReport {
+    fn annotations(&self) -> Vec<Annotation>;
+
+    fn step_summary(&self) -> String;
+}
+
+pub fn is_github_actions() -> bool {
+    std::env::var("GITHUB_ACTIONS").is_ok_and(|value| value == "true")
+}
+
+// Annotations go to stdout where the runner picks them up; the summary is
+// appended to the file named by GITHUB_STEP_SUMMARY when it is set.
+pub fn emit<R: ActionsReport + ?Sized>(report: &R) -> Result<(), AppStoreConnectError> {
+    for annotation in report.annotations() {
+        println!("{}", annotation);
+    }
+
+    if let Ok(path) = std::env::var("GITHUB_STEP_SUMMARY") {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", report.step_summary())?;
+    }
+
+    Ok(())
+}
+
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+impl ActionsReport for PipelineReport {
+    fn annotations(&self) -> Vec<Annotation> {
+        self.steps
+            .iter()
+            .filter_map(|result| match &result.status {
+                StepStatus::Failed(error) => Some(Annotation::error(
+                    format!("Release {}: {}", self.version_string, result.step),
+                    error,
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn step_summary(&self) -> String {
+        let mut summary = format!("### Release {}", self.version_string);
+        if self.dry_run {
+            summary.push_str(" (dry run)");
+        }
+        summary.push_str("\n\n| Step | Status | Detail |\n| --- | --- | --- |\n");
+
+        for result in &self.steps {
+            let (status, detail) = match &result.status {
+                StepStatus::Done(detail) => ("✅ done", detail),
+                StepStatus::AlreadyDone(detail) => ("✅ already done", detail),
+                StepStatus::WouldRun(detail) => ("📝 would run", detail),
+                StepStatus::Skipped(detail) => ("⏭️ skipped", detail),
+                StepStatus::Failed(detail) => ("❌ failed", detail),
+            };
+            summary.push_str(&format!(
+                "| {} | {} | {} |\n",
+                result.step,
+                status,
+                table_cell(detail)
+            ));
+        }
+
+        summary
+    }
+}
+
+impl ActionsReport for DistributionReport {
+    fn annotations(&self) -> Vec<Annotation> {
+        self.failures()
+            .into_iter()
+            .filter_map(|step| match &step.outcome {
+                StepOutcome::Failed(error) => Some(Annotation::error(
+                    format!("TestFlight: {}", step.step),
+                    error,
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn step_summary(&self) -> String {
+        let mut summary = format!(
+            "### TestFlight distribution{}\n\n| Step | Status |\n| --- | --- |\n",
+            self.build_id
+                .as_ref()
+                .map(|id| format!(" (build {})", id))
+                .unwrap_or_default()
+        );
+
+        for step in &self.steps {
+            let status = match &step.outcome {
+                StepOutcome::Done => "✅ done".to_string(),
+                StepOutcome::Skipped(reason) => format!("⏭️ skipped: {}", reason),
+                StepOutcome::Failed(error) => format!("❌ failed: {}", error),
+            };
+            summary.push_str(&format!(
+                "| {} | {} |\n",
+                table_cell(&step.step),
+                table_cell(&status)
+            ));
+        }
+
+        summary
+    }
+}
+
+impl ActionsReport for [BulkResult] {
+    fn annotations(&self) -> Vec<Annotation> {
+        self.iter()
+            .filter_map(|result| {
+                let error = result.error()?;
+                Some(Annotation::error(
+                    format!("Localization {}", result.locale),
+                    error,
+                ))
+            })
+            .collect()
+    }
+
+    fn step_summary(&self) -> String {
+        let failed = self.iter().filter(|r| !r.is_success()).count();
+        let mut summary = format!(
+            "### Localizations\n\n{} updated, {} failed\n\n| Locale | Result |\n| --- | --- |\n",
+            self.len() - failed,
+            failed
+        );
+
+        for result in self {
+            let status = match &result.action {
+                BulkAction::Failed(error) => format!("❌ {}", error),
+                action => format!("✅ {}", action),
+            };
+            summary.push_str(&format!(
+                "| {} | {} |\n",
+                table_cell(&result.locale),
+                table_cell(&status)
+            ));
+        }
+
+        summary
+    }
+}
+
+impl ActionsReport for SigningAudit {
+    fn annotations(&self) -> Vec<Annotation> {
+        self.issues
+            .iter()
+            .map(|issue| {
+                let asset = match issue.asset {
+                    SigningAssetKind::Certificate => "Certificate",
+                    SigningAssetKind::Profile => "Profile",
+                };
+                let title = format!("{} {}", asset, issue.name.as_deref().unwrap_or(&issue.id));
+
+                match &issue.kind {
+                    SigningIssueKind::ExpiringSoon { days_left } => {
+                        Annotation::warning(title, format!("Expires in {} days", days_left))
+                    }
+                    kind => Annotation::error(title, signing_issue_text(kind)),
+                }
+            })
+            .collect()
+    }
+
+    fn step_summary(&self) -> String {
+        let mut summary = format!(
+            "### Signing audit\n\n{} certificates and {} profiles checked, {} issues\n",
+            self.certificates_checked,
+            self.profiles_checked,
+            self.issues.len()
+        );
+        if self.is_clean() {
+            return summary;
+        }
+
+        summary.push_str("\n| Asset | Name | Expires | Issue |\n| --- | --- | --- | --- |\n");
+        for issue in &self.issues {
+            summary.push_str(&format!(
+                "| {:?} | {} | {} | {} |\n",
+                issue.asset,
+                table_cell(issue.name.as_deref().unwrap_or(&issue.id)),
+                issue
+                    .expires
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+                table_cell(&signing_issue_text(&issue.kind))
+            ));
+        }
+
+        summary
+    }
+}
+
+impl ActionsReport for LintReport {
+    fn annotations(&self) -> Vec<Annotation> {
+        self.findings
+            .iter()
+            .map(|finding| {
+                let title = match &finding.locale {
+                    Some(locale) => format!("{} [{}]", finding.rule.as_str(), locale),
+                    None => finding.rule.as_str().to_string(),
+                };
+                match finding.severity {
+                    Severity::Error => Annotation::error(title, &finding.message),
+                    Severity::Warning => Annotation::warning(title, &finding.message),
+                }
+            })
+            .collect()
+    }
+
+    fn step_summary(&self) -> String {
+        let mut summary = format!(
+            "### Metadata lint\n\n{} errors, {} warnings\n",
+            self.errors().len(),
+            self.warnings().len()
+        );
+        if self.findings.is_empty() {
+            return summary;
+        }
+
+        summary.push_str(
+            "\n| Severity | Rule | Locale | Field | Finding |\n| --- | --- | --- | --- | --- |\n",
+        );
+        for finding in &self.findings {
+            summary.push_str(&format!(
+                "| {:?} | {} | {} | {} | {} |\n",
+                finding.severity,
+                finding.rule.as_str(),
+                finding.locale.as_deref().unwrap_or_default(),
+                finding.field.as_deref().unwrap_or_default(),
+                table_cell(&finding.message)
+            ));
+        }
+
+        summary
+    }
+}
+
+impl ActionsReport for ReviewSubmission {
+    fn annotations(&self) -> Vec<Annotation> {
+        if !self.is_rejected() {
+            return Vec::new();
+        }
+
+        vec![Annotation::error(
+            format!("Review submission {}", self.id),
+            format!(
+                "App Review returned the submission as {}; check Resolution Center in App Store Connect",
+                self.state
+            ),
+        )]
+    }
+
+    fn step_summary(&self) -> String {
+        let status = if self.is_rejected() { "❌" } else { "ℹ️" };
+        let mut summary = format!(
+            "### App Review\n\n| Submission | Platform | State |\n| --- | --- | --- |\n| {} | {} | {} {} |\n",
+            table_cell(&self.id),
+            self.platform.as_deref().unwrap_or_default(),
+            status,
+            self.state
+        );
+        if let Some(date) = &self.submitted_date {
+            summary.push_str(&format!("\nSubmitted {}\n", date));
+        }
+
+        summary
+    }
+}
+
+fn signing_issue_text(kind: &SigningIssueKind) -> String {
+    match kind {
+        SigningIssueKind::Expired => "Expired".to_string(),
+        SigningIssueKind::ExpiringSoon { days_left } => {
+            format!("Expires in {} days", days_left)
+        }
+        SigningIssueKind::RevokedCertificate { serial_number } => {
+            format!("Signed with revoked certificate {}", serial_number)
+        }
+        SigningIssueKind::Invalid => "Profile is invalid".to_string(),
+        SigningIssueKind::Unreadable(error) => format!("Could not be read: {}", error),
+    }
+}
@@ -1,21 +1,92 @@
 pub mod auth;
 pub mod base;
+pub mod bulk;
 pub mod client;
+mod csv;
+pub mod diff;
 pub mod error;
+pub mod events;
+pub mod github_actions;
+pub mod lint;
+pub mod listing;
+pub mod locale;
+pub mod metadata;
+pub mod pipeline;
+pub mod plan;
+pub mod polling;
+pub mod rate_limit;
+pub mod reports;
+pub mod signing;
+pub mod snapshot;
+pub mod upload;
+pub mod watcher;
 pub mod api {
+    pub mod age_ratings;
+    pub mod alternative_distribution;
+    pub mod analytics_reports;
     pub mod apps;
+    pub mod availability;
+    pub mod beta_app_localizations;
+    pub mod beta_build_localizations;
+    pub mod beta_feedback;
+    pub mod beta_license_agreements;
+    pub mod beta_testers;
+    pub mod builds;
+    pub mod bundle_ids;
     pub mod categories;
+    pub mod certificates;
+    pub mod ci;
+    pub mod devices;
+    pub mod encryption_declarations;
+    pub mod eulas;
+    pub mod game_center;
+    pub mod in_app_purchase_localizations;
+    pub mod in_app_purchases;
     pub mod localizations;
     pub mod media;
+    pub mod nominations;
+    pub mod offer_codes;
+    pub mod pricing;
+    pub mod profiles;
+    pub mod promotional_offers;
+    pub mod review_attachments;
+    pub mod review_submissions;
+    pub mod reviews;
+    pub mod sales_reports;
+    pub mod sandbox;
+    pub mod subscription_groups;
+    pub mod subscriptions;
+    pub mod territories;
+    pub mod testflight;
+    pub mod user_invitations;
+    pub mod users;
+    pub mod version_localizations;
     pub mod versions;
 }
 
 pub use auth::Auth;
+pub use bulk::{BulkAction, BulkResult};
 pub use client::Client;
+pub use diff::SnapshotDiff;
 pub use error::{
-    AppStoreConnectError, AuthenticationError, ConflictError, NotFoundError, RateLimitError,
-    ValidationError,
+    AppStoreConnectError, AuthenticationError, ConflictError, InvalidBinaryError, NotFoundError,
+    RateLimitError, TimeoutError, ValidationError,
 };
+pub use events::{AppEvent, Events, Subscription};
+pub use github_actions::ActionsReport;
+pub use lint::{LintFinding, LintReport};
+pub use listing::StoreListing;
+pub use locale::normalize_locale;
+pub use metadata::{FieldUpdate, KeywordAnalyzer, Translator};
+pub use pipeline::{PipelineReport, ReleasePipeline};
+pub use plan::{DesiredState, Plan};
+pub use rate_limit::RateLimiter;
+pub use reports::SalesReportRow;
+pub use signing::SigningAudit;
+pub use snapshot::Snapshot;
+#[cfg(feature = "sqlite")]
+pub use watcher::SqliteStateStore;
+pub use watcher::{ReviewEvent, StateStore, WatchEvent, WatchTarget, Watcher};
 
 pub type Result<T> = std::result::Result<T, AppStoreConnectError>;
 
@@ -51,6 +122,39 @@ mod tests {
 
         let conflict_error = ConflictError::new("test conflict");
         assert_eq!(conflict_error.message, "test conflict");
+
+        let timeout_error = TimeoutError::new("test timeout");
+        assert_eq!(timeout_error.message, "test timeout");
+
+        let invalid_binary_error = InvalidBinaryError::new("1.2.0", "42", "INVALID");
+        assert_eq!(invalid_binary_error.processing_state, "INVALID");
+
+        let rollback_error = AppStoreConnectError::RollbackFailed {
+            source: Box::new(AppStoreConnectError::Timeout(TimeoutError::new(
+                "processing",
+            ))),
+            rollback_errors: vec!["restoring 01.png: forbidden".to_string()],
+            snapshot_dir: None,
+        };
+        assert_eq!(
+            rollback_error.to_string(),
+            "Timed out: Timed out: processing (rollback also failed: restoring 01.png: forbidden)"
+        );
+
+        let kept_error = AppStoreConnectError::RollbackFailed {
+            source: Box::new(AppStoreConnectError::Timeout(TimeoutError::new(
+                "processing",
+            ))),
+            rollback_errors: vec!["restoring shot-9.png: forbidden".to_string()],
+            snapshot_dir: Some(std::path::PathBuf::from("/tmp/asc-replace-set-1")),
+        };
+        assert!(kept_error
+            .to_string()
+            .ends_with("; removed screenshots kept in /tmp/asc-replace-set-1"));
+        assert_eq!(
+            invalid_binary_error.to_string(),
+            "Build 1.2.0 (42) finished processing as INVALID"
+        );
     }
 
     #[tokio::test]
@@ -62,4 +166,2264 @@ mod tests {
         let result = Client::from_env().await;
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_snapshot_yaml_round_trip() {
+        let snapshot = Snapshot {
+            app_id: "123".to_string(),
+            exported_at: chrono::Utc::now(),
+            app: [("name".to_string(), serde_json::json!("My App"))]
+                .into_iter()
+                .collect(),
+            app_infos: Vec::new(),
+            versions: Vec::new(),
+            pricing: Some(snapshot::PricingSnapshot {
+                base_territory: "USA".to_string(),
+                manual_prices: vec![api::pricing::PriceTimelineEntry {
+                    territory: "USA".to_string(),
+                    price_point_id: "usa-499".to_string(),
+                    customer_price: Some("4.99".to_string()),
+                    proceeds: Some("4.24".to_string()),
+                    start_date: chrono::NaiveDate::from_ymd_opt(2024, 6, 1),
+                    end_date: None,
+                    manual: true,
+                }],
+            }),
+            availability: snapshot::AvailabilitySnapshot {
+                available_in_new_territories: true,
+                territories: vec!["USA".to_string()],
+            },
+        };
+
+        let yaml = snapshot.to_yaml().unwrap();
+        assert_eq!(Snapshot::from_yaml(&yaml).unwrap(), snapshot);
+
+        let json = snapshot.to_json().unwrap();
+        assert_eq!(Snapshot::from_json(&json).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let base = Snapshot {
+            app_id: "123".to_string(),
+            exported_at: chrono::Utc::now(),
+            app: Default::default(),
+            app_infos: vec![snapshot::AppInfoSnapshot {
+                id: "info".to_string(),
+                state: None,
+                categories: serde_json::json!({ "primaryCategory": { "id": "HEALTH_AND_FITNESS" } }),
+                localizations: [(
+                    "en-US".to_string(),
+                    [("name".to_string(), serde_json::json!("Sleep"))]
+                        .into_iter()
+                        .collect(),
+                )]
+                .into_iter()
+                .collect(),
+            }],
+            versions: Vec::new(),
+            pricing: None,
+            availability: snapshot::AvailabilitySnapshot {
+                available_in_new_territories: false,
+                territories: vec!["USA".to_string()],
+            },
+        };
+
+        assert!(base.diff(&base).is_empty());
+
+        let mut changed = base.clone();
+        changed.app_infos[0]
+            .localizations
+            .get_mut("en-US")
+            .unwrap()
+            .insert("name".to_string(), serde_json::json!("Sleep Loops"));
+        changed.availability.territories.push("CAN".to_string());
+
+        let diff = base.diff(&changed);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff.for_locale("en-US").len(), 1);
+        assert_eq!(diff.changes[0].kind, diff::ChangeKind::Modified);
+        assert_eq!(diff.changes[1].kind, diff::ChangeKind::Added);
+    }
+
+    #[test]
+    fn test_desired_state_from_yaml() {
+        let yaml = r#"
+localizations:
+  en-US:
+    name: Sleep Loops
+    subtitle: Fall asleep faster
+version:
+  version_string: "2.1.0"
+  localizations:
+    en-US:
+      whatsNew: Bug fixes
+availability:
+  territories: [USA, CAN]
+subscription_availability:
+  "6450000001":
+    territories: [USA]
+    available_in_new_territories: true
+"#;
+
+        let desired = DesiredState::from_yaml(yaml).unwrap();
+        assert_eq!(desired.localizations["en-US"].len(), 2);
+
+        let version = desired.version.unwrap();
+        assert_eq!(version.platform, "IOS");
+        assert_eq!(version.version_string.as_deref(), Some("2.1.0"));
+        assert_eq!(
+            desired.availability.unwrap().available_in_new_territories,
+            None
+        );
+        assert_eq!(
+            desired.subscription_availability["6450000001"].available_in_new_territories,
+            Some(true)
+        );
+        assert!(!desired.prune_locales);
+
+        let field = |name: &str| -> snapshot::FieldMap {
+            [(name.to_string(), serde_json::json!("text"))]
+                .into_iter()
+                .collect()
+        };
+        let locales = [("es_MX".to_string(), field("whatsNew"))]
+            .into_iter()
+            .collect();
+        let normalized =
+            plan::normalize_desired_locales(&locales, snapshot::VERSION_LOCALIZATION_FIELDS)
+                .unwrap();
+        assert!(normalized.contains_key("es-MX"));
+
+        let locales = [("fr-FR".to_string(), field("whatsNw"))]
+            .into_iter()
+            .collect();
+        assert!(
+            plan::normalize_desired_locales(&locales, snapshot::VERSION_LOCALIZATION_FIELDS)
+                .is_err()
+        );
+
+        let locales = [
+            ("de".to_string(), field("name")),
+            ("de-DE".to_string(), field("name")),
+        ]
+        .into_iter()
+        .collect();
+        assert!(
+            plan::normalize_desired_locales(&locales, snapshot::APP_INFO_LOCALIZATION_FIELDS)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_categories_patch_clears_removed_fields() {
+        use diff::{ChangeKind, DiffScope, FieldChange};
+
+        let change = |field: &str, kind, new: Option<&str>| FieldChange {
+            scope: DiffScope::Categories,
+            field: field.to_string(),
+            kind,
+            old: Some(serde_json::json!("OLD")),
+            new: new.map(|n| serde_json::json!(n)),
+        };
+        let changes = vec![
+            change("primaryCategory", ChangeKind::Modified, Some("GAMES")),
+            change("secondaryCategory", ChangeKind::Removed, None),
+            change("primarySubcategoryOne", ChangeKind::Removed, None),
+        ];
+
+        let patch = plan::categories_patch("info", &changes);
+        let data = &patch["data"];
+        assert_eq!(
+            data["relationships"]["primaryCategory"]["data"]["id"],
+            "GAMES"
+        );
+        assert!(data["relationships"]["secondaryCategory"]["data"].is_null());
+        assert!(data["attributes"]
+            .as_object()
+            .unwrap()
+            .contains_key("primarySubcategoryOne"));
+        assert!(data["attributes"]["primarySubcategoryOne"].is_null());
+    }
+
+    #[test]
+    fn test_upload_operations_from_reservation() {
+        let reservation = serde_json::json!({
+            "id": "attachment",
+            "attributes": {
+                "uploadOperations": [
+                    {
+                        "method": "PUT",
+                        "url": "https://upload.example.com/part1",
+                        "length": 4,
+                        "offset": 0,
+                        "requestHeaders": [{ "name": "Content-Type", "value": "image/png" }]
+                    },
+                    {
+                        "method": "PUT",
+                        "url": "https://upload.example.com/part2",
+                        "length": 2,
+                        "offset": 4,
+                        "requestHeaders": []
+                    }
+                ]
+            }
+        });
+
+        let operations = upload::UploadOperation::from_resource(&reservation);
+        assert_eq!(operations.len(), 2);
+        assert_eq!(operations[0].request_headers[0].0, "Content-Type");
+
+        let data = b"abcdef";
+        assert_eq!(operations[1].slice(data).unwrap(), b"ef");
+        assert!(operations[1].slice(b"abc").is_err());
+    }
+
+    #[test]
+    fn test_review_submission_has_item() {
+        let items = vec![serde_json::json!({
+            "type": "reviewSubmissionItems",
+            "id": "item-1",
+            "relationships": {
+                "appStoreVersion": { "data": { "type": "appStoreVersions", "id": "version-1" } }
+            }
+        })];
+
+        assert!(api::review_submissions::has_item(
+            &items,
+            "appStoreVersion",
+            "version-1"
+        ));
+        assert!(!api::review_submissions::has_item(
+            &items,
+            "appStoreVersion",
+            "version-2"
+        ));
+        assert!(!api::review_submissions::has_item(
+            &items,
+            "appEvent",
+            "version-1"
+        ));
+    }
+
+    #[test]
+    fn test_bump_version() {
+        use api::versions::{bump_version, VersionBump};
+
+        assert_eq!(bump_version("1.2.3", VersionBump::Patch).unwrap(), "1.2.4");
+        assert_eq!(bump_version("1.2.3", VersionBump::Minor).unwrap(), "1.3.0");
+        assert_eq!(bump_version("1.2.3", VersionBump::Major).unwrap(), "2.0.0");
+        assert_eq!(bump_version("1.2", VersionBump::Patch).unwrap(), "1.2.1");
+        assert_eq!(bump_version("1", VersionBump::Minor).unwrap(), "1.1");
+        assert!(bump_version("1.2.beta", VersionBump::Patch).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_poll_until() {
+        use std::time::Duration;
+
+        let options =
+            polling::PollOptions::new(Duration::from_millis(200), Duration::from_millis(1));
+        let mut attempts = 0;
+        let result = polling::poll_until("counter", &options, || {
+            attempts += 1;
+            let done = attempts >= 3;
+            async move { Ok(done.then_some(attempts)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(result, 3);
+
+        let options = polling::PollOptions::new(Duration::from_millis(5), Duration::from_millis(1));
+        let result: Result<()> =
+            polling::poll_until("never", &options, || async { Ok(None) }).await;
+        assert!(matches!(result, Err(AppStoreConnectError::Timeout(_))));
+
+        let first = polling::rate_limit_delay(Duration::from_secs(10), None);
+        assert!(first >= Duration::from_secs(60));
+        let second = polling::rate_limit_delay(Duration::from_secs(10), Some(first));
+        assert!(second > first);
+        let capped =
+            polling::rate_limit_delay(Duration::from_secs(10), Some(Duration::from_secs(5 * 60)));
+        assert_eq!(capped, Duration::from_secs(5 * 60));
+    }
+
+    #[test]
+    fn test_validate_release_date() {
+        use api::versions::validate_release_date;
+        use chrono::{Duration, Timelike, Utc};
+
+        let next_hour = (Utc::now() + Duration::hours(2))
+            .with_minute(0)
+            .and_then(|d| d.with_second(0))
+            .and_then(|d| d.with_nanosecond(0))
+            .unwrap();
+        assert!(validate_release_date(&next_hour).is_ok());
+        assert!(validate_release_date(&(next_hour + Duration::minutes(30))).is_err());
+        assert!(validate_release_date(&(next_hour - Duration::days(2))).is_err());
+    }
+
+    #[test]
+    fn test_summarize_tester_usage() {
+        use api::beta_testers::summarize_tester_usage;
+
+        let metrics = vec![
+            serde_json::json!({
+                "dataPoints": [
+                    { "values": { "sessionCount": 3, "crashCount": 1, "feedbackCount": 0 } },
+                    { "values": { "sessionCount": 2, "crashCount": 0, "feedbackCount": 1 } }
+                ],
+                "dimensions": { "betaTesters": { "data": "tester-b" } }
+            }),
+            serde_json::json!({
+                "dataPoints": [],
+                "dimensions": { "betaTesters": { "data": { "type": "betaTesters", "id": "tester-a" } } }
+            }),
+        ];
+
+        let usage = summarize_tester_usage(&metrics);
+        assert_eq!(usage.len(), 2);
+        assert_eq!(usage[0].tester_id, "tester-a");
+        assert_eq!(usage[0].sessions, 0);
+        assert_eq!(usage[1].sessions, 5);
+        assert_eq!(usage[1].crashes, 1);
+        assert_eq!(usage[1].feedback, 1);
+    }
+
+    #[test]
+    fn test_feedback_screenshot_urls() {
+        use api::beta_feedback::screenshot_urls;
+
+        let submission = serde_json::json!({
+            "id": "feedback-1",
+            "attributes": {
+                "comment": "Button overlaps the header",
+                "screenshots": [
+                    { "url": "https://example.com/1.png", "width": 1170, "height": 2532 },
+                    { "width": 1170, "height": 2532 },
+                    { "url": "https://example.com/2.png" }
+                ]
+            }
+        });
+
+        assert_eq!(
+            screenshot_urls(&submission),
+            vec!["https://example.com/1.png", "https://example.com/2.png"]
+        );
+        assert!(screenshot_urls(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_select_builds_to_expire() {
+        use api::builds::{select_builds_to_expire, ExpirePolicy};
+        use chrono::{TimeZone, Utc};
+
+        let build = |id: &str, uploaded: &str| serde_json::json!({ "id": id, "attributes": { "uploadedDate": uploaded } });
+        let builds = vec![
+            build("old", "2024-01-01T00:00:00Z"),
+            build("newest", "2024-03-01T00:00:00Z"),
+            build("middle", "2024-02-01T00:00:00Z"),
+        ];
+        let ids = |selected: Vec<serde_json::Value>| {
+            selected
+                .iter()
+                .map(|b| b["id"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        let now = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            ids(select_builds_to_expire(
+                &builds,
+                ExpirePolicy::KeepLatest(1),
+                now
+            )),
+            vec!["middle", "old"]
+        );
+        assert_eq!(
+            ids(select_builds_to_expire(
+                &builds,
+                ExpirePolicy::OlderThan(chrono::Duration::days(30)),
+                now
+            )),
+            vec!["middle", "old"]
+        );
+        assert!(select_builds_to_expire(&builds, ExpirePolicy::KeepLatest(5), now).is_empty());
+    }
+
+    #[test]
+    fn test_manual_prices_body() {
+        use api::pricing::{manual_prices, ScheduledPrice};
+        use chrono::NaiveDate;
+
+        let prices = vec![
+            ScheduledPrice::immediate("point-1"),
+            ScheduledPrice::starting("point-2", NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()),
+        ];
+        let (linkages, included) = manual_prices(
+            "inAppPurchasePrices",
+            "inAppPurchasePricePoints",
+            "inAppPurchasePricePoint",
+            &prices,
+        );
+
+        assert_eq!(linkages.len(), 2);
+        assert_eq!(linkages[1]["id"], "${price-1}");
+        assert!(included[0]["attributes"]["startDate"].is_null());
+        assert_eq!(included[1]["attributes"]["startDate"], "2025-01-15");
+        assert_eq!(
+            included[1]["relationships"]["inAppPurchasePricePoint"]["data"]["id"],
+            "point-2"
+        );
+    }
+
+    #[test]
+    fn test_promotional_offer_signature_payload() {
+        use api::promotional_offers::PromotionalOfferSignatureInput;
+
+        let mut input = PromotionalOfferSignatureInput::new(
+            "com.example.app",
+            "KEY123",
+            "com.example.premium.monthly",
+            "WINBACK50",
+            "User-ABC",
+        );
+        input.nonce = uuid::Uuid::parse_str("A1B2C3D4-0000-4000-8000-000000000001").unwrap();
+        input.timestamp = 1700000000000;
+
+        assert_eq!(
+            input.payload(),
+            "com.example.app\u{2063}KEY123\u{2063}com.example.premium.monthly\u{2063}WINBACK50\u{2063}user-abc\u{2063}a1b2c3d4-0000-4000-8000-000000000001\u{2063}1700000000000"
+        );
+    }
+
+    #[test]
+    fn test_find_price_point() {
+        use api::pricing::find_price_point;
+
+        let points = vec![
+            serde_json::json!({ "id": "p1", "attributes": { "customerPrice": "0.99" } }),
+            serde_json::json!({ "id": "p2", "attributes": { "customerPrice": "4.99" } }),
+            serde_json::json!({ "id": "p3", "attributes": { "customerPrice": "5.0" } }),
+        ];
+
+        assert_eq!(find_price_point(&points, "4.99").unwrap()["id"], "p2");
+        assert_eq!(find_price_point(&points, "5.00").unwrap()["id"], "p3");
+        assert!(find_price_point(&points, "2.99").is_none());
+        assert!(find_price_point(&points, "free").is_none());
+    }
+
+    #[test]
+    fn test_build_price_timeline() {
+        use api::pricing::build_price_timeline;
+
+        let price = |point: &str, territory: &str, start: Option<&str>, end: Option<&str>| {
+            serde_json::json!({
+                "type": "appPrices",
+                "attributes": { "manual": territory == "USA", "startDate": start, "endDate": end },
+                "relationships": {
+                    "appPricePoint": { "data": { "type": "appPricePoints", "id": point } },
+                    "territory": { "data": { "type": "territories", "id": territory } }
+                }
+            })
+        };
+        let prices = vec![
+            price("usa-499", "USA", Some("2024-06-01"), None),
+            price("usa-399", "USA", None, Some("2024-06-01")),
+            price("fra-499", "FRA", None, None),
+        ];
+        let included = vec![serde_json::json!({
+            "type": "appPricePoints",
+            "id": "usa-499",
+            "attributes": { "customerPrice": "4.99", "proceeds": "4.24" }
+        })];
+
+        let timeline = build_price_timeline(&prices, &included);
+        assert_eq!(timeline.len(), 3);
+        assert_eq!(timeline[0].territory, "FRA");
+        assert!(!timeline[0].manual);
+        assert_eq!(timeline[1].price_point_id, "usa-399");
+        assert_eq!(timeline[2].customer_price.as_deref(), Some("4.99"));
+        assert_eq!(
+            timeline[2].start_date,
+            chrono::NaiveDate::from_ymd_opt(2024, 6, 1)
+        );
+
+        let usa: Vec<_> = timeline
+            .iter()
+            .filter(|e| e.territory == "USA")
+            .cloned()
+            .collect();
+        let day = |m, d| chrono::NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let current = api::pricing::price_in_effect(&usa, day(5, 31)).unwrap();
+        assert_eq!(current.price_point_id, "usa-399");
+        let current = api::pricing::price_in_effect(&usa, day(6, 1)).unwrap();
+        assert_eq!(current.price_point_id, "usa-499");
+    }
+
+    #[test]
+    fn test_territory_country_mapping() {
+        use api::territories::{country_for_territory, territory_for_country, Territory};
+
+        assert_eq!(territory_for_country("us"), Some("USA"));
+        assert_eq!(territory_for_country("GB"), Some("GBR"));
+        assert_eq!(country_for_territory("DEU"), Some("DE"));
+        assert_eq!(country_for_territory("XKS"), Some("XK"));
+        assert_eq!(territory_for_country("ZZ"), None);
+
+        let territory = Territory::from_value(&serde_json::json!({
+            "type": "territories",
+            "id": "JPN",
+            "attributes": { "currency": "JPY" }
+        }))
+        .unwrap();
+        assert_eq!(territory.currency.as_deref(), Some("JPY"));
+        assert_eq!(territory.country_code.as_deref(), Some("JP"));
+    }
+
+    #[tokio::test]
+    async fn test_source_file_checksum() {
+        assert_eq!(upload::md5_hex(b"abc"), "900150983cd24fb0d6963f7d28e17f72");
+
+        let path = env::temp_dir().join("asc-checksum-test.bin");
+        tokio::fs::write(&path, b"abc").await.unwrap();
+        let checksum = upload::md5_file(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(checksum, "900150983cd24fb0d6963f7d28e17f72");
+    }
+
+    #[test]
+    fn test_upload_progress_pending() {
+        let operation = |offset| upload::UploadOperation {
+            method: "PUT".to_string(),
+            url: format!("https://upload.example.com/{}", offset),
+            length: 4,
+            offset,
+            request_headers: Vec::new(),
+        };
+        let operations = vec![operation(0), operation(4), operation(8)];
+
+        let mut progress = upload::UploadProgress::new("screenshot");
+        progress.completed_offsets.insert(4);
+
+        let pending: Vec<u64> = progress
+            .pending(&operations)
+            .iter()
+            .map(|op| op.offset)
+            .collect();
+        assert_eq!(pending, vec![0, 8]);
+        assert!(!progress.is_complete(&operations));
+
+        progress.completed_offsets.extend([0, 8]);
+        assert!(progress.is_complete(&operations));
+    }
+
+    #[tokio::test]
+    async fn test_upload_read_part() {
+        let path = std::env::temp_dir().join("asc_read_part_test.bin");
+        tokio::fs::write(&path, b"abcdefgh").await.unwrap();
+
+        let operation = |offset, length| upload::UploadOperation {
+            method: "PUT".to_string(),
+            url: "https://upload.example.com".to_string(),
+            length,
+            offset,
+            request_headers: Vec::new(),
+        };
+        let part = upload::read_part(&path, &operation(4, 3)).await.unwrap();
+        let past_end = upload::read_part(&path, &operation(6, 4)).await;
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(part, b"efg");
+        assert!(matches!(past_end, Err(AppStoreConnectError::Validation(_))));
+        assert_eq!(
+            upload::UploadProgress::sidecar_path(std::path::Path::new("previews/intro.mp4")),
+            std::path::PathBuf::from("previews/intro.mp4.upload.json")
+        );
+        assert!(!upload::is_transient(&AppStoreConnectError::Conflict(
+            ConflictError::new("already committed")
+        )));
+
+        let sidecar = std::env::temp_dir().join("asc_progress_test.upload.json");
+        let mut progress = upload::UploadProgress::new("preview-1");
+        progress.completed_offsets.insert(0);
+        progress.save(&sidecar).await.unwrap();
+        let loaded = upload::UploadProgress::load(&sidecar).await.unwrap();
+        tokio::fs::remove_file(&sidecar).await.unwrap();
+        assert_eq!(loaded, progress);
+    }
+
+    #[test]
+    fn test_media_asset_url() {
+        let asset = serde_json::json!({
+            "templateUrl": "https://is1-ssl.mzstatic.com/image/thumb/abc/{w}x{h}bb.{f}",
+            "width": 1290,
+            "height": 2796
+        });
+
+        assert_eq!(
+            api::media::asset_url(&asset, "png").as_deref(),
+            Some("https://is1-ssl.mzstatic.com/image/thumb/abc/1290x2796bb.png")
+        );
+        assert!(api::media::asset_url(&serde_json::json!({}), "png").is_none());
+    }
+
+    #[test]
+    fn test_validate_frame_time_code() {
+        use api::media::validate_frame_time_code;
+
+        assert!(validate_frame_time_code("00:00:05:12").is_ok());
+        assert!(validate_frame_time_code("00:05:12").is_err());
+        assert!(validate_frame_time_code("00:00:5:12").is_err());
+        assert!(validate_frame_time_code("00:00:0a:12").is_err());
+    }
+
+    #[test]
+    fn test_validate_screenshot() {
+        use api::media::{validate_screenshot, ImageFormat, ScreenshotDisplayType};
+
+        let png = |width: u32, height: u32, color_type: u8| {
+            let mut data = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+            data.extend_from_slice(&width.to_be_bytes());
+            data.extend_from_slice(&height.to_be_bytes());
+            data.extend_from_slice(&[8, color_type, 0, 0, 0]);
+            data
+        };
+
+        let info =
+            validate_screenshot(&png(1290, 2796, 2), ScreenshotDisplayType::AppIphone67).unwrap();
+        assert_eq!(info.format, ImageFormat::Png);
+        assert!(
+            validate_screenshot(&png(2796, 1290, 6), ScreenshotDisplayType::AppIphone67).is_ok()
+        );
+
+        match validate_screenshot(&png(1000, 1000, 0), ScreenshotDisplayType::AppIphone67) {
+            Err(AppStoreConnectError::Validation(e)) => assert_eq!(e.issues.len(), 2),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        assert!(validate_screenshot(b"GIF89a", ScreenshotDisplayType::AppIphone67).is_err());
+        assert_eq!(
+            ScreenshotDisplayType::parse("APP_IPAD_PRO_3GEN_129"),
+            Some(ScreenshotDisplayType::AppIpadPro3Gen129)
+        );
+        assert!(ScreenshotDisplayType::IPHONE_69.accepts(1320, 2868));
+        assert!(ScreenshotDisplayType::AppAppleVisionPro.accepts(3840, 2160));
+        let imessage = ScreenshotDisplayType::parse("IMESSAGE_APP_IPHONE_67").unwrap();
+        assert!(imessage.is_imessage());
+        assert!(imessage.accepts(1290, 2796));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_burst_and_refill() {
+        let limiter = RateLimiter::new(36_000, 2);
+        let start = tokio::time::Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() < std::time::Duration::from_millis(50));
+
+        limiter.acquire().await;
+        assert!(start.elapsed() >= std::time::Duration::from_millis(80));
+
+        limiter.disable();
+        let start = tokio::time::Instant::now();
+        for _ in 0..10 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < std::time::Duration::from_millis(50));
+        assert!(!RateLimiter::unlimited().is_enabled());
+    }
+
+    #[test]
+    fn test_media_asset_delivery_state() {
+        use api::media::{AssetHandle, AssetKind, MediaAsset};
+
+        let asset = MediaAsset::from_value(&serde_json::json!({
+            "type": "appScreenshots",
+            "id": "shot-1",
+            "attributes": {
+                "fileName": "01.png",
+                "fileSize": 1024,
+                "assetDeliveryState": {
+                    "state": "FAILED",
+                    "errors": [{ "code": "IMAGE_TOOL_FAILURE", "description": "Image could not be processed" }],
+                    "warnings": []
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(asset.kind, AssetKind::Screenshot);
+        assert_eq!(asset.handle(), AssetHandle::screenshot("shot-1"));
+        assert!(asset.delivery_state.is_failed());
+        assert_eq!(
+            asset.delivery_state.errors,
+            vec!["Image could not be processed"]
+        );
+        assert!(
+            MediaAsset::from_value(&serde_json::json!({ "type": "apps", "id": "1" })).is_none()
+        );
+    }
+
+    #[test]
+    fn test_screenshots_from_included_sets() {
+        let sets = vec![serde_json::json!({
+            "type": "appScreenshotSets",
+            "id": "set-1",
+            "attributes": { "screenshotDisplayType": "APP_IPHONE_67" },
+            "relationships": {
+                "appScreenshots": {
+                    "data": [
+                        { "type": "appScreenshots", "id": "b" },
+                        { "type": "appScreenshots", "id": "a" }
+                    ]
+                }
+            }
+        })];
+        let included = vec![
+            serde_json::json!({ "type": "appScreenshots", "id": "a" }),
+            serde_json::json!({ "type": "appScreenshots", "id": "b" }),
+        ];
+
+        let screenshots = api::media::screenshots_from_sets(&sets, &included);
+        let ids: Vec<&str> = screenshots
+            .iter()
+            .map(|s| s["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["b", "a"]);
+        assert_eq!(screenshots[0]["displayType"], "APP_IPHONE_67");
+    }
+
+    #[test]
+    fn test_store_listing_from_localizations() {
+        let app_info_localizations = vec![serde_json::json!({
+            "id": "info-en",
+            "attributes": { "locale": "en-US", "name": "Sleep Loops", "subtitle": null }
+        })];
+        let version_localizations = vec![
+            serde_json::json!({
+                "id": "version-en",
+                "attributes": { "locale": "en-US", "description": "Fall asleep faster", "whatsNew": null }
+            }),
+            serde_json::json!({
+                "id": "version-de",
+                "attributes": { "locale": "de-DE", "description": "Schneller einschlafen" }
+            }),
+        ];
+
+        let listing = listing::StoreListing::from_localizations(
+            "123",
+            Some("info".to_string()),
+            None,
+            &app_info_localizations,
+            &version_localizations,
+        );
+
+        assert_eq!(listing.locales.len(), 2);
+        assert_eq!(listing.locales["en-US"].app_info["name"], "Sleep Loops");
+        assert!(!listing.locales["en-US"].app_info.contains_key("subtitle"));
+        assert!(listing.locales["de-DE"].app_info.is_empty());
+
+        let yaml = listing.to_yaml().unwrap();
+        assert_eq!(listing::StoreListing::from_yaml(&yaml).unwrap(), listing);
+    }
+
+    #[test]
+    fn test_store_listing_diff_only_managed_fields() {
+        let live = listing::StoreListing::from_yaml(
+            r#"
+app_id: "123"
+locales:
+  en-US:
+    app_info: { name: Sleep Loops, subtitle: Relax }
+    version: { description: Old, keywords: "sleep,relax" }
+"#,
+        )
+        .unwrap();
+        let desired = listing::StoreListing::from_yaml(
+            r#"
+app_id: "123"
+locales:
+  en-US:
+    version: { description: New }
+  fr-FR:
+    app_info: { name: Sommeil }
+"#,
+        )
+        .unwrap();
+
+        let diff = live.diff(&desired);
+        let rendered: Vec<String> = diff.changes.iter().map(|c| format!("{}", c)).collect();
+
+        assert_eq!(diff.len(), 2, "{:?}", rendered);
+        assert_eq!(diff.for_locale("fr-FR")[0].field, "name");
+        assert_eq!(diff.for_locale("en-US")[0].field, "description");
+    }
+
+    #[test]
+    fn test_store_listing_import_sends_only_changed_fields() {
+        let live = listing::StoreListing::from_yaml(
+            r#"
+app_id: "123"
+locales:
+  en-US:
+    version: { description: Old, keywords: "sleep,relax" }
+"#,
+        )
+        .unwrap();
+        let desired = listing::StoreListing::from_yaml(
+            r#"
+app_id: "123"
+locales:
+  en-US:
+    version: { description: New, keywords: "sleep,relax" }
+  de-DE:
+    version: { description: Neu }
+"#,
+        )
+        .unwrap();
+
+        let diff = live.diff(&desired);
+        let changed = desired.import_fields(&diff, true, false);
+        assert_eq!(changed.len(), 2);
+        assert_eq!(
+            changed["en-US"].keys().collect::<Vec<_>>(),
+            vec!["description"]
+        );
+        assert_eq!(changed["de-DE"]["description"], "Neu");
+        assert!(desired.import_fields(&diff, true, true).is_empty());
+
+        let all = desired.import_fields(&diff, false, false);
+        assert_eq!(all["en-US"].len(), 2);
+    }
+
+    #[test]
+    fn test_metadata_field_limits() {
+        let attributes = serde_json::json!({
+            "name": "Sleep Loops",
+            "subtitle": "Relaxing sounds for deep, restful sleep",
+            "keywords": "sleep,relax",
+            "promotionalText": "é".repeat(170)
+        });
+
+        assert_eq!(
+            metadata::check_field_limits(&attributes),
+            vec!["subtitle is 39 characters (limit 30)"]
+        );
+        match metadata::validate_field_limits("de-DE", &attributes) {
+            Err(AppStoreConnectError::Validation(e)) => {
+                assert!(e.message.contains("de-DE"));
+                assert_eq!(e.issues.len(), 1);
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_keyword_analyzer() {
+        let analyzer = metadata::KeywordAnalyzer::default();
+        let report = analyzer.analyze(
+            "en-US",
+            "sleep, white noise,Rain,rain,meditation, loops",
+            Some("Sleep Loops"),
+            Some("Rain sounds for calm"),
+        );
+
+        assert_eq!(report.duplicates, vec!["rain"]);
+        assert_eq!(report.in_name_or_subtitle, vec!["sleep", "Rain", "loops"]);
+        assert_eq!(report.optimized, "white noise,meditation");
+        assert_eq!(report.remaining, 100 - report.length);
+
+        let long = (0..30)
+            .map(|i| format!("term{}", i))
+            .collect::<Vec<_>>()
+            .join(",");
+        let report = analyzer.analyze("en-US", &long, None, None);
+        assert!(report.optimized.chars().count() <= 100);
+    }
+
+    #[test]
+    fn test_whats_new_for_locale() {
+        use api::version_localizations::WhatsNew;
+
+        let all = WhatsNew::All("Bug fixes".to_string());
+        assert_eq!(all.for_locale("ja"), Some("Bug fixes"));
+
+        let mut texts = std::collections::HashMap::new();
+        texts.insert("de-DE".to_string(), "Fehlerbehebungen".to_string());
+        let per_locale = WhatsNew::PerLocale {
+            texts: texts.clone(),
+            default: Some("Bug fixes".to_string()),
+        };
+        assert_eq!(per_locale.for_locale("de-DE"), Some("Fehlerbehebungen"));
+        assert_eq!(per_locale.for_locale("fr-FR"), Some("Bug fixes"));
+
+        let strict = WhatsNew::PerLocale {
+            texts,
+            default: None,
+        };
+        assert_eq!(strict.for_locale("fr-FR"), None);
+    }
+
+    #[test]
+    fn test_bulk_result_actions() {
+        let created = BulkResult::from_result(
+            "en-US",
+            BulkAction::Created,
+            Ok(serde_json::json!({ "id": "1" })),
+        );
+        assert!(created.is_success());
+        assert_eq!(created.action.to_string(), "created");
+
+        let failed = BulkResult::from_result(
+            "fr-FR",
+            BulkAction::Updated,
+            Err(AppStoreConnectError::Api {
+                message: "boom".to_string(),
+            }),
+        );
+        assert!(!failed.is_success());
+        assert!(failed.error().is_some_and(|e| e.contains("boom")));
+        assert!(failed.data.is_none());
+
+        let results = vec![created, failed];
+        let failures = bulk::failures(&results);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].locale, "fr-FR");
+    }
+
+    #[test]
+    fn test_normalize_locale() {
+        use locale::{is_supported_locale, suggest_locale};
+
+        assert_eq!(normalize_locale("es_MX").unwrap(), "es-MX");
+        assert_eq!(normalize_locale("es-419").unwrap(), "es-MX");
+        assert_eq!(normalize_locale("pt_BR").unwrap(), "pt-BR");
+        assert_eq!(normalize_locale("zh-CN").unwrap(), "zh-Hans");
+        assert_eq!(normalize_locale("zh_TW").unwrap(), "zh-Hant");
+        assert_eq!(normalize_locale("EN-us").unwrap(), "en-US");
+        assert_eq!(normalize_locale("ja-JP").unwrap(), "ja");
+        assert!(is_supported_locale("fr-CA"));
+        assert!(!is_supported_locale("fr_CA"));
+
+        assert_eq!(suggest_locale("de-AT"), Some("de-DE"));
+        assert_eq!(suggest_locale("en-IN"), Some("en-US"));
+        assert_eq!(suggest_locale("pt-AO"), Some("pt-BR"));
+
+        let error = normalize_locale("en_in").unwrap_err().to_string();
+        assert!(error.contains("did you mean 'en-US'"));
+        assert!(normalize_locale("en-ZZ").is_err());
+        assert!(normalize_locale("klingon").is_err());
+        assert!(normalize_locale("en-US-extra").is_err());
+    }
+
+    #[test]
+    fn test_store_listing_csv_round_trip() {
+        let listing = listing::StoreListing::from_yaml(
+            r#"
+app_id: "123"
+locales:
+  en-US:
+    app_info: { name: Sleep Loops, subtitle: "Rain, waves & \"white\" noise" }
+    version: { whatsNew: "Line one\nLine two" }
+"#,
+        )
+        .unwrap();
+
+        let csv = listing.to_csv();
+        assert!(csv.starts_with("locale,scope,field,value\n"));
+        assert!(csv.contains("\"Rain, waves & \"\"white\"\" noise\""));
+
+        let parsed = listing::StoreListing::from_csv("123", &csv).unwrap();
+        assert_eq!(parsed.locales, listing.locales);
+        assert!(listing.diff(&parsed).is_empty());
+
+        let blank = listing::StoreListing::from_csv(
+            "123",
+            "locale,scope,field,value\r\nfr-FR,app_info,name,\r\nfr-FR,version,keywords,sommeil\r\n",
+        )
+        .unwrap();
+        assert!(blank.locales["fr-FR"].app_info.is_empty());
+        assert_eq!(blank.locales["fr-FR"].version["keywords"], "sommeil");
+
+        assert!(listing::StoreListing::from_csv("123", "locale,field\n").is_err());
+        assert!(listing::StoreListing::from_csv(
+            "123",
+            "locale,scope,field,value\nen-US,app_info,keywords,x\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_export_by_locale() {
+        let localizations = vec![
+            serde_json::json!({
+                "id": "loc-en",
+                "attributes": {
+                    "locale": "en-US",
+                    "description": "Sleep better",
+                    "keywords": "sleep,rain",
+                    "whatsNew": null
+                }
+            }),
+            serde_json::json!({ "id": "broken", "attributes": {} }),
+        ];
+
+        let exported = bulk::export_by_locale(localizations, snapshot::VERSION_LOCALIZATION_FIELDS);
+
+        assert_eq!(exported.len(), 1);
+        assert_eq!(
+            exported["en-US"],
+            serde_json::json!({ "description": "Sleep better", "keywords": "sleep,rain" })
+        );
+    }
+
+    #[test]
+    fn test_field_update_never_clobbers() {
+        use api::localizations::AppInfoLocalizationUpdate;
+        use api::version_localizations::VersionLocalizationUpdate;
+        use metadata::FieldUpdate;
+
+        let update = VersionLocalizationUpdate::from_value(&serde_json::json!({
+            "whatsNew": "Bug fixes",
+            "promotionalText": null
+        }));
+        assert_eq!(update.whats_new, FieldUpdate::Set("Bug fixes".to_string()));
+        assert_eq!(update.promotional_text, FieldUpdate::Clear);
+        assert!(update.description.is_keep());
+        assert_eq!(
+            update.to_attributes(),
+            serde_json::json!({ "whatsNew": "Bug fixes", "promotionalText": null })
+        );
+
+        let update = AppInfoLocalizationUpdate {
+            subtitle: Some("Relax").into(),
+            name: None.into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            update.to_attributes(),
+            serde_json::json!({ "subtitle": "Relax" })
+        );
+
+        let update = api::versions::VersionUpdate {
+            copyright: FieldUpdate::Clear,
+            uses_idfa: Some(false).into(),
+            earliest_release_date: FieldUpdate::Clear,
+            ..Default::default()
+        };
+        assert_eq!(
+            update.to_attributes(),
+            serde_json::json!({
+                "copyright": null,
+                "earliestReleaseDate": null,
+                "usesIdfa": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_customer_review_from_value() {
+        use api::reviews::CustomerReview;
+
+        let review = serde_json::json!({
+            "type": "customerReviews",
+            "id": "r1",
+            "attributes": {
+                "rating": 4,
+                "title": "Great",
+                "body": "Helps me sleep",
+                "reviewerNickname": "night owl",
+                "territory": "USA",
+                "createdDate": "2024-03-01T08:30:00-08:00"
+            },
+            "relationships": { "response": { "data": { "type": "customerReviewResponses", "id": "resp1" } } }
+        });
+        let included = vec![serde_json::json!({
+            "type": "customerReviewResponses",
+            "id": "resp1",
+            "attributes": { "responseBody": "Thanks!", "state": "PUBLISHED" }
+        })];
+
+        let parsed = CustomerReview::from_value(&review, &included).unwrap();
+        assert_eq!(parsed.rating, 4);
+        assert_eq!(parsed.territory.as_deref(), Some("USA"));
+        assert_eq!(
+            parsed.created_date.unwrap().to_rfc3339(),
+            "2024-03-01T16:30:00+00:00"
+        );
+        assert_eq!(parsed.response.unwrap().response_body, "Thanks!");
+
+        let without_response = CustomerReview::from_value(&review, &[]).unwrap();
+        assert!(without_response.response.is_none());
+    }
+
+    #[test]
+    fn test_next_page_link() {
+        let response = serde_json::json!({
+            "data": [],
+            "links": {
+                "self": "https://api.appstoreconnect.apple.com/v1/apps/1/customerReviews",
+                "next": "https://api.appstoreconnect.apple.com/v1/apps/1/customerReviews?cursor=AB&limit=200"
+            }
+        });
+
+        let (endpoint, params) = base::next_page(&response).unwrap();
+        assert_eq!(endpoint, "apps/1/customerReviews");
+        assert_eq!(params["cursor"], "AB");
+        assert_eq!(params["limit"], "200");
+
+        assert!(base::next_page(&serde_json::json!({ "links": {} })).is_none());
+    }
+
+    #[test]
+    fn test_customer_review_csv_row() {
+        use api::reviews::{CustomerReview, ReviewResponse};
+
+        let review = CustomerReview {
+            id: "r1".to_string(),
+            rating: 2,
+            title: Some("Crashes, often".to_string()),
+            body: Some("Says \"error\" on launch".to_string()),
+            reviewer_nickname: None,
+            territory: Some("GBR".to_string()),
+            created_date: None,
+            response: Some(ReviewResponse {
+                id: "resp".to_string(),
+                response_body: "Fixed in 2.1".to_string(),
+                last_modified_date: None,
+                state: None,
+            }),
+        };
+
+        let mut csv = String::new();
+        review.push_csv_row(&mut csv);
+        assert_eq!(
+            csv,
+            "r1,2,\"Crashes, often\",\"Says \"\"error\"\" on launch\",,GBR,,Fixed in 2.1\n"
+        );
+    }
+
+    #[test]
+    fn test_ratings_summary_per_territory() {
+        use api::reviews::{CustomerReview, RatingsSummary};
+        use chrono::{TimeZone, Utc};
+
+        let review = |id: &str, rating: u8, territory: &str, day: u32| CustomerReview {
+            id: id.to_string(),
+            rating,
+            title: None,
+            body: None,
+            reviewer_nickname: None,
+            territory: Some(territory.to_string()),
+            created_date: Some(Utc.with_ymd_and_hms(2024, 5, day, 12, 0, 0).unwrap()),
+            response: None,
+        };
+        let reviews = vec![
+            review("1", 5, "USA", 10),
+            review("2", 2, "USA", 11),
+            review("3", 4, "DEU", 12),
+            review("4", 1, "DEU", 1),
+        ];
+
+        let since = Utc.with_ymd_and_hms(2024, 5, 5, 0, 0, 0).unwrap();
+        let summary = RatingsSummary::from_reviews(&reviews, Some(since), None);
+
+        assert_eq!(summary.overall.review_count, 3);
+        assert!((summary.overall.average_rating - 11.0 / 3.0).abs() < 1e-9);
+        let usa = summary.territory("USA").unwrap();
+        assert_eq!(usa.review_count, 2);
+        assert_eq!(usa.average_rating, 3.5);
+        assert_eq!(usa.distribution, [0, 1, 0, 0, 1]);
+        assert_eq!(summary.territory("DEU").unwrap().review_count, 1);
+        assert_eq!(summary.territories[0].territory, "DEU");
+    }
+
+    #[tokio::test]
+    async fn test_state_stores_and_review_fingerprint() {
+        use api::reviews::{CustomerReview, ReviewResponse};
+        use watcher::{review_fingerprint, FileStateStore, MemoryStateStore, StateStore};
+
+        let memory = MemoryStateStore::new();
+        assert_eq!(memory.get("review:1").await.unwrap(), None);
+        memory.set("review:1", "abc").await.unwrap();
+        assert_eq!(
+            memory.get("review:1").await.unwrap().as_deref(),
+            Some("abc")
+        );
+
+        let path = env::temp_dir().join(format!("asc-state-{}.json", uuid::Uuid::new_v4()));
+        let store = FileStateStore::open(&path).await.unwrap();
+        store.set("review:2", "def").await.unwrap();
+        let reopened = FileStateStore::open(&path).await.unwrap();
+        assert_eq!(
+            reopened.get("review:2").await.unwrap().as_deref(),
+            Some("def")
+        );
+        let _ = std::fs::remove_file(&path);
+
+        let mut review = CustomerReview {
+            id: "r1".to_string(),
+            rating: 3,
+            title: Some("OK".to_string()),
+            body: None,
+            reviewer_nickname: None,
+            territory: None,
+            created_date: None,
+            response: None,
+        };
+        let before = review_fingerprint(&review);
+        review.territory = Some("USA".to_string());
+        assert_eq!(review_fingerprint(&review), before);
+        review.response = Some(ReviewResponse {
+            id: "resp".to_string(),
+            response_body: "Thanks".to_string(),
+            last_modified_date: None,
+            state: None,
+        });
+        assert_ne!(review_fingerprint(&review), before);
+    }
+
+    #[test]
+    fn test_response_template_render() {
+        use api::reviews::{CustomerReview, ResponseTemplate, MAX_RESPONSE_LENGTH};
+
+        let mut review = CustomerReview {
+            id: "r1".to_string(),
+            rating: 5,
+            title: None,
+            body: None,
+            reviewer_nickname: Some("night owl".to_string()),
+            territory: Some("DEU".to_string()),
+            created_date: None,
+            response: None,
+        };
+        let template = ResponseTemplate::new(
+            "Thanks {reviewer_name} for the {rating} stars on {app_name}!",
+            "Sleep Loops",
+        )
+        .with_variant("DEU", "Danke {reviewer_name}!");
+
+        assert_eq!(template.render(&review).unwrap(), "Danke night owl!");
+
+        review.territory = Some("USA".to_string());
+        review.reviewer_nickname = None;
+        assert_eq!(
+            template.render(&review).unwrap(),
+            "Thanks there for the 5 stars on Sleep Loops!"
+        );
+
+        assert!(ResponseTemplate::new("Hi {name}", "App")
+            .render(&review)
+            .is_err());
+        let long = ResponseTemplate::new("x".repeat(MAX_RESPONSE_LENGTH + 1), "App");
+        assert!(long.render(&review).is_err());
+    }
+
+    #[test]
+    fn test_parse_sales_report_versions() {
+        use reports::parse_sales_report;
+
+        let current = "Provider\tProvider Country\tSKU\tDeveloper\tTitle\tVersion\tProduct Type Identifier\tUnits\tDeveloper Proceeds\tBegin Date\tEnd Date\tCustomer Currency\tCountry Code\tCurrency of Proceeds\tApple Identifier\tCustomer Price\tPromo Code\tParent Identifier\tSubscription\tPeriod\tCategory\tCMB\tDevice\tSupported Platforms\tProceeds Reason\tPreserved Pricing\tClient\tOrder Type\n\
+APPLE\tUS\tsleeploops\tEbowwa\tSleep Loops\t2.1\t1F\t3\t0.70\t05/01/2024\t05/01/2024\tUSD\tUS\tUSD\t6450000001\t0.99\t\t\t\t\tHealth & Fitness\t\tiPhone\tiOS\t\t\t\t\n\
+\n";
+        let rows = parse_sales_report(current).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].sku, "sleeploops");
+        assert_eq!(rows[0].units, 3);
+        assert!((rows[0].total_proceeds() - 2.1).abs() < 1e-9);
+        assert_eq!(
+            rows[0].begin_date,
+            chrono::NaiveDate::from_ymd_opt(2024, 5, 1)
+        );
+        assert_eq!(rows[0].device.as_deref(), Some("iPhone"));
+        assert_eq!(rows[0].promo_code, None);
+
+        let older = "SKU\tUnits\tDeveloper Proceeds (per item)\tCountry Code\r\nsleeploops\t-1\t0.70\tDE\r\n";
+        let rows = parse_sales_report(older).unwrap();
+        assert_eq!(rows[0].units, -1);
+        assert_eq!(rows[0].device, None);
+        assert_eq!(rows[0].country_code.as_deref(), Some("DE"));
+
+        assert!(parse_sales_report("Title\tUnits\n").is_err());
+        assert!(parse_sales_report("SKU\tUnits\tDeveloper Proceeds\nx\tmany\t1\n").is_err());
+    }
+
+    #[test]
+    fn test_analytics_report_models() {
+        use api::analytics_reports::{
+            AnalyticsAccessType, AnalyticsReport, AnalyticsReportSegment, ReportGranularity,
+        };
+
+        assert_eq!(
+            AnalyticsAccessType::OneTimeSnapshot.as_str(),
+            "ONE_TIME_SNAPSHOT"
+        );
+        assert_eq!(ReportGranularity::Weekly.as_str(), "WEEKLY");
+
+        let report = AnalyticsReport::from_value(&serde_json::json!({
+            "type": "analyticsReports",
+            "id": "rep1",
+            "attributes": { "name": "App Downloads Detailed", "category": "COMMERCE" }
+        }))
+        .unwrap();
+        assert_eq!(report.category.as_deref(), Some("COMMERCE"));
+
+        let segment = AnalyticsReportSegment::from_value(&serde_json::json!({
+            "type": "analyticsReportSegments",
+            "id": "seg1",
+            "attributes": { "url": "https://example.com/seg1", "checksum": "abc", "sizeInBytes": 1024 }
+        }))
+        .unwrap();
+        assert_eq!(segment.size_in_bytes, Some(1024));
+        assert!(AnalyticsReportSegment::from_value(&serde_json::json!({ "id": "seg2" })).is_none());
+    }
+
+    #[test]
+    fn test_report_dates_and_fiscal_calendar() {
+        use api::sales_reports::SalesReportFrequency;
+        use chrono::NaiveDate;
+        use reports::{fiscal_month_for, fiscal_month_range, fiscal_months_between, report_dates};
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            report_dates(
+                SalesReportFrequency::Weekly,
+                date(2024, 4, 30),
+                date(2024, 5, 6)
+            ),
+            vec!["2024-05-05", "2024-05-12"]
+        );
+        assert_eq!(
+            report_dates(
+                SalesReportFrequency::Monthly,
+                date(2024, 1, 15),
+                date(2024, 3, 1)
+            ),
+            vec!["2024-01", "2024-02", "2024-03"]
+        );
+        assert_eq!(
+            report_dates(
+                SalesReportFrequency::Daily,
+                date(2024, 2, 28),
+                date(2024, 3, 1)
+            )
+            .len(),
+            3
+        );
+
+        assert_eq!(
+            fiscal_month_range(2023, 10),
+            Some((date(2023, 10, 1), date(2023, 11, 4)))
+        );
+        assert_eq!(
+            fiscal_month_range(2024, 1),
+            Some((date(2023, 12, 31), date(2024, 2, 3)))
+        );
+        assert_eq!(fiscal_month_range(2024, 9).unwrap().1, date(2024, 9, 28));
+        assert_eq!(fiscal_month_for(date(2024, 2, 2)), (2024, 1));
+        assert_eq!(fiscal_month_for(date(2023, 12, 31)), (2024, 1));
+        assert_eq!(fiscal_month_for(date(2024, 9, 29)), (2024, 10));
+        assert_eq!(
+            fiscal_months_between(date(2024, 1, 1), date(2024, 3, 1)),
+            vec!["2024-01", "2024-02"]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_sales_dedupes_rows() {
+        use reports::{aggregate_sales, dedupe_rows, gunzip, parse_sales_report};
+        use std::io::Write;
+
+        let report = "SKU\tParent Identifier\tUnits\tDeveloper Proceeds\tCountry Code\tCurrency of Proceeds\n\
+sleeploops\t\t2\t0.70\tUS\tUSD\n\
+sleeploops\t\t1\t0.70\tUS\tUSD\n\
+premium\tsleeploops\t1\t3.50\tDE\tEUR\n";
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(report.as_bytes()).unwrap();
+        let decoded = gunzip(&encoder.finish().unwrap()).unwrap();
+        assert_eq!(decoded, report);
+
+        let mut rows = parse_sales_report(&decoded).unwrap();
+        rows.extend(parse_sales_report(report).unwrap());
+        let rows = dedupe_rows(rows);
+        assert_eq!(rows.len(), 3);
+
+        let totals = aggregate_sales(&rows);
+        assert_eq!(totals.len(), 2);
+        let us = totals.iter().find(|t| t.territory == "US").unwrap();
+        assert_eq!(us.units, 3);
+        assert!((us.proceeds - 2.1).abs() < 1e-9);
+        let de = totals.iter().find(|t| t.sku == "premium").unwrap();
+        assert_eq!(de.app_sku, "sleeploops");
+        assert_eq!(de.currency, "EUR");
+    }
+
+    #[tokio::test]
+    async fn test_sales_report_stream_concatenated_gzip() {
+        use reports::SalesReportStream;
+        use std::io::Write;
+
+        let report = "SKU\tUnits\tDeveloper Proceeds\tCountry Code\nsleeploops\t2\t0.70\tUS\n";
+        let mut data = Vec::new();
+        for _ in 0..3 {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(report.as_bytes()).unwrap();
+            data.extend(encoder.finish().unwrap());
+        }
+
+        let mut stream = SalesReportStream::from_reader(std::io::Cursor::new(data));
+        let mut units = 0;
+        let mut rows = 0;
+        while let Some(row) = stream.next().await {
+            units += row.unwrap().units;
+            rows += 1;
+        }
+        assert_eq!((rows, units), (3, 6));
+
+        let mut broken =
+            SalesReportStream::from_reader(std::io::Cursor::new(b"sleeploops\t2\t0.70\n".to_vec()));
+        assert!(broken.next().await.unwrap().is_err());
+        assert!(broken.next().await.is_none());
+    }
+
+    #[test]
+    fn test_team_user_roles() {
+        use api::users::{role_names, TeamUser, UserRole};
+
+        let user = TeamUser::from_value(&serde_json::json!({
+            "type": "users",
+            "id": "u1",
+            "attributes": {
+                "username": "jane@example.com",
+                "roles": ["APP_MANAGER", "CUSTOMER_SUPPORT", "SOMETHING_NEW"],
+                "allAppsVisible": false,
+                "provisioningAllowed": true
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            user.roles,
+            vec![UserRole::AppManager, UserRole::CustomerSupport]
+        );
+        assert!(user.has_role(UserRole::CustomerSupport));
+        assert!(!user.all_apps_visible);
+        assert!(user.provisioning_allowed);
+
+        assert_eq!(
+            role_names(&[UserRole::AccountHolder, UserRole::Finance]),
+            vec!["ACCOUNT_HOLDER", "FINANCE"]
+        );
+        assert!(UserRole::ALL
+            .iter()
+            .all(|role| UserRole::parse(role.as_str()) == Some(*role)));
+    }
+
+    #[test]
+    fn test_new_user_invitation_request() {
+        use api::user_invitations::NewUserInvitation;
+        use api::users::UserRole;
+
+        let invitation =
+            NewUserInvitation::new("pat@example.com", "Pat", "Partner", &[UserRole::Marketing])
+                .with_visible_apps(["app1", "app2"]);
+        assert!(invitation.validate().is_ok());
+
+        let request = invitation.to_request();
+        assert_eq!(
+            request["data"]["attributes"]["roles"],
+            serde_json::json!(["MARKETING"])
+        );
+        assert_eq!(request["data"]["attributes"]["allAppsVisible"], false);
+        assert_eq!(
+            request["data"]["relationships"]["visibleApps"]["data"][1]["id"],
+            "app2"
+        );
+
+        let everything =
+            NewUserInvitation::new("pat@example.com", "Pat", "Partner", &[UserRole::Developer]);
+        assert!(everything.to_request()["data"]
+            .get("relationships")
+            .is_none());
+
+        let mut invalid = NewUserInvitation::new("pat", "", "Partner", &[UserRole::AccountHolder]);
+        invalid.all_apps_visible = false;
+        match invalid.validate() {
+            Err(AppStoreConnectError::Validation(error)) => assert_eq!(error.issues.len(), 4),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bundle_id_identifiers() {
+        use api::bundle_ids::{validate_bundle_identifier, BundleId, BundleIdPlatform};
+
+        assert!(validate_bundle_identifier("com.example.sleep-loops").is_ok());
+        assert!(validate_bundle_identifier("com.example.*").is_ok());
+        assert!(validate_bundle_identifier("*").is_ok());
+        assert!(validate_bundle_identifier("").is_err());
+        assert!(validate_bundle_identifier("com..example").is_err());
+        assert!(validate_bundle_identifier("com.example.app_beta").is_err());
+        assert!(validate_bundle_identifier("com.*.app").is_err());
+
+        let bundle_id = BundleId::from_value(&serde_json::json!({
+            "type": "bundleIds",
+            "id": "B1",
+            "attributes": { "identifier": "com.example.*", "name": "Wildcard", "platform": "UNIVERSAL", "seedId": "TEAM123" }
+        }))
+        .unwrap();
+        assert_eq!(bundle_id.platform, Some(BundleIdPlatform::Universal));
+        assert!(bundle_id.is_wildcard());
+    }
+
+    #[test]
+    fn test_decode_certificate_serial_and_validity() {
+        use api::certificates::{csr_content, decode_certificate, Certificate};
+        use base64::Engine;
+
+        fn tlv(tag: u8, body: &[u8]) -> Vec<u8> {
+            let mut out = vec![tag];
+            if body.len() < 0x80 {
+                out.push(body.len() as u8);
+            } else {
+                out.extend([0x82, (body.len() >> 8) as u8, body.len() as u8]);
+            }
+            out.extend(body);
+            out
+        }
+
+        let validity = [tlv(0x17, b"240501120000Z"), tlv(0x18, b"20250501120000Z")].concat();
+        let tbs = [
+            tlv(0xA0, &tlv(0x02, &[2])),
+            tlv(0x02, &[0x00, 0x8F, 0x12, 0xAB]),
+            tlv(0x30, &[]),
+            tlv(0x30, &[0x05; 200]),
+            tlv(0x30, &validity),
+        ]
+        .concat();
+        let der = tlv(0x30, &tlv(0x30, &tbs));
+
+        let info = decode_certificate(&der).unwrap();
+        assert_eq!(info.serial_number, "8F12AB");
+        assert_eq!(info.not_before.to_rfc3339(), "2024-05-01T12:00:00+00:00");
+        assert_eq!(info.not_after.to_rfc3339(), "2025-05-01T12:00:00+00:00");
+        assert!(decode_certificate(&der[..der.len() - 4]).is_err());
+
+        let certificate = Certificate::from_value(&serde_json::json!({
+            "type": "certificates",
+            "id": "C1",
+            "attributes": {
+                "certificateType": "DISTRIBUTION",
+                "certificateContent": base64::engine::general_purpose::STANDARD.encode(&der)
+            }
+        }))
+        .unwrap();
+        assert_eq!(certificate.info().unwrap(), info);
+
+        let pem = "-----BEGIN CERTIFICATE REQUEST-----\nTUlJQ1\nZqQ0NB\n-----END CERTIFICATE REQUEST-----\n";
+        assert_eq!(csr_content(pem).unwrap(), "TUlJQ1ZqQ0NB");
+        assert!(csr_content("not a csr!").is_err());
+    }
+
+    #[test]
+    fn test_parse_device_list() {
+        use api::bundle_ids::BundleIdPlatform;
+        use api::devices::{parse_device_list, validate_udid};
+
+        assert!(validate_udid("00008120-001A2B3C4D5E6F70").is_ok());
+        assert!(validate_udid("a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2").is_ok());
+        assert!(validate_udid("not-a-udid").is_err());
+
+        let content = "Device ID\tDevice Name\tDevice Platform\n\
+00008120-001A2B3C4D5E6F70\tQA iPhone\tios\n\
+\n\
+# contractor laptops\n\
+12345678-90AB-CDEF-1234-567890ABCDEF\tQA Mac\tmac\n\
+a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2\n";
+        let devices = parse_device_list(content, BundleIdPlatform::Ios).unwrap();
+        assert_eq!(devices.len(), 3);
+        assert_eq!(devices[1].platform, BundleIdPlatform::MacOs);
+        assert_eq!(devices[2].name, devices[2].udid);
+        assert_eq!(devices[2].platform, BundleIdPlatform::Ios);
+
+        match parse_device_list(
+            "bogus\tName\n00008120-001A2B3C4D5E6F70\tX\ttvos\n",
+            BundleIdPlatform::Ios,
+        ) {
+            Err(AppStoreConnectError::Validation(error)) => assert_eq!(error.issues.len(), 2),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_provisioning_profile() {
+        use api::profiles::{decode_provisioning_profile, NewProfile, ProfileType};
+
+        let plist = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Name</key><string>SleepLoops Ad Hoc</string>
+    <key>UUID</key><string>6f1c3a2e-0000-4000-8000-000000000001</string>
+    <key>TeamIdentifier</key><array><string>ABCDE12345</string></array>
+    <key>CreationDate</key><date>2024-05-01T12:00:00Z</date>
+    <key>ExpirationDate</key><date>2025-05-01T12:00:00Z</date>
+    <key>ProvisionedDevices</key><array><string>00008120-001A2B3C4D5E6F70</string></array>
+    <key>DeveloperCertificates</key><array><data>bm90IGEgY2VydA==</data></array>
+    <key>Entitlements</key>
+    <dict>
+        <key>application-identifier</key><string>ABCDE12345.com.example.sleeploops</string>
+        <key>aps-environment</key><string>production</string>
+        <key>get-task-allow</key><false/>
+    </dict>
+</dict>
+</plist>"#;
+        // Stand-in for the CMS envelope around the plist
+        let mut data = vec![0x30, 0x80, 0x06, 0x09];
+        data.extend(plist.as_bytes());
+        data.extend([0xA0, 0x82, 0x00, 0x00]);
+
+        let info = decode_provisioning_profile(&data).unwrap();
+        assert_eq!(info.team_identifier.as_deref(), Some("ABCDE12345"));
+        assert_eq!(
+            info.application_identifier.as_deref(),
+            Some("ABCDE12345.com.example.sleeploops")
+        );
+        assert_eq!(
+            info.expiration_date.to_rfc3339(),
+            "2025-05-01T12:00:00+00:00"
+        );
+        assert_eq!(info.provisioned_devices.len(), 1);
+        assert!(info.developer_certificates.is_empty());
+        assert_eq!(
+            info.entitlement("get-task-allow"),
+            Some(&serde_json::json!(false))
+        );
+        assert!(decode_provisioning_profile(b"garbage").is_err());
+
+        let adhoc = NewProfile::new("Ad Hoc", ProfileType::IosAppAdhoc, "B1", &["C1"]);
+        assert!(adhoc.validate().is_err());
+        let adhoc = adhoc.with_devices(&["D1", "D2"]);
+        assert!(adhoc.validate().is_ok());
+        let request = adhoc.to_request();
+        assert_eq!(
+            request["data"]["relationships"]["devices"]["data"][1]["id"],
+            "D2"
+        );
+        assert_eq!(
+            request["data"]["relationships"]["bundleId"]["data"]["id"],
+            "B1"
+        );
+        assert!(
+            NewProfile::new("Store", ProfileType::IosAppStore, "B1", &["C1"])
+                .with_devices(&["D1"])
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_signing_audit_flags_expiry_and_revoked_certificates() {
+        use api::certificates::Certificate;
+        use api::profiles::Profile;
+        use base64::Engine;
+        use chrono::{TimeZone, Utc};
+        use signing::{SigningAssetKind, SigningAudit, SigningIssueKind};
+
+        fn tlv(tag: u8, body: &[u8]) -> Vec<u8> {
+            let mut out = vec![tag, body.len() as u8];
+            out.extend(body);
+            out
+        }
+        let certificate_der = |serial: u8| {
+            let validity = [tlv(0x17, b"240101000000Z"), tlv(0x17, b"250101000000Z")].concat();
+            let tbs = [
+                tlv(0x02, &[serial]),
+                tlv(0x30, &[]),
+                tlv(0x30, &[]),
+                tlv(0x30, &validity),
+            ]
+            .concat();
+            tlv(0x30, &tlv(0x30, &tbs))
+        };
+        let encode = |data: &[u8]| base64::engine::general_purpose::STANDARD.encode(data);
+        let profile = |id: &str, state: &str, expires: &str, serial: u8| {
+            let plist = format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><plist version=\"1.0\"><dict>\
+<key>Name</key><string>{id}</string><key>ExpirationDate</key><date>{expires}</date>\
+<key>DeveloperCertificates</key><array><data>{}</data></array></dict></plist>",
+                encode(&certificate_der(serial))
+            );
+            Profile::from_value(&serde_json::json!({
+                "id": id,
+                "attributes": { "name": id, "profileState": state, "profileContent": encode(plist.as_bytes()) }
+            }))
+            .unwrap()
+        };
+
+        let certificates = vec![
+            Certificate::from_value(&serde_json::json!({
+                "id": "C1",
+                "attributes": { "displayName": "Distribution", "serialNumber": "0A", "expirationDate": "2024-06-10T00:00:00.000+0000" }
+            }))
+            .unwrap(),
+            Certificate::from_value(&serde_json::json!({
+                "id": "C2",
+                "attributes": { "certificateContent": encode(&certificate_der(0x0B)) }
+            }))
+            .unwrap(),
+        ];
+        let profiles = vec![
+            profile("fine", "ACTIVE", "2025-01-01T00:00:00Z", 0x0A),
+            profile("soon", "ACTIVE", "2024-06-20T00:00:00Z", 0x0B),
+            profile("revoked", "INVALID", "2025-01-01T00:00:00Z", 0x0C),
+        ];
+
+        let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let audit = SigningAudit::from_assets(&certificates, &profiles, now, 30);
+        assert_eq!(audit.profiles_checked, 3);
+
+        let certificate_issues = audit.issues_for(SigningAssetKind::Certificate);
+        assert_eq!(certificate_issues.len(), 1);
+        assert_eq!(
+            certificate_issues[0].kind,
+            SigningIssueKind::ExpiringSoon { days_left: 9 }
+        );
+
+        let kinds: Vec<(&str, &SigningIssueKind)> = audit
+            .issues_for(SigningAssetKind::Profile)
+            .into_iter()
+            .map(|issue| (issue.id.as_str(), &issue.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("soon", &SigningIssueKind::ExpiringSoon { days_left: 19 }),
+                (
+                    "revoked",
+                    &SigningIssueKind::RevokedCertificate {
+                        serial_number: "0C".to_string()
+                    }
+                ),
+                ("revoked", &SigningIssueKind::Invalid),
+            ]
+        );
+        assert!(!audit.is_clean());
+    }
+
+    #[test]
+    fn test_game_center_detail_model() {
+        use api::game_center::GameCenterDetail;
+
+        let detail = GameCenterDetail::from_value(&serde_json::json!({
+            "type": "gameCenterDetails",
+            "id": "GC1",
+            "attributes": { "arcadeEnabled": false, "challengeEnabled": true },
+            "relationships": {
+                "gameCenterGroup": { "data": { "type": "gameCenterGroups", "id": "G1" } }
+            }
+        }))
+        .unwrap();
+        assert!(detail.challenge_enabled);
+        assert_eq!(detail.group_id.as_deref(), Some("G1"));
+
+        let ungrouped = GameCenterDetail::from_value(&serde_json::json!({
+            "id": "GC2",
+            "relationships": { "gameCenterGroup": { "data": null } }
+        }))
+        .unwrap();
+        assert!(!ungrouped.challenge_enabled);
+        assert!(ungrouped.group_id.is_none());
+    }
+
+    #[test]
+    fn test_game_center_localization_gaps() {
+        use api::game_center::{localization_gaps, GameCenterAsset, GameCenterAssetKind};
+
+        let included = vec![
+            serde_json::json!({ "type": "gameCenterAchievementLocalizations", "id": "L1", "attributes": { "locale": "en-US", "name": "Night Owl" } }),
+            serde_json::json!({ "type": "gameCenterAchievementLocalizations", "id": "L2", "attributes": { "locale": "de-DE", "name": " " } }),
+        ];
+        let asset = |id: &str, archived: bool, localizations: serde_json::Value| {
+            GameCenterAsset::from_value(
+                GameCenterAssetKind::Achievement,
+                &serde_json::json!({
+                    "id": id,
+                    "attributes": { "referenceName": id, "archived": archived },
+                    "relationships": { "localizations": { "data": localizations } }
+                }),
+                &included,
+            )
+            .unwrap()
+        };
+
+        let assets = vec![
+            asset(
+                "named",
+                false,
+                serde_json::json!([{ "id": "L1" }, { "id": "L2" }]),
+            ),
+            asset("unnamed", false, serde_json::json!([])),
+            asset("old", true, serde_json::json!([])),
+        ];
+        assert_eq!(assets[0].localized_names.len(), 1);
+
+        let gaps = localization_gaps(&assets, &[]);
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].asset_id, "unnamed");
+        assert!(gaps[0].missing_locales.is_empty());
+
+        let gaps = localization_gaps(&assets, &["en-US", "de-DE"]);
+        assert_eq!(gaps.len(), 2);
+        assert_eq!(gaps[0].missing_locales, vec!["de-DE"]);
+        assert_eq!(gaps[1].missing_locales, vec!["en-US", "de-DE"]);
+    }
+
+    #[test]
+    fn test_ci_build_run_and_test_summary() {
+        use api::ci::{
+            CiBuildRun, CiCompletionStatus, CiExecutionProgress, CiTestResult, CiTestSummary,
+        };
+
+        let run = CiBuildRun::from_value(&serde_json::json!({
+            "type": "ciBuildRuns",
+            "id": "R1",
+            "attributes": {
+                "number": 42,
+                "executionProgress": "COMPLETE",
+                "completionStatus": "FAILED",
+                "sourceCommit": { "commitSha": "abc123" }
+            }
+        }))
+        .unwrap();
+        assert!(run.is_complete());
+        assert_eq!(run.number, Some(42));
+        assert_eq!(run.completion_status, Some(CiCompletionStatus::Failed));
+        assert_eq!(run.source_commit.as_deref(), Some("abc123"));
+        assert_eq!(
+            CiExecutionProgress::parse("RUNNING"),
+            Some(CiExecutionProgress::Running)
+        );
+
+        let results: Vec<CiTestResult> = [
+            "SUCCESS",
+            "MIXED",
+            "FAILURE",
+            "SKIPPED",
+            "EXPECTED_FAILURE",
+        ]
+        .iter()
+        .enumerate()
+        .filter_map(|(i, status)| {
+            CiTestResult::from_value(
+                &serde_json::json!({ "id": i.to_string(), "attributes": { "status": status } }),
+            )
+        })
+        .collect();
+        let summary = CiTestSummary::from_results(&results);
+        assert_eq!((summary.passed, summary.failed, summary.skipped), (2, 2, 1));
+        assert_eq!(summary.total(), 5);
+    }
+
+    #[test]
+    fn test_ci_git_reference_and_run_outcome() {
+        use api::ci::{
+            CiBuildAction, CiBuildRun, CiCompletionStatus, CiGitReference, CiRunOutcome,
+        };
+
+        let reference = CiGitReference::from_value(&serde_json::json!({
+            "type": "scmGitReferences",
+            "id": "REF1",
+            "attributes": { "name": "main", "canonicalName": "refs/heads/main", "kind": "BRANCH", "isDeleted": false }
+        }))
+        .unwrap();
+        assert!(reference.matches("main"));
+        assert!(reference.matches("refs/heads/main"));
+        assert!(!reference.matches("develop"));
+
+        let action = |id: &str, status: &str| {
+            CiBuildAction::from_value(
+                &serde_json::json!({ "id": id, "attributes": { "completionStatus": status } }),
+            )
+            .unwrap()
+        };
+        let outcome = CiRunOutcome {
+            run: CiBuildRun::from_value(&serde_json::json!({ "id": "R1" })).unwrap(),
+            status: CiCompletionStatus::Failed,
+            actions: vec![
+                action("build", "SUCCEEDED"),
+                action("test", "FAILED"),
+                action("archive", "SKIPPED"),
+            ],
+        };
+        assert!(!outcome.is_success());
+        let failed: Vec<&str> = outcome
+            .failed_actions()
+            .iter()
+            .map(|a| a.id.as_str())
+            .collect();
+        assert_eq!(failed, vec!["test"]);
+    }
+
+    #[test]
+    fn test_alternative_distribution_key_and_webhook_validation() {
+        use api::alternative_distribution::{public_key_content, validate_webhook_url};
+
+        let pem = "-----BEGIN PUBLIC KEY-----\nMFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE\nq1/x+y2==\n-----END PUBLIC KEY-----\n";
+        assert_eq!(
+            public_key_content(pem).unwrap(),
+            "MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEq1/x+y2=="
+        );
+        assert!(
+            public_key_content("-----BEGIN PUBLIC KEY-----\n-----END PUBLIC KEY-----").is_err()
+        );
+        assert!(public_key_content("not base64!").is_err());
+
+        assert!(validate_webhook_url("https://store.example.eu/hooks").is_ok());
+        assert!(validate_webhook_url("http://store.example.eu/hooks").is_err());
+        assert!(validate_webhook_url("store.example.eu").is_err());
+    }
+
+    #[test]
+    fn test_age_rating_declaration_round_trip() {
+        use api::age_ratings::{
+            AgeRatingDeclaration, AgeRatingUpdate, ContentDescriptor, ContentLevel, KidsAgeBand,
+        };
+
+        let declaration = AgeRatingDeclaration::from_value(&serde_json::json!({
+            "type": "ageRatingDeclarations",
+            "id": "AR1",
+            "attributes": {
+                "violenceCartoonOrFantasy": "INFREQUENT_OR_MILD",
+                "contests": "NONE",
+                "gambling": false,
+                "kidsAgeBand": "NINE_TO_ELEVEN",
+                "unrestrictedWebAccess": null
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            declaration
+                .descriptors
+                .get(&ContentDescriptor::ViolenceCartoonOrFantasy),
+            Some(&ContentLevel::InfrequentOrMild)
+        );
+        assert_eq!(declaration.kids_age_band, Some(KidsAgeBand::NineToEleven));
+        assert!(declaration.is_made_for_kids());
+        assert_eq!(declaration.unanswered().len(), 11);
+        assert!(declaration.unanswered().contains(&"unrestrictedWebAccess"));
+
+        let mut update = AgeRatingUpdate::no_objectionable_content().with_descriptor(
+            ContentDescriptor::HorrorOrFearThemes,
+            ContentLevel::FrequentOrIntense,
+        );
+        update.kids_age_band = FieldUpdate::Clear;
+        let attributes = update.to_attributes();
+        assert_eq!(attributes["horrorOrFearThemes"], "FREQUENT_OR_INTENSE");
+        assert_eq!(attributes["contests"], "NONE");
+        assert_eq!(attributes["gambling"], false);
+        assert!(attributes["kidsAgeBand"].is_null());
+        assert!(attributes.get("ageRatingOverride").is_none());
+
+        let answered = AgeRatingDeclaration::from_value(&serde_json::json!({
+            "id": "AR2",
+            "attributes": attributes
+        }))
+        .unwrap();
+        assert!(answered.unanswered().is_empty());
+        assert!(!answered.is_made_for_kids());
+    }
+
+    #[test]
+    fn test_pipeline_report_resume_and_review_detail() {
+        use api::review_attachments::ReviewDetail;
+        use pipeline::{PipelineStep, PipelineStepResult, StepStatus};
+
+        let step = |step, status| PipelineStepResult { step, status };
+        let report = PipelineReport {
+            app_id: "123".to_string(),
+            version_string: "2.0".to_string(),
+            dry_run: false,
+            version_id: Some("V1".to_string()),
+            build_id: Some("B1".to_string()),
+            steps: vec![
+                step(
+                    PipelineStep::PrepareVersion,
+                    StepStatus::Done("Created version 2.0".to_string()),
+                ),
+                step(
+                    PipelineStep::AttachBuild,
+                    StepStatus::AlreadyDone("Build 42 is already attached".to_string()),
+                ),
+                step(
+                    PipelineStep::Localizations,
+                    StepStatus::Failed("fr-FR: too long".to_string()),
+                ),
+                step(
+                    PipelineStep::ReviewDetails,
+                    StepStatus::Skipped("No review details provided".to_string()),
+                ),
+            ],
+        };
+        assert!(report.is_completed(PipelineStep::PrepareVersion));
+        assert!(report.is_completed(PipelineStep::AttachBuild));
+        assert!(!report.is_completed(PipelineStep::Localizations));
+        assert!(!report.is_completed(PipelineStep::ReviewDetails));
+        assert!(!report.is_completed(PipelineStep::Submit));
+        assert_eq!(
+            report.failure().map(|f| f.step),
+            Some(PipelineStep::Localizations)
+        );
+        assert!(!report.is_success());
+
+        let restored: PipelineReport =
+            serde_json::from_str(&serde_json::to_string(&report).unwrap()).unwrap();
+        assert_eq!(restored, report);
+
+        let dry = PipelineReport {
+            dry_run: true,
+            ..report
+        };
+        assert!(!dry.is_completed(PipelineStep::PrepareVersion));
+
+        let detail = ReviewDetail {
+            contact_email: Some("review@example.com".to_string()),
+            demo_account_required: Some(true),
+            demo_account_name: Some("demo".to_string()),
+            ..Default::default()
+        };
+        let attributes = detail.to_attributes();
+        assert_eq!(attributes["contactEmail"], "review@example.com");
+        assert_eq!(attributes["demoAccountRequired"], true);
+        assert!(attributes.get("notes").is_none());
+        assert!(!detail.has_demo_account());
+        assert_eq!(
+            ReviewDetail::from_value(&serde_json::json!({ "attributes": attributes })),
+            detail
+        );
+    }
+
+    #[test]
+    fn test_github_actions_annotations_and_summary() {
+        use bulk::BulkResult;
+        use github_actions::Annotation;
+        use pipeline::{PipelineStep, PipelineStepResult, StepStatus};
+
+        assert_eq!(
+            Annotation::error("Release 2.0: submit", "50% done\nthen failed").to_string(),
+            "::error title=Release 2.0%3A submit::50%25 done%0Athen failed"
+        );
+        assert_eq!(
+            Annotation::warning("Profile a,b", "soon").to_string(),
+            "::warning title=Profile a%2Cb::soon"
+        );
+
+        let report = PipelineReport {
+            app_id: "123".to_string(),
+            version_string: "2.0".to_string(),
+            steps: vec![
+                PipelineStepResult {
+                    step: PipelineStep::PrepareVersion,
+                    status: StepStatus::Done("Created version 2.0".to_string()),
+                },
+                PipelineStepResult {
+                    step: PipelineStep::VerifyScreenshots,
+                    status: StepStatus::Failed("Missing screenshots (en-US | fr-FR)".to_string()),
+                },
+            ],
+            ..Default::default()
+        };
+        let annotations = report.annotations();
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(
+            annotations[0].title.as_deref(),
+            Some("Release 2.0: verify screenshots")
+        );
+        let summary = report.step_summary();
+        assert!(summary.starts_with("### Release 2.0\n"));
+        assert!(summary.contains("| prepare version | ✅ done | Created version 2.0 |"));
+        assert!(summary.contains("Missing screenshots (en-US \\| fr-FR)"));
+
+        let results = vec![
+            BulkResult::failed("de-DE", "Subtitle exceeds 30 characters"),
+            BulkResult::from_result(
+                "en-US",
+                bulk::BulkAction::Updated,
+                Ok(serde_json::json!({})),
+            ),
+        ];
+        let annotations = results.as_slice().annotations();
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].message, "Subtitle exceeds 30 characters");
+        assert!(results
+            .as_slice()
+            .step_summary()
+            .contains("1 updated, 1 failed"));
+
+        let submission =
+            api::review_submissions::ReviewSubmission::from_value(&serde_json::json!({
+                "type": "reviewSubmissions",
+                "id": "sub-1",
+                "attributes": { "state": "UNRESOLVED_ISSUES", "platform": "IOS" }
+            }))
+            .unwrap();
+        let annotations = submission.annotations();
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].level, github_actions::AnnotationLevel::Error);
+        assert!(submission
+            .step_summary()
+            .contains("| sub-1 | IOS | ❌ UNRESOLVED_ISSUES |"));
+
+        let waiting = api::review_submissions::ReviewSubmission {
+            state: "WAITING_FOR_REVIEW".to_string(),
+            ..submission
+        };
+        assert!(waiting.annotations().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_event_state_survives_store_reopen() {
+        use events::state_transition;
+        use watcher::FileStateStore;
+
+        let path = std::env::temp_dir().join(format!("asc-events-{}.json", uuid::Uuid::new_v4()));
+        let store = FileStateStore::open(&path).await.unwrap();
+        assert_eq!(
+            state_transition(&store, "build:B1", "PROCESSING")
+                .await
+                .unwrap(),
+            Some(None)
+        );
+        assert_eq!(
+            state_transition(&store, "build:B1", "PROCESSING")
+                .await
+                .unwrap(),
+            None
+        );
+        drop(store);
+
+        let reopened = FileStateStore::open(&path).await.unwrap();
+        assert_eq!(
+            state_transition(&reopened, "build:B1", "PROCESSING")
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            state_transition(&reopened, "build:B1", "VALID")
+                .await
+                .unwrap(),
+            Some(Some("PROCESSING".to_string()))
+        );
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        let initial = AppEvent::BuildProcessingChanged {
+            app_id: "123".to_string(),
+            build_id: "B1".to_string(),
+            build_version: Some("42".to_string()),
+            from: None,
+            to: "VALID".to_string(),
+        };
+        assert!(initial.is_initial());
+        assert_eq!(initial.new_state(), Some("VALID"));
+        assert_eq!(Subscription::customer_reviews("123").app_id(), "123");
+
+        #[cfg(feature = "sqlite")]
+        {
+            let store = SqliteStateStore::in_memory().unwrap();
+            assert_eq!(store.get("version:V1").await.unwrap(), None);
+            store.set("version:V1", "IN_REVIEW").await.unwrap();
+            store.set("version:V1", "READY_FOR_SALE").await.unwrap();
+            assert_eq!(
+                store.get("version:V1").await.unwrap().as_deref(),
+                Some("READY_FOR_SALE")
+            );
+        }
+    }
+
+    #[test]
+    fn test_lint_flags_common_rejection_causes() {
+        use api::review_attachments::ReviewDetail;
+        use lint::{LintInput, LintRule, Severity};
+        use serde_json::json;
+
+        let input = LintInput {
+            app_id: "123".to_string(),
+            platform: "IOS".to_string(),
+            primary_locale: Some("en-US".to_string()),
+            app_info_localizations: vec![
+                json!({ "attributes": {
+                    "locale": "en-US",
+                    "name": "Todo Buddy",
+                    "privacyPolicyUrl": "https://example.com/privacy"
+                }}),
+                json!({ "attributes": { "locale": "de-DE", "name": "Todo Buddy" }}),
+            ],
+            version_id: Some("V1".to_string()),
+            version_localizations: vec![
+                json!({ "attributes": {
+                    "locale": "en-US",
+                    "description": "A simple todo list. Also on Android!",
+                    "whatsNew": "TODO"
+                }}),
+                json!({ "attributes": {
+                    "locale": "de-DE",
+                    "description": "Lorem ipsum dolor sit amet"
+                }}),
+            ],
+            screenshot_display_types: [
+                (
+                    "en-US".to_string(),
+                    vec!["APP_IPHONE_67".to_string(), "APP_IPAD_PRO_129".to_string()],
+                ),
+                ("de-DE".to_string(), vec!["APP_IPHONE_61".to_string()]),
+            ]
+            .into_iter()
+            .collect(),
+            review_detail: Some(ReviewDetail {
+                demo_account_required: Some(true),
+                demo_account_name: Some("reviewer".to_string()),
+                ..Default::default()
+            }),
+        };
+
+        let report = LintReport::from_input(&input);
+        let placeholders = report.findings_for(LintRule::PlaceholderText);
+        assert_eq!(placeholders.len(), 2);
+        assert!(placeholders.iter().any(
+            |f| f.locale.as_deref() == Some("en-US") && f.field.as_deref() == Some("whatsNew")
+        ));
+        assert!(placeholders
+            .iter()
+            .any(|f| f.locale.as_deref() == Some("de-DE") && f.message.contains("lorem ipsum")));
+
+        let platforms = report.findings_for(LintRule::OtherPlatformMention);
+        assert_eq!(platforms.len(), 1);
+        assert_eq!(platforms[0].field.as_deref(), Some("description"));
+
+        let privacy = report.findings_for(LintRule::MissingPrivacyPolicyUrl);
+        assert_eq!(privacy.len(), 1);
+        assert_eq!(privacy[0].severity, Severity::Warning);
+
+        assert_eq!(report.findings_for(LintRule::MissingDemoAccount).len(), 1);
+
+        let screenshots: Vec<String> = report
+            .findings_for(LintRule::MissingScreenshots)
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        assert_eq!(
+            screenshots,
+            vec![
+                "error [missing-screenshots] de-DE: iPhone screenshots must include APP_IPHONE_67 or APP_IPHONE_65",
+                "error [missing-screenshots] de-DE: No iPad screenshots",
+            ]
+        );
+        assert!(report.has_errors());
+        assert_eq!(report.warnings().len(), 1);
+    }
 }
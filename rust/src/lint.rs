@@ -0,0 +1,444 @@
+use crate::api::review_attachments::ReviewDetail;
+use crate::client::Client;
+use crate::error::AppStoreConnectError;
+use crate::snapshot::EDITABLE_VERSION_STATES;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+const APP_INFO_FIELDS: &[&str] = &["name", "subtitle", "privacyPolicyText"];
+const VERSION_FIELDS: &[&str] = &["description", "keywords", "promotionalText", "whatsNew"];
+
+// Matched case-insensitively on word boundaries.
+const PLACEHOLDER_PHRASES: &[&str] = &[
+    "lorem ipsum",
+    "placeholder",
+    "your text here",
+    "description here",
+    "insert description",
+    "coming soon",
+];
+// Only flagged in capitals, so "a simple todo list" doesn't trip the check.
+const PLACEHOLDER_MARKERS: &[&str] = &["TODO", "TBD", "FIXME", "XXX"];
+
+const OTHER_PLATFORMS: &[&str] = &[
+    "android",
+    "google play",
+    "play store",
+    "windows phone",
+    "blackberry",
+    "appgallery",
+    "galaxy store",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LintRule {
+    PlaceholderText,
+    MissingPrivacyPolicyUrl,
+    OtherPlatformMention,
+    MissingDemoAccount,
+    MissingScreenshots,
+}
+
+impl LintRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintRule::PlaceholderText => "placeholder-text",
+            LintRule::MissingPrivacyPolicyUrl => "missing-privacy-policy-url",
+            LintRule::OtherPlatformMention => "other-platform-mention",
+            LintRule::MissingDemoAccount => "missing-demo-account",
+            LintRule::MissingScreenshots => "missing-screenshots",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintFinding {
+    pub rule: LintRule,
+    pub severity: Severity,
+    pub locale: Option<String>,
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{} [{}]", severity, self.rule.as_str())?;
+        if let Some(locale) = &self.locale {
+            write!(f, " {}", locale)?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, " {}", field)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LintInput {
+    pub app_id: String,
+    pub platform: String,
+    pub primary_locale: Option<String>,
+    pub app_info_localizations: Vec<Value>,
+    pub version_id: Option<String>,
+    pub version_localizations: Vec<Value>,
+    pub screenshot_display_types: BTreeMap<String, Vec<String>>,
+    pub review_detail: Option<ReviewDetail>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintReport {
+    pub app_id: String,
+    pub version_id: Option<String>,
+    pub findings: Vec<LintFinding>,
+}
+
+impl LintReport {
+    pub async fn run(client: &Client, app_id: &str) -> Result<Self, AppStoreConnectError> {
+        let app = client.apps().get_app(app_id).await?;
+        let mut input = LintInput {
+            app_id: app_id.to_string(),
+            platform: "IOS".to_string(),
+            primary_locale: attribute(&app, "primaryLocale").map(String::from),
+            ..Default::default()
+        };
+
+        let app_infos = client.apps().get_app_infos(app_id).await?;
+        let app_info = app_infos
+            .iter()
+            .find(|info| attribute(info, "appStoreState") != Some("READY_FOR_SALE"))
+            .or_else(|| app_infos.first());
+        if let Some(app_info_id) = app_info.and_then(|i| i.get("id")).and_then(|i| i.as_str()) {
+            input.app_info_localizations = client.localizations().get_all(app_info_id).await?;
+        }
+
+        let versions = client.versions().get_all(app_id).await?;
+        let version = versions.iter().find(|v| {
+            attribute(v, "appStoreState").is_some_and(|s| EDITABLE_VERSION_STATES.contains(&s))
+        });
+        let version = match version {
+            Some(version) => Some(version.clone()),
+            None => client.versions().get_current(app_id).await?,
+        };
+
+        if let Some(version) = version {
+            let version_id = version
+                .get("id")
+                .and_then(|i| i.as_str())
+                .unwrap_or_default()
+                .to_string();
+            if let Some(platform) = attribute(&version, "platform") {
+                input.platform = platform.to_string();
+            }
+
+            input.version_localizations =
+                client.version_localizations().get_all(&version_id).await?;
+            for localization in &input.version_localizations {
+                let (Some(localization_id), Some(locale)) = (
+                    localization.get("id").and_then(|i| i.as_str()),
+                    attribute(localization, "locale"),
+                ) else {
+                    continue;
+                };
+
+                let display_types = client
+                    .media()
+                    .get_screenshots(localization_id, None)
+                    .await?
+                    .iter()
+                    .filter_map(|s| s.get("displayType").and_then(|d| d.as_str()))
+                    .map(String::from)
+                    .collect();
+                input
+                    .screenshot_display_types
+                    .insert(locale.to_string(), display_types);
+            }
+
+            input.review_detail = client
+                .review_attachments()
+                .get_review_detail(&version_id)
+                .await?
+                .map(|detail| ReviewDetail::from_value(&detail));
+            input.version_id = Some(version_id);
+        }
+
+        Ok(Self::from_input(&input))
+    }
+
+    pub fn from_input(input: &LintInput) -> Self {
+        let mut findings = Vec::new();
+
+        let localized = input
+            .app_info_localizations
+            .iter()
+            .map(|l| (l, APP_INFO_FIELDS))
+            .chain(
+                input
+                    .version_localizations
+                    .iter()
+                    .map(|l| (l, VERSION_FIELDS)),
+            );
+        for (localization, fields) in localized {
+            let locale = attribute(localization, "locale").map(String::from);
+            for field in fields {
+                if let Some(text) = attribute(localization, field) {
+                    check_text(&mut findings, locale.as_deref(), field, text);
+                }
+            }
+        }
+
+        check_privacy_policy(&mut findings, input);
+        check_demo_account(&mut findings, input.review_detail.as_ref());
+        check_screenshots(&mut findings, input);
+
+        findings.sort_by(|a, b| {
+            (a.severity, &a.locale, &a.field).cmp(&(b.severity, &b.locale, &b.field))
+        });
+
+        Self {
+            app_id: input.app_id.clone(),
+            version_id: input.version_id.clone(),
+            findings,
+        }
+    }
+
+    pub fn errors(&self) -> Vec<&LintFinding> {
+        self.with_severity(Severity::Error)
+    }
+
+    pub fn warnings(&self) -> Vec<&LintFinding> {
+        self.with_severity(Severity::Warning)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors().is_empty()
+    }
+
+    pub fn findings_for(&self, rule: LintRule) -> Vec<&LintFinding> {
+        self.findings.iter().filter(|f| f.rule == rule).collect()
+    }
+
+    fn with_severity(&self, severity: Severity) -> Vec<&LintFinding> {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == severity)
+            .collect()
+    }
+}
+
+fn finding(
+    rule: LintRule,
+    severity: Severity,
+    locale: Option<&str>,
+    field: Option<&str>,
+    message: String,
+) -> LintFinding {
+    LintFinding {
+        rule,
+        severity,
+        locale: locale.map(String::from),
+        field: field.map(String::from),
+        message,
+    }
+}
+
+fn check_text(findings: &mut Vec<LintFinding>, locale: Option<&str>, field: &str, text: &str) {
+    let words = words(text);
+    let lowercase: Vec<String> = words.iter().map(|w| w.to_lowercase()).collect();
+
+    let placeholder = PLACEHOLDER_PHRASES
+        .iter()
+        .copied()
+        .find(|phrase| contains_phrase(&lowercase, phrase))
+        .or_else(|| {
+            PLACEHOLDER_MARKERS
+                .iter()
+                .copied()
+                .find(|marker| words.contains(marker))
+        });
+    if let Some(placeholder) = placeholder {
+        findings.push(finding(
+            LintRule::PlaceholderText,
+            Severity::Error,
+            locale,
+            Some(field),
+            format!("Contains placeholder text \"{}\"", placeholder),
+        ));
+    }
+
+    if let Some(platform) = OTHER_PLATFORMS
+        .iter()
+        .find(|platform| contains_phrase(&lowercase, platform))
+    {
+        findings.push(finding(
+            LintRule::OtherPlatformMention,
+            Severity::Error,
+            locale,
+            Some(field),
+            format!("Mentions another platform (\"{}\")", platform),
+        ));
+    }
+}
+
+fn words(text: &str) -> Vec<&str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+fn contains_phrase(words: &[String], phrase: &str) -> bool {
+    let phrase: Vec<&str> = phrase.split(' ').collect();
+    words
+        .windows(phrase.len())
+        .any(|window| window.iter().zip(&phrase).all(|(w, p)| w == p))
+}
+
+// A privacy policy URL is required for every app. The primary locale must
+// have one; other locales fall back to it but usually shouldn't.
+fn check_privacy_policy(findings: &mut Vec<LintFinding>, input: &LintInput) {
+    let missing: Vec<&str> = input
+        .app_info_localizations
+        .iter()
+        .filter(|l| attribute(l, "privacyPolicyUrl").is_none_or(|url| url.trim().is_empty()))
+        .filter_map(|l| attribute(l, "locale"))
+        .collect();
+    let none_set = missing.len() == input.app_info_localizations.len();
+
+    for locale in missing {
+        let required = none_set || input.primary_locale.as_deref() == Some(locale);
+        findings.push(finding(
+            LintRule::MissingPrivacyPolicyUrl,
+            if required {
+                Severity::Error
+            } else {
+                Severity::Warning
+            },
+            Some(locale),
+            Some("privacyPolicyUrl"),
+            "No privacy policy URL".to_string(),
+        ));
+    }
+}
+
+fn check_demo_account(findings: &mut Vec<LintFinding>, detail: Option<&ReviewDetail>) {
+    let Some(detail) = detail else {
+        return;
+    };
+
+    if detail.demo_account_required == Some(true) && !detail.has_demo_account() {
+        findings.push(finding(
+            LintRule::MissingDemoAccount,
+            Severity::Error,
+            None,
+            Some("demoAccountName"),
+            "Sign-in is required but no demo account name and password are provided".to_string(),
+        ));
+    }
+}
+
+struct DeviceFamily {
+    name: &'static str,
+    prefix: &'static str,
+    // App Store Connect scales these down for smaller devices, so one of them
+    // must be present.
+    required: &'static [&'static str],
+}
+
+const DEVICE_FAMILIES: &[DeviceFamily] = &[
+    DeviceFamily {
+        name: "iPhone",
+        prefix: "APP_IPHONE_",
+        required: &["APP_IPHONE_67", "APP_IPHONE_65"],
+    },
+    DeviceFamily {
+        name: "iPad",
+        prefix: "APP_IPAD_",
+        required: &["APP_IPAD_PRO_3GEN_129", "APP_IPAD_PRO_129"],
+    },
+    DeviceFamily {
+        name: "Mac",
+        prefix: "APP_DESKTOP",
+        required: &["APP_DESKTOP"],
+    },
+    DeviceFamily {
+        name: "Apple TV",
+        prefix: "APP_APPLE_TV",
+        required: &["APP_APPLE_TV"],
+    },
+    DeviceFamily {
+        name: "Apple Vision Pro",
+        prefix: "APP_APPLE_VISION_PRO",
+        required: &["APP_APPLE_VISION_PRO"],
+    },
+];
+
+// Supported devices aren't exposed by the API, so a device family counts as
+// supported when the platform implies it or any locale has screenshots for it.
+fn check_screenshots(findings: &mut Vec<LintFinding>, input: &LintInput) {
+    let mut supported: BTreeSet<&str> = input
+        .screenshot_display_types
+        .values()
+        .flatten()
+        .filter_map(|display_type| {
+            DEVICE_FAMILIES
+                .iter()
+                .find(|family| display_type.starts_with(family.prefix))
+                .map(|family| family.name)
+        })
+        .collect();
+    supported.insert(match input.platform.as_str() {
+        "MAC_OS" => "Mac",
+        "TV_OS" => "Apple TV",
+        "VISION_OS" => "Apple Vision Pro",
+        _ => "iPhone",
+    });
+
+    for (locale, display_types) in &input.screenshot_display_types {
+        for family in DEVICE_FAMILIES
+            .iter()
+            .filter(|family| supported.contains(family.name))
+        {
+            let has_any = display_types.iter().any(|d| d.starts_with(family.prefix));
+            let has_required = display_types
+                .iter()
+                .any(|d| family.required.contains(&d.as_str()));
+
+            let message = if !has_any {
+                format!("No {} screenshots", family.name)
+            } else if !has_required {
+                format!(
+                    "{} screenshots must include {}",
+                    family.name,
+                    family.required.join(" or ")
+                )
+            } else {
+                continue;
+            };
+            findings.push(finding(
+                LintRule::MissingScreenshots,
+                Severity::Error,
+                Some(locale),
+                None,
+                message,
+            ));
+        }
+    }
+}
+
+fn attribute<'a>(resource: &'a Value, key: &str) -> Option<&'a str> {
+    resource
+        .get("attributes")
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+}
@@ -0,0 +1,506 @@
+use crate::api::localizations::AppInfoLocalizationUpdate;
+use crate::api::version_localizations::VersionLocalizationUpdate;
+use crate::client::Client;
+use crate::csv::{parse_csv, push_csv_row};
+use crate::diff::{DiffScope, SnapshotDiff};
+use crate::error::{AppStoreConnectError, ValidationError};
+use crate::snapshot::{
+    index_by_locale, resource_id, string_attribute, ApplyOutcome, ApplyReport, FieldMap,
+    APP_INFO_LOCALIZATION_FIELDS, EDITABLE_VERSION_STATES, VERSION_LOCALIZATION_FIELDS,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+const CSV_HEADER: [&str; 4] = ["locale", "scope", "field", "value"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoreListing {
+    pub app_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exported_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_info_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<ListingVersion>,
+    #[serde(default)]
+    pub locales: BTreeMap<String, LocaleListing>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ListingVersion {
+    pub id: String,
+    pub version_string: String,
+    pub platform: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct LocaleListing {
+    #[serde(default, skip_serializing_if = "FieldMap::is_empty")]
+    pub app_info: FieldMap,
+    #[serde(default, skip_serializing_if = "FieldMap::is_empty")]
+    pub version: FieldMap,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    pub dry_run: bool,
+    pub only_changed: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            only_changed: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub diff: SnapshotDiff,
+    pub report: ApplyReport,
+}
+
+impl StoreListing {
+    pub fn from_localizations(
+        app_id: &str,
+        app_info_id: Option<String>,
+        version: Option<ListingVersion>,
+        app_info_localizations: &[Value],
+        version_localizations: &[Value],
+    ) -> Self {
+        let mut locales: BTreeMap<String, LocaleListing> = BTreeMap::new();
+
+        for localization in app_info_localizations {
+            if let Some(locale) = string_attribute(localization, "locale") {
+                locales.entry(locale).or_default().app_info =
+                    listing_fields(localization, APP_INFO_LOCALIZATION_FIELDS);
+            }
+        }
+        for localization in version_localizations {
+            if let Some(locale) = string_attribute(localization, "locale") {
+                locales.entry(locale).or_default().version =
+                    listing_fields(localization, VERSION_LOCALIZATION_FIELDS);
+            }
+        }
+
+        Self {
+            app_id: app_id.to_string(),
+            exported_at: Some(Utc::now()),
+            app_info_id,
+            version,
+            locales,
+        }
+    }
+
+    pub async fn capture(client: &Client, app_id: &str) -> Result<Self, AppStoreConnectError> {
+        let app_infos = client.apps().get_app_infos(app_id).await?;
+        let app_info = app_infos
+            .iter()
+            .find(|info| {
+                string_attribute(info, "appStoreState").as_deref() != Some("READY_FOR_SALE")
+            })
+            .or_else(|| app_infos.first());
+
+        let (app_info_id, app_info_localizations) = match app_info {
+            Some(app_info) => {
+                let id = resource_id(app_info)?;
+                let localizations = client.localizations().get_all(&id).await?;
+                (Some(id), localizations)
+            }
+            None => (None, Vec::new()),
+        };
+
+        let versions = client.versions().get_all(app_id).await?;
+        let editable = versions.iter().find(|version| {
+            string_attribute(version, "appStoreState")
+                .is_some_and(|state| EDITABLE_VERSION_STATES.contains(&state.as_str()))
+        });
+        let version = match editable {
+            Some(version) => Some(version.clone()),
+            None => client.versions().get_current(app_id).await?,
+        };
+
+        let (version, version_localizations) = match version {
+            Some(version) => {
+                let id = resource_id(&version)?;
+                let localizations = client.version_localizations().get_all(&id).await?;
+                let listing_version = ListingVersion {
+                    version_string: string_attribute(&version, "versionString").unwrap_or_default(),
+                    platform: string_attribute(&version, "platform").unwrap_or_default(),
+                    state: string_attribute(&version, "appStoreState"),
+                    id,
+                };
+                (Some(listing_version), localizations)
+            }
+            None => (None, Vec::new()),
+        };
+
+        Ok(Self::from_localizations(
+            app_id,
+            app_info_id,
+            version,
+            &app_info_localizations,
+            &version_localizations,
+        ))
+    }
+
+    pub fn diff(&self, desired: &StoreListing) -> SnapshotDiff {
+        let mut diff = SnapshotDiff::default();
+
+        let (live_info, desired_info) = self.managed_fields(desired, |l| &l.app_info);
+        diff.diff_locales(&live_info, &desired_info, |locale| {
+            DiffScope::AppInfoLocalization {
+                locale: locale.to_string(),
+            }
+        });
+
+        let (live_version, desired_version) = self.managed_fields(desired, |l| &l.version);
+        let (platform, version_string) = self
+            .version
+            .as_ref()
+            .map(|v| (v.platform.clone(), v.version_string.clone()))
+            .unwrap_or_default();
+        diff.diff_locales(&live_version, &desired_version, |locale| {
+            DiffScope::VersionLocalization {
+                platform: platform.clone(),
+                version_string: version_string.clone(),
+                locale: locale.to_string(),
+            }
+        });
+
+        diff
+    }
+
+    fn managed_fields(
+        &self,
+        desired: &StoreListing,
+        fields: impl Fn(&LocaleListing) -> &FieldMap,
+    ) -> (BTreeMap<String, FieldMap>, BTreeMap<String, FieldMap>) {
+        let mut live = BTreeMap::new();
+        let mut wanted = BTreeMap::new();
+
+        for (locale, listing) in &desired.locales {
+            let desired_fields = fields(listing);
+            if desired_fields.is_empty() {
+                continue;
+            }
+
+            let live_fields = self
+                .locales
+                .get(locale)
+                .map(|l| {
+                    fields(l)
+                        .iter()
+                        .filter(|(key, _)| desired_fields.contains_key(*key))
+                        .map(|(key, value)| (key.clone(), value.clone()))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            live.insert(locale.clone(), live_fields);
+            wanted.insert(locale.clone(), desired_fields.clone());
+        }
+
+        (live, wanted)
+    }
+
+    // The fields `import` sends for each locale in one scope: everything in the
+    // file, or with `only_changed` just the fields the diff found different, so
+    // unchanged fields are never rewritten.
+    pub(crate) fn import_fields(
+        &self,
+        diff: &SnapshotDiff,
+        only_changed: bool,
+        app_info: bool,
+    ) -> BTreeMap<String, FieldMap> {
+        let mut changed: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for change in &diff.changes {
+            match (&change.scope, app_info) {
+                (DiffScope::AppInfoLocalization { locale }, true)
+                | (DiffScope::VersionLocalization { locale, .. }, false) => {
+                    changed
+                        .entry(locale.as_str())
+                        .or_default()
+                        .insert(change.field.as_str());
+                }
+                _ => {}
+            }
+        }
+
+        self.locales
+            .iter()
+            .map(|(locale, l)| (locale, if app_info { &l.app_info } else { &l.version }))
+            .filter_map(|(locale, fields)| {
+                let fields: FieldMap = if only_changed {
+                    let changed = changed.get(locale.as_str())?;
+                    fields
+                        .iter()
+                        .filter(|(field, _)| changed.contains(field.as_str()))
+                        .map(|(field, value)| (field.clone(), value.clone()))
+                        .collect()
+                } else {
+                    fields.clone()
+                };
+                (!fields.is_empty()).then(|| (locale.clone(), fields))
+            })
+            .collect()
+    }
+
+    pub async fn import(
+        &self,
+        client: &Client,
+        app_id: &str,
+        options: &ImportOptions,
+    ) -> Result<ImportReport, AppStoreConnectError> {
+        let live = StoreListing::capture(client, app_id).await?;
+        let diff = live.diff(self);
+        let mut report = ApplyReport {
+            dry_run: options.dry_run,
+            results: Vec::new(),
+        };
+
+        let app_info_locales = self.import_fields(&diff, options.only_changed, true);
+        if !app_info_locales.is_empty() {
+            match &live.app_info_id {
+                Some(app_info_id) => {
+                    let existing =
+                        index_by_locale(client.localizations().get_all(app_info_id).await?);
+
+                    for (locale, fields) in app_info_locales {
+                        let resource = format!("appInfoLocalization:{}", locale);
+                        let field = |name: &str| fields.get(name).and_then(|v| v.as_str());
+
+                        let result = match existing.get(&locale) {
+                            Some(_) if options.dry_run => Ok(ApplyOutcome::Updated),
+                            None if options.dry_run => Ok(ApplyOutcome::Created),
+                            Some((localization_id, _)) => client
+                                .localizations()
+                                .update_fields(
+                                    localization_id,
+                                    &AppInfoLocalizationUpdate::from_value(&field_object(&fields)),
+                                )
+                                .await
+                                .map(|_| ApplyOutcome::Updated),
+                            None => client
+                                .localizations()
+                                .create(
+                                    app_info_id,
+                                    &locale,
+                                    field("name"),
+                                    field("subtitle"),
+                                    field("privacyPolicyUrl"),
+                                    field("privacyPolicyText"),
+                                )
+                                .await
+                                .map(|_| ApplyOutcome::Created),
+                        };
+                        report.record_result(resource, result);
+                    }
+                }
+                None => report.record(
+                    "appInfo",
+                    ApplyOutcome::Skipped("no app info available".into()),
+                ),
+            }
+        }
+
+        let version_locales = self.import_fields(&diff, options.only_changed, false);
+        if !version_locales.is_empty() {
+            let editable = live.version.as_ref().filter(|v| {
+                v.state
+                    .as_deref()
+                    .is_some_and(|state| EDITABLE_VERSION_STATES.contains(&state))
+            });
+
+            match editable {
+                Some(version) => {
+                    let existing =
+                        index_by_locale(client.version_localizations().get_all(&version.id).await?);
+
+                    for (locale, fields) in version_locales {
+                        let resource = format!(
+                            "appStoreVersionLocalization:{}:{}",
+                            version.version_string, locale
+                        );
+                        let field = |name: &str| fields.get(name).and_then(|v| v.as_str());
+
+                        let result = match existing.get(&locale) {
+                            Some(_) if options.dry_run => Ok(ApplyOutcome::Updated),
+                            None if options.dry_run => Ok(ApplyOutcome::Created),
+                            Some((localization_id, _)) => client
+                                .version_localizations()
+                                .update_fields(
+                                    localization_id,
+                                    &VersionLocalizationUpdate::from_value(&field_object(&fields)),
+                                )
+                                .await
+                                .map(|_| ApplyOutcome::Updated),
+                            None => client
+                                .version_localizations()
+                                .create(
+                                    &version.id,
+                                    &locale,
+                                    field("description"),
+                                    field("keywords"),
+                                    field("marketingUrl"),
+                                    field("promotionalText"),
+                                    field("supportUrl"),
+                                    field("whatsNew"),
+                                )
+                                .await
+                                .map(|_| ApplyOutcome::Created),
+                        };
+                        report.record_result(resource, result);
+                    }
+                }
+                None => report.record(
+                    "appStoreVersion",
+                    ApplyOutcome::Skipped("no editable version".into()),
+                ),
+            }
+        }
+
+        Ok(ImportReport { diff, report })
+    }
+
+    pub fn to_yaml(&self) -> Result<String, AppStoreConnectError> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self, AppStoreConnectError> {
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        push_csv_row(&mut csv, &CSV_HEADER);
+
+        for (locale, listing) in &self.locales {
+            for (scope, fields) in [
+                ("app_info", &listing.app_info),
+                ("version", &listing.version),
+            ] {
+                for (field, value) in fields {
+                    let value = match value {
+                        Value::String(text) => text.clone(),
+                        other => other.to_string(),
+                    };
+                    push_csv_row(&mut csv, &[locale, scope, field, &value]);
+                }
+            }
+        }
+
+        csv
+    }
+
+    pub fn from_csv(app_id: &str, content: &str) -> Result<Self, AppStoreConnectError> {
+        let rows = parse_csv(content)?;
+        let mut rows = rows.into_iter();
+
+        match rows.next() {
+            Some((_, header)) if header.iter().map(|h| h.trim()).eq(CSV_HEADER) => {}
+            _ => {
+                return Err(csv_error(format!(
+                    "CSV header must be {}",
+                    CSV_HEADER.join(",")
+                )))
+            }
+        }
+
+        let mut locales: BTreeMap<String, LocaleListing> = BTreeMap::new();
+        for (line, row) in rows {
+            if row.iter().all(|cell| cell.is_empty()) {
+                continue;
+            }
+            let [locale, scope, field, value] = <[String; 4]>::try_from(row).map_err(|row| {
+                csv_error(format!(
+                    "line {}: expected 4 columns, found {}",
+                    line,
+                    row.len()
+                ))
+            })?;
+            // Blank cells are treated as untranslated, not as a request to clear the field.
+            if value.is_empty() {
+                continue;
+            }
+
+            let listing = locales.entry(locale.trim().to_string()).or_default();
+            let (fields, allowed) = match scope.trim() {
+                "app_info" => (&mut listing.app_info, APP_INFO_LOCALIZATION_FIELDS),
+                "version" => (&mut listing.version, VERSION_LOCALIZATION_FIELDS),
+                other => {
+                    return Err(csv_error(format!(
+                        "line {}: unknown scope '{}' (expected app_info or version)",
+                        line, other
+                    )))
+                }
+            };
+            let field = field.trim();
+            if !allowed.contains(&field) {
+                return Err(csv_error(format!(
+                    "line {}: unknown {} field '{}'",
+                    line,
+                    scope.trim(),
+                    field
+                )));
+            }
+            fields.insert(field.to_string(), Value::String(value));
+        }
+
+        Ok(Self {
+            app_id: app_id.to_string(),
+            exported_at: None,
+            app_info_id: None,
+            version: None,
+            locales,
+        })
+    }
+
+    pub async fn save_csv(&self, path: impl AsRef<Path>) -> Result<(), AppStoreConnectError> {
+        tokio::fs::write(path, self.to_csv()).await?;
+        Ok(())
+    }
+
+    pub async fn load_csv(
+        app_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, AppStoreConnectError> {
+        let content = tokio::fs::read_to_string(path).await?;
+        Self::from_csv(app_id, &content)
+    }
+
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), AppStoreConnectError> {
+        tokio::fs::write(path, self.to_yaml()?).await?;
+        Ok(())
+    }
+
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self, AppStoreConnectError> {
+        let content = tokio::fs::read_to_string(path).await?;
+        Self::from_yaml(&content)
+    }
+}
+
+fn listing_fields(localization: &Value, fields: &[&str]) -> FieldMap {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let value = localization.get("attributes")?.get(*field)?;
+            (!value.is_null()).then(|| (field.to_string(), value.clone()))
+        })
+        .collect()
+}
+
+fn field_object(fields: &FieldMap) -> Value {
+    Value::Object(fields.clone().into_iter().collect())
+}
+
+fn csv_error(message: String) -> AppStoreConnectError {
+    AppStoreConnectError::Validation(ValidationError::new(message))
+}
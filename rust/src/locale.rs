@@ -0,0 +1,113 @@
+use crate::error::{AppStoreConnectError, ValidationError};
+
+pub const APP_STORE_LOCALES: &[&str] = &[
+    "ar-SA", "ca", "cs", "da", "de-DE", "el", "en-AU", "en-CA", "en-GB", "en-US", "es-ES", "es-MX",
+    "fi", "fr-CA", "fr-FR", "he", "hi", "hr", "hu", "id", "it", "ja", "ko", "ms", "nl-NL", "no",
+    "pl", "pt-BR", "pt-PT", "ro", "ru", "sk", "sv", "th", "tr", "uk", "vi", "zh-Hans", "zh-Hant",
+];
+
+const LOCALE_ALIASES: &[(&str, &str)] = &[
+    ("ar", "ar-SA"),
+    ("de", "de-DE"),
+    ("en", "en-US"),
+    ("es", "es-ES"),
+    ("es-419", "es-MX"),
+    ("es-us", "es-MX"),
+    ("fr", "fr-FR"),
+    ("in", "id"),
+    ("iw", "he"),
+    ("nb", "no"),
+    ("nb-no", "no"),
+    ("nl", "nl-NL"),
+    ("nn", "no"),
+    ("pt", "pt-BR"),
+    ("zh", "zh-Hans"),
+    ("zh-cn", "zh-Hans"),
+    ("zh-sg", "zh-Hans"),
+    ("zh-hans-cn", "zh-Hans"),
+    ("zh-hk", "zh-Hant"),
+    ("zh-mo", "zh-Hant"),
+    ("zh-tw", "zh-Hant"),
+    ("zh-hant-tw", "zh-Hant"),
+];
+
+pub fn is_supported_locale(locale: &str) -> bool {
+    APP_STORE_LOCALES.contains(&locale)
+}
+
+pub fn normalize_locale(locale: &str) -> Result<String, AppStoreConnectError> {
+    let cleaned = locale.trim().replace('_', "-");
+    let key = cleaned.to_lowercase();
+
+    if let Some(canonical) = APP_STORE_LOCALES
+        .iter()
+        .find(|code| code.to_lowercase() == key)
+    {
+        return Ok(canonical.to_string());
+    }
+    if let Some((_, canonical)) = LOCALE_ALIASES.iter().find(|(alias, _)| *alias == key) {
+        return Ok(canonical.to_string());
+    }
+
+    // Region-less App Store codes ("ja", "sv") accept any region ("ja-JP", "sv-SE").
+    let language = key.split('-').next().unwrap_or_default();
+    if let Some(canonical) = APP_STORE_LOCALES.iter().find(|code| **code == language) {
+        return Ok(canonical.to_string());
+    }
+
+    let message = match suggest_locale(&cleaned) {
+        Some(suggestion) => format!(
+            "Unsupported locale '{}'; did you mean '{}'?",
+            locale, suggestion
+        ),
+        None => format!("Unsupported locale '{}'", locale),
+    };
+    Err(AppStoreConnectError::Validation(ValidationError::new(
+        message,
+    )))
+}
+
+pub fn suggest_locale(locale: &str) -> Option<&'static str> {
+    let key = locale.trim().replace('_', "-").to_lowercase();
+    if key.is_empty() {
+        return None;
+    }
+
+    // A language's default region ("en" -> "en-US") is a better guess than
+    // whichever regional variant happens to come first in the list.
+    let language = key.split('-').next().unwrap_or_default();
+    if let Some((_, code)) = LOCALE_ALIASES.iter().find(|(alias, _)| *alias == language) {
+        return Some(code);
+    }
+    if let Some(code) = APP_STORE_LOCALES
+        .iter()
+        .find(|code| code.to_lowercase().split('-').next() == Some(language))
+    {
+        return Some(code);
+    }
+
+    APP_STORE_LOCALES
+        .iter()
+        .map(|code| (edit_distance(&key, &code.to_lowercase()), *code))
+        .filter(|(distance, _)| *distance <= 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, code)| code)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
@@ -0,0 +1,226 @@
+use crate::error::{AppStoreConnectError, ValidationError};
+use crate::listing::StoreListing;
+use crate::snapshot::FieldMap;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+
+pub const FIELD_LIMITS: &[(&str, usize)] = &[
+    ("name", 30),
+    ("subtitle", 30),
+    ("keywords", 100),
+    ("promotionalText", 170),
+    ("description", 4000),
+    ("whatsNew", 4000),
+];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum FieldUpdate<T> {
+    #[default]
+    Keep,
+    Clear,
+    Set(T),
+}
+
+impl<T> FieldUpdate<T> {
+    pub fn is_keep(&self) -> bool {
+        matches!(self, FieldUpdate::Keep)
+    }
+}
+
+impl<T: Serialize> FieldUpdate<T> {
+    pub fn write(&self, attributes: &mut Value, key: &str) {
+        match self {
+            FieldUpdate::Keep => {}
+            FieldUpdate::Clear => attributes[key] = Value::Null,
+            FieldUpdate::Set(value) => attributes[key] = json!(value),
+        }
+    }
+}
+
+impl FieldUpdate<String> {
+    pub fn from_attribute(attributes: &Value, key: &str) -> Self {
+        match attributes.get(key) {
+            None => FieldUpdate::Keep,
+            Some(Value::Null) => FieldUpdate::Clear,
+            Some(Value::String(text)) => FieldUpdate::Set(text.clone()),
+            Some(other) => FieldUpdate::Set(other.to_string()),
+        }
+    }
+}
+
+impl From<Option<&str>> for FieldUpdate<String> {
+    fn from(value: Option<&str>) -> Self {
+        match value {
+            Some(text) => FieldUpdate::Set(text.to_string()),
+            None => FieldUpdate::Keep,
+        }
+    }
+}
+
+impl From<Option<bool>> for FieldUpdate<bool> {
+    fn from(value: Option<bool>) -> Self {
+        match value {
+            Some(flag) => FieldUpdate::Set(flag),
+            None => FieldUpdate::Keep,
+        }
+    }
+}
+
+#[async_trait]
+pub trait Translator: Send + Sync {
+    async fn translate(
+        &self,
+        source_locale: &str,
+        target_locale: &str,
+        field: &str,
+        text: &str,
+    ) -> Result<String, AppStoreConnectError>;
+}
+
+pub fn field_limit(field: &str) -> Option<usize> {
+    FIELD_LIMITS
+        .iter()
+        .find(|(name, _)| *name == field)
+        .map(|(_, limit)| *limit)
+}
+
+pub fn check_field_limits(attributes: &Value) -> Vec<String> {
+    FIELD_LIMITS
+        .iter()
+        .filter_map(|(field, limit)| {
+            let length = attributes.get(*field)?.as_str()?.chars().count();
+            (length > *limit)
+                .then(|| format!("{} is {} characters (limit {})", field, length, limit))
+        })
+        .collect()
+}
+
+pub fn validate_field_limits(locale: &str, attributes: &Value) -> Result<(), AppStoreConnectError> {
+    let issues = check_field_limits(attributes);
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(AppStoreConnectError::Validation(
+            ValidationError::with_issues(
+                format!("Metadata for {} exceeds App Store limits", locale),
+                issues,
+            ),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeywordReport {
+    pub locale: String,
+    pub length: usize,
+    pub remaining: usize,
+    pub terms: Vec<String>,
+    pub duplicates: Vec<String>,
+    pub in_name_or_subtitle: Vec<String>,
+    pub optimized: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeywordAnalyzer {
+    pub limit: usize,
+}
+
+impl Default for KeywordAnalyzer {
+    fn default() -> Self {
+        Self {
+            limit: field_limit("keywords").unwrap_or(100),
+        }
+    }
+}
+
+impl KeywordAnalyzer {
+    pub fn analyze(
+        &self,
+        locale: &str,
+        keywords: &str,
+        name: Option<&str>,
+        subtitle: Option<&str>,
+    ) -> KeywordReport {
+        let listing_words: BTreeSet<String> = [name, subtitle]
+            .iter()
+            .flatten()
+            .flat_map(|text| text.split(|c: char| !c.is_alphanumeric()))
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        let terms: Vec<String> = keywords
+            .split(',')
+            .map(|term| term.trim().to_string())
+            .filter(|term| !term.is_empty())
+            .collect();
+
+        let mut seen = BTreeSet::new();
+        let mut duplicates = Vec::new();
+        let mut in_name_or_subtitle = Vec::new();
+        let mut kept = Vec::new();
+
+        for term in &terms {
+            let normalized = term.to_lowercase();
+            if !seen.insert(normalized.clone()) {
+                duplicates.push(term.clone());
+                continue;
+            }
+
+            let covered = normalized
+                .split_whitespace()
+                .all(|word| listing_words.contains(word));
+            if covered {
+                in_name_or_subtitle.push(term.clone());
+                continue;
+            }
+
+            kept.push(term.clone());
+        }
+
+        let mut optimized = String::new();
+        for term in kept {
+            let separator = if optimized.is_empty() { 0 } else { 1 };
+            if optimized.chars().count() + separator + term.chars().count() > self.limit {
+                continue;
+            }
+            if separator == 1 {
+                optimized.push(',');
+            }
+            optimized.push_str(&term);
+        }
+
+        let length = keywords.chars().count();
+        KeywordReport {
+            locale: locale.to_string(),
+            length,
+            remaining: self.limit.saturating_sub(length),
+            terms,
+            duplicates,
+            in_name_or_subtitle,
+            optimized,
+        }
+    }
+
+    pub fn analyze_listing(&self, listing: &StoreListing) -> Vec<KeywordReport> {
+        listing
+            .locales
+            .iter()
+            .filter_map(|(locale, fields)| {
+                let text = |map: &FieldMap, field: &str| {
+                    map.get(field)
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string())
+                };
+                let keywords = text(&fields.version, "keywords")?;
+                let name = text(&fields.app_info, "name");
+                let subtitle = text(&fields.app_info, "subtitle");
+
+                Some(self.analyze(locale, &keywords, name.as_deref(), subtitle.as_deref()))
+            })
+            .collect()
+    }
+}
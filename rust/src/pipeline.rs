@@ -0,0 +1,660 @@
+use crate::api::media::ScreenshotDisplayType;
+use crate::api::review_attachments::ReviewDetail;
+use crate::api::version_localizations::WhatsNew;
+use crate::api::versions::{VersionFilter, VersionUpdate};
+use crate::bulk::failures;
+use crate::client::Client;
+use crate::error::{AppStoreConnectError, ValidationError};
+use crate::locale::normalize_locale;
+use crate::metadata::FieldUpdate;
+use crate::snapshot::EDITABLE_VERSION_STATES;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+const SUBMITTED_VERSION_STATES: &[&str] = &[
+    "WAITING_FOR_REVIEW",
+    "IN_REVIEW",
+    "PENDING_DEVELOPER_RELEASE",
+    "PENDING_APPLE_RELEASE",
+    "PROCESSING_FOR_APP_STORE",
+    "READY_FOR_SALE",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PipelineStep {
+    PrepareVersion,
+    AttachBuild,
+    Localizations,
+    VerifyScreenshots,
+    ReviewDetails,
+    Submit,
+}
+
+impl PipelineStep {
+    pub const ALL: [PipelineStep; 6] = [
+        PipelineStep::PrepareVersion,
+        PipelineStep::AttachBuild,
+        PipelineStep::Localizations,
+        PipelineStep::VerifyScreenshots,
+        PipelineStep::ReviewDetails,
+        PipelineStep::Submit,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PipelineStep::PrepareVersion => "prepare version",
+            PipelineStep::AttachBuild => "attach build",
+            PipelineStep::Localizations => "localizations",
+            PipelineStep::VerifyScreenshots => "verify screenshots",
+            PipelineStep::ReviewDetails => "review details",
+            PipelineStep::Submit => "submit",
+        }
+    }
+}
+
+impl fmt::Display for PipelineStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StepStatus {
+    Done(String),
+    // The step's goal was already met in App Store Connect, e.g. the build
+    // was attached or the version submitted before this run.
+    AlreadyDone(String),
+    WouldRun(String),
+    Skipped(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineStepResult {
+    pub step: PipelineStep,
+    pub status: StepStatus,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PipelineReport {
+    pub app_id: String,
+    pub version_string: String,
+    pub dry_run: bool,
+    pub version_id: Option<String>,
+    pub build_id: Option<String>,
+    pub steps: Vec<PipelineStepResult>,
+}
+
+impl PipelineReport {
+    fn record(&mut self, step: PipelineStep, status: StepStatus) {
+        self.steps.push(PipelineStepResult { step, status });
+    }
+
+    pub fn status(&self, step: PipelineStep) -> Option<&StepStatus> {
+        self.steps
+            .iter()
+            .find(|result| result.step == step)
+            .map(|result| &result.status)
+    }
+
+    // Dry runs never complete anything, so resuming from one starts over.
+    // Skipped steps were left out by configuration and run again on resume.
+    pub fn is_completed(&self, step: PipelineStep) -> bool {
+        !self.dry_run
+            && matches!(
+                self.status(step),
+                Some(StepStatus::Done(_)) | Some(StepStatus::AlreadyDone(_))
+            )
+    }
+
+    pub fn failure(&self) -> Option<&PipelineStepResult> {
+        self.steps
+            .iter()
+            .find(|result| matches!(result.status, StepStatus::Failed(_)))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failure().is_none() && self.steps.len() == PipelineStep::ALL.len()
+    }
+
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), AppStoreConnectError> {
+        tokio::fs::write(path, serde_json::to_string_pretty(self)?).await?;
+        Ok(())
+    }
+
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self, AppStoreConnectError> {
+        let content = tokio::fs::read_to_string(path).await?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReleasePipeline {
+    app_id: String,
+    version_string: String,
+    platform: String,
+    min_build_version: Option<String>,
+    copy_localizations: bool,
+    localizations: BTreeMap<String, Value>,
+    whats_new: Option<WhatsNew>,
+    required_display_types: Vec<ScreenshotDisplayType>,
+    review_detail: Option<ReviewDetail>,
+    submit: bool,
+    dry_run: bool,
+    resume: Option<PipelineReport>,
+}
+
+impl ReleasePipeline {
+    pub fn new(app_id: impl Into<String>, version_string: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+            version_string: version_string.into(),
+            platform: "IOS".to_string(),
+            min_build_version: None,
+            copy_localizations: true,
+            localizations: BTreeMap::new(),
+            whats_new: None,
+            required_display_types: Vec::new(),
+            review_detail: None,
+            submit: true,
+            dry_run: false,
+            resume: None,
+        }
+    }
+
+    pub fn platform(mut self, platform: impl Into<String>) -> Self {
+        self.platform = platform.into();
+        self
+    }
+
+    pub fn min_build_version(mut self, build_version: impl Into<String>) -> Self {
+        self.min_build_version = Some(build_version.into());
+        self
+    }
+
+    pub fn copy_localizations(mut self, copy: bool) -> Self {
+        self.copy_localizations = copy;
+        self
+    }
+
+    pub fn localization(mut self, locale: impl Into<String>, attributes: Value) -> Self {
+        self.localizations.insert(locale.into(), attributes);
+        self
+    }
+
+    pub fn whats_new(mut self, whats_new: WhatsNew) -> Self {
+        self.whats_new = Some(whats_new);
+        self
+    }
+
+    pub fn require_screenshots(mut self, display_types: &[ScreenshotDisplayType]) -> Self {
+        self.required_display_types = display_types.to_vec();
+        self
+    }
+
+    pub fn review_detail(mut self, detail: ReviewDetail) -> Self {
+        self.review_detail = Some(detail);
+        self
+    }
+
+    pub fn submit(mut self, submit: bool) -> Self {
+        self.submit = submit;
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn resume_from(mut self, report: PipelineReport) -> Self {
+        self.resume = Some(report);
+        self
+    }
+
+    // Steps run in order and the pipeline stops at the first failure; the
+    // returned report can be passed to `resume_from` to pick up from there.
+    pub async fn run(&self, client: &Client) -> Result<PipelineReport, AppStoreConnectError> {
+        let mut report = PipelineReport {
+            app_id: self.app_id.clone(),
+            version_string: self.version_string.clone(),
+            dry_run: self.dry_run,
+            ..Default::default()
+        };
+
+        if let Some(previous) = &self.resume {
+            if previous.app_id != self.app_id || previous.version_string != self.version_string {
+                return Err(AppStoreConnectError::Validation(ValidationError::new(
+                    format!(
+                        "Cannot resume the {} release of app {} from a report for {} of app {}",
+                        self.version_string, self.app_id, previous.version_string, previous.app_id
+                    ),
+                )));
+            }
+            report.version_id = previous.version_id.clone();
+            report.build_id = previous.build_id.clone();
+        }
+
+        for step in PipelineStep::ALL {
+            if self
+                .resume
+                .as_ref()
+                .is_some_and(|previous| previous.is_completed(step))
+            {
+                report.record(
+                    step,
+                    StepStatus::AlreadyDone("Completed in a previous run".to_string()),
+                );
+                continue;
+            }
+
+            let status = self
+                .run_step(client, step, &mut report)
+                .await
+                .unwrap_or_else(|e| StepStatus::Failed(e.to_string()));
+            let failed = matches!(status, StepStatus::Failed(_));
+            report.record(step, status);
+
+            if failed {
+                break;
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn run_step(
+        &self,
+        client: &Client,
+        step: PipelineStep,
+        report: &mut PipelineReport,
+    ) -> Result<StepStatus, AppStoreConnectError> {
+        if step == PipelineStep::PrepareVersion {
+            return self.prepare_version(client, report).await;
+        }
+
+        // Only a dry run gets here without a version to work on.
+        let Some(version_id) = report.version_id.clone() else {
+            return Ok(StepStatus::WouldRun(
+                "Runs once the version has been created".to_string(),
+            ));
+        };
+
+        match step {
+            PipelineStep::PrepareVersion => unreachable!(),
+            PipelineStep::AttachBuild => self.attach_build(client, &version_id, report).await,
+            PipelineStep::Localizations => self.update_localizations(client, &version_id).await,
+            PipelineStep::VerifyScreenshots => self.verify_screenshots(client, &version_id).await,
+            PipelineStep::ReviewDetails => self.set_review_detail(client, &version_id).await,
+            PipelineStep::Submit => self.submit_version(client, &version_id).await,
+        }
+    }
+
+    async fn prepare_version(
+        &self,
+        client: &Client,
+        report: &mut PipelineReport,
+    ) -> Result<StepStatus, AppStoreConnectError> {
+        let versions = client
+            .versions()
+            .list(
+                &self.app_id,
+                &VersionFilter::default().platform(&self.platform),
+            )
+            .await?;
+
+        let existing = versions
+            .iter()
+            .find(|v| attribute(v, "versionString") == Some(&self.version_string));
+        if let Some(version) = existing {
+            let state = attribute(version, "appStoreState").unwrap_or("UNKNOWN");
+            if !EDITABLE_VERSION_STATES.contains(&state) {
+                return Ok(StepStatus::Failed(format!(
+                    "Version {} is {} and can no longer be edited",
+                    self.version_string, state
+                )));
+            }
+
+            report.version_id = version.get("id").and_then(|i| i.as_str()).map(String::from);
+            return Ok(StepStatus::Done(format!(
+                "Reusing version {} ({})",
+                self.version_string, state
+            )));
+        }
+
+        // An app can only have one editable version per platform, so an
+        // unsubmitted version with another number is renamed rather than
+        // creating a second one.
+        let editable = versions.iter().find(|v| {
+            attribute(v, "appStoreState").is_some_and(|s| EDITABLE_VERSION_STATES.contains(&s))
+        });
+        if let Some(version) = editable {
+            let version_id = version
+                .get("id")
+                .and_then(|i| i.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let previous = attribute(version, "versionString").unwrap_or_default();
+            report.version_id = Some(version_id.clone());
+
+            if self.dry_run {
+                return Ok(StepStatus::WouldRun(format!(
+                    "Rename editable version {} to {}",
+                    previous, self.version_string
+                )));
+            }
+            let update = VersionUpdate {
+                version_string: FieldUpdate::Set(self.version_string.clone()),
+                ..Default::default()
+            };
+            client
+                .versions()
+                .update_fields(&version_id, &update)
+                .await?;
+            return Ok(StepStatus::Done(format!(
+                "Renamed editable version {} to {}",
+                previous, self.version_string
+            )));
+        }
+
+        if self.dry_run {
+            return Ok(StepStatus::WouldRun(format!(
+                "Create version {}",
+                self.version_string
+            )));
+        }
+        let version = client
+            .versions()
+            .create(
+                &self.app_id,
+                &self.version_string,
+                Some(&self.platform),
+                None,
+                None,
+            )
+            .await?;
+        report.version_id = version.get("id").and_then(|i| i.as_str()).map(String::from);
+
+        Ok(StepStatus::Done(format!(
+            "Created version {}",
+            self.version_string
+        )))
+    }
+
+    async fn attach_build(
+        &self,
+        client: &Client,
+        version_id: &str,
+        report: &mut PipelineReport,
+    ) -> Result<StepStatus, AppStoreConnectError> {
+        let latest = client
+            .versions()
+            .find_latest_build(
+                &self.app_id,
+                &self.version_string,
+                &self.platform,
+                self.min_build_version.as_deref(),
+            )
+            .await?;
+        let Some(build) = latest else {
+            return Ok(StepStatus::Failed(format!(
+                "No processed build found for version {} ({})",
+                self.version_string, self.platform
+            )));
+        };
+
+        let build_id = build
+            .get("id")
+            .and_then(|i| i.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let build_number = attribute(&build, "version").unwrap_or_default().to_string();
+
+        let attached = client.versions().get_build(version_id).await?;
+        if attached
+            .as_ref()
+            .and_then(|b| b.get("id"))
+            .and_then(|i| i.as_str())
+            == Some(build_id.as_str())
+        {
+            report.build_id = Some(build_id);
+            return Ok(StepStatus::AlreadyDone(format!(
+                "Build {} is already attached",
+                build_number
+            )));
+        }
+
+        if self.dry_run {
+            report.build_id = Some(build_id);
+            return Ok(StepStatus::WouldRun(format!(
+                "Attach build {}",
+                build_number
+            )));
+        }
+        client.versions().set_build(version_id, &build_id).await?;
+        report.build_id = Some(build_id);
+
+        Ok(StepStatus::Done(format!("Attached build {}", build_number)))
+    }
+
+    async fn update_localizations(
+        &self,
+        client: &Client,
+        version_id: &str,
+    ) -> Result<StepStatus, AppStoreConnectError> {
+        let desired = self.desired_localizations(client, version_id).await?;
+        if desired.is_empty() {
+            return Ok(StepStatus::Skipped("No localization changes".to_string()));
+        }
+
+        let locales: Vec<&str> = desired.keys().map(|l| l.as_str()).collect();
+        if self.dry_run {
+            return Ok(StepStatus::WouldRun(format!(
+                "Update {} locales: {}",
+                locales.len(),
+                locales.join(", ")
+            )));
+        }
+
+        let count = desired.len();
+        let results = client
+            .version_localizations()
+            .bulk_update(
+                version_id,
+                desired
+                    .into_iter()
+                    .map(|(locale, fields)| (locale, Value::Object(fields)))
+                    .collect(),
+            )
+            .await?;
+
+        let failed: Vec<String> = failures(&results)
+            .into_iter()
+            .map(|r| format!("{}: {}", r.locale, r.error().unwrap_or_default()))
+            .collect();
+        if !failed.is_empty() {
+            return Ok(StepStatus::Failed(failed.join("; ")));
+        }
+
+        Ok(StepStatus::Done(format!("Updated {} locales", count)))
+    }
+
+    async fn desired_localizations(
+        &self,
+        client: &Client,
+        version_id: &str,
+    ) -> Result<BTreeMap<String, Map<String, Value>>, AppStoreConnectError> {
+        let mut desired: BTreeMap<String, Map<String, Value>> = BTreeMap::new();
+
+        if self.copy_localizations {
+            let versions = client
+                .versions()
+                .list(
+                    &self.app_id,
+                    &VersionFilter::default().platform(&self.platform),
+                )
+                .await?;
+            let live = versions.iter().find(|v| {
+                v.get("id").and_then(|i| i.as_str()) != Some(version_id)
+                    && attribute(v, "appStoreState") == Some("READY_FOR_SALE")
+            });
+
+            if let Some(live_id) = live.and_then(|v| v.get("id")).and_then(|i| i.as_str()) {
+                for (locale, fields) in client.version_localizations().bulk_export(live_id).await? {
+                    let mut fields = fields.as_object().cloned().unwrap_or_default();
+                    // Release notes belong to the version they shipped with.
+                    fields.remove("whatsNew");
+                    desired.insert(locale, fields);
+                }
+            }
+        }
+
+        for (requested, attributes) in &self.localizations {
+            let locale = normalize_locale(requested).unwrap_or_else(|_| requested.clone());
+            let fields = desired.entry(locale).or_default();
+            for (key, value) in attributes.as_object().into_iter().flatten() {
+                fields.insert(key.clone(), value.clone());
+            }
+        }
+
+        if let Some(whats_new) = &self.whats_new {
+            let existing = client
+                .version_localizations()
+                .bulk_export(version_id)
+                .await?;
+            for locale in existing.into_keys() {
+                desired.entry(locale).or_default();
+            }
+            for (locale, fields) in desired.iter_mut() {
+                if let Some(text) = whats_new.for_locale(locale) {
+                    fields.insert("whatsNew".to_string(), json!(text));
+                }
+            }
+        }
+
+        desired.retain(|_, fields| !fields.is_empty());
+        Ok(desired)
+    }
+
+    async fn verify_screenshots(
+        &self,
+        client: &Client,
+        version_id: &str,
+    ) -> Result<StepStatus, AppStoreConnectError> {
+        let localizations = client.version_localizations().get_all(version_id).await?;
+        if localizations.is_empty() {
+            return Ok(StepStatus::Failed(
+                "Version has no localizations".to_string(),
+            ));
+        }
+
+        let mut missing = Vec::new();
+        for localization in &localizations {
+            let (Some(localization_id), Some(locale)) = (
+                localization.get("id").and_then(|i| i.as_str()),
+                attribute(localization, "locale"),
+            ) else {
+                continue;
+            };
+
+            let screenshots = client
+                .media()
+                .get_screenshots(localization_id, None)
+                .await?;
+            let present: HashSet<&str> = screenshots
+                .iter()
+                .filter_map(|s| s.get("displayType").and_then(|d| d.as_str()))
+                .collect();
+
+            if self.required_display_types.is_empty() {
+                if screenshots.is_empty() {
+                    missing.push(format!("{}: no screenshots", locale));
+                }
+                continue;
+            }
+            for display_type in &self.required_display_types {
+                if !present.contains(display_type.as_str()) {
+                    missing.push(format!("{}: {}", locale, display_type.as_str()));
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            return Ok(StepStatus::Failed(format!(
+                "Missing screenshots ({})",
+                missing.join(", ")
+            )));
+        }
+
+        Ok(StepStatus::Done(format!(
+            "Screenshots present for {} locales",
+            localizations.len()
+        )))
+    }
+
+    async fn set_review_detail(
+        &self,
+        client: &Client,
+        version_id: &str,
+    ) -> Result<StepStatus, AppStoreConnectError> {
+        let Some(detail) = &self.review_detail else {
+            return Ok(StepStatus::Skipped(
+                "No review details provided".to_string(),
+            ));
+        };
+
+        if self.dry_run {
+            return Ok(StepStatus::WouldRun("Set review details".to_string()));
+        }
+        client
+            .review_attachments()
+            .set_review_detail(version_id, detail)
+            .await?;
+
+        Ok(StepStatus::Done("Review details set".to_string()))
+    }
+
+    async fn submit_version(
+        &self,
+        client: &Client,
+        version_id: &str,
+    ) -> Result<StepStatus, AppStoreConnectError> {
+        if !self.submit {
+            return Ok(StepStatus::Skipped("Submission disabled".to_string()));
+        }
+
+        let state = client.versions().get_state(version_id).await?;
+        if SUBMITTED_VERSION_STATES.contains(&state.as_str()) {
+            return Ok(StepStatus::AlreadyDone(format!(
+                "Version is already {}",
+                state
+            )));
+        }
+
+        if self.dry_run {
+            return Ok(StepStatus::WouldRun("Submit for review".to_string()));
+        }
+        let submission = client
+            .review_submissions()
+            .submit_version(version_id)
+            .await?;
+
+        Ok(StepStatus::Done(format!(
+            "Submitted for review ({})",
+            submission
+                .get("id")
+                .and_then(|i| i.as_str())
+                .unwrap_or_default()
+        )))
+    }
+}
+
+fn attribute<'a>(resource: &'a Value, key: &str) -> Option<&'a str> {
+    resource
+        .get("attributes")
+        .and_then(|a| a.get(key))
+        .and_then(|v| v.as_str())
+}
@@ -0,0 +1,786 @@
+use crate::api::localizations::AppInfoLocalizationUpdate;
+use crate::api::version_localizations::VersionLocalizationUpdate;
+use crate::client::Client;
+use crate::diff::{category_fields, ChangeKind, DiffScope, FieldChange};
+use crate::error::{AppStoreConnectError, ValidationError};
+use crate::locale::normalize_locale;
+use crate::snapshot::{
+    index_by_locale, ApplyOutcome, ApplyReport, FieldMap, Snapshot, SnapshotFormat,
+    APP_INFO_LOCALIZATION_FIELDS, EDITABLE_VERSION_STATES, VERSION_LOCALIZATION_FIELDS,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DesiredState {
+    #[serde(default)]
+    pub localizations: BTreeMap<String, FieldMap>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<DesiredVersion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub categories: Option<FieldMap>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub availability: Option<DesiredAvailability>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing: Option<DesiredPricing>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub subscription_availability: BTreeMap<String, DesiredAvailability>,
+    #[serde(default)]
+    pub prune_locales: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DesiredVersion {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_string: Option<String>,
+    #[serde(default = "default_platform")]
+    pub platform: String,
+    #[serde(default)]
+    pub localizations: BTreeMap<String, FieldMap>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DesiredAvailability {
+    pub territories: Vec<String>,
+    // Left out, the live setting is kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub available_in_new_territories: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DesiredPricing {
+    pub base_territory: String,
+    pub price_point_id: String,
+}
+
+fn default_platform() -> String {
+    "IOS".to_string()
+}
+
+impl DesiredState {
+    pub fn from_yaml(content: &str) -> Result<Self, AppStoreConnectError> {
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, AppStoreConnectError> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self, AppStoreConnectError> {
+        let path = path.as_ref();
+        let content = tokio::fs::read_to_string(path).await?;
+
+        match SnapshotFormat::from_path(path) {
+            SnapshotFormat::Json => Self::from_json(&content),
+            SnapshotFormat::Yaml => Self::from_yaml(&content),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Operation {
+    Create,
+    Update,
+    Delete,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Create => write!(f, "+ create"),
+            Operation::Update => write!(f, "~ update"),
+            Operation::Delete => write!(f, "- delete"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedChange {
+    pub scope: DiffScope,
+    pub operation: Operation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Plan {
+    pub app_id: String,
+    pub changes: Vec<PlannedChange>,
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes. Live metadata matches the desired state.");
+        }
+
+        for planned in &self.changes {
+            writeln!(f, "{} {}", planned.operation, planned.scope)?;
+            for change in &planned.changes {
+                writeln!(f, "    {}", change)?;
+            }
+        }
+
+        let count = |op: Operation| self.changes.iter().filter(|c| c.operation == op).count();
+        writeln!(
+            f,
+            "\nPlan: {} to create, {} to update, {} to delete.",
+            count(Operation::Create),
+            count(Operation::Update),
+            count(Operation::Delete)
+        )
+    }
+}
+
+impl Plan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub async fn compute(
+        client: &Client,
+        app_id: &str,
+        desired: &DesiredState,
+    ) -> Result<Self, AppStoreConnectError> {
+        let live = Snapshot::capture(client, app_id).await?;
+        let mut changes = Vec::new();
+
+        if !desired.localizations.is_empty() || desired.categories.is_some() {
+            let app_info = live
+                .editable_app_info()
+                .ok_or_else(|| AppStoreConnectError::Api {
+                    message: format!("No app info found for app {}", app_id),
+                })?;
+            let existing = index_by_locale(client.localizations().get_all(&app_info.id).await?);
+            let desired_localizations =
+                normalize_desired_locales(&desired.localizations, APP_INFO_LOCALIZATION_FIELDS)?;
+
+            plan_locales(
+                &mut changes,
+                &app_info.id,
+                &existing,
+                &app_info.localizations,
+                &desired_localizations,
+                desired.prune_locales,
+                |locale| DiffScope::AppInfoLocalization {
+                    locale: locale.to_string(),
+                },
+            );
+
+            if let Some(categories) = &desired.categories {
+                let live_categories = category_fields(&app_info.categories);
+                let field_changes =
+                    changed_fields(&DiffScope::Categories, &live_categories, categories);
+                if !field_changes.is_empty() {
+                    changes.push(PlannedChange {
+                        scope: DiffScope::Categories,
+                        operation: Operation::Update,
+                        resource_id: Some(app_info.id.clone()),
+                        parent_id: None,
+                        changes: field_changes,
+                    });
+                }
+            }
+        }
+
+        if let Some(version) = &desired.version {
+            let target = match &version.version_string {
+                Some(version_string) => live.version(version_string, &version.platform),
+                None => live.versions.iter().find(|v| {
+                    v.platform == version.platform
+                        && EDITABLE_VERSION_STATES.contains(&v.state.as_deref().unwrap_or_default())
+                }),
+            }
+            .ok_or_else(|| {
+                AppStoreConnectError::Validation(ValidationError::new(format!(
+                    "No matching {} version found for app {}",
+                    version.platform, app_id
+                )))
+            })?;
+
+            let existing =
+                index_by_locale(client.version_localizations().get_all(&target.id).await?);
+            let desired_localizations =
+                normalize_desired_locales(&version.localizations, VERSION_LOCALIZATION_FIELDS)?;
+
+            plan_locales(
+                &mut changes,
+                &target.id,
+                &existing,
+                &target.localizations,
+                &desired_localizations,
+                desired.prune_locales,
+                |locale| DiffScope::VersionLocalization {
+                    platform: target.platform.clone(),
+                    version_string: target.version_string.clone(),
+                    locale: locale.to_string(),
+                },
+            );
+        }
+
+        if let Some(availability) = &desired.availability {
+            let field_changes = availability_changes(
+                &DiffScope::Availability,
+                &live.availability.territories,
+                live.availability.available_in_new_territories,
+                availability,
+            );
+
+            if !field_changes.is_empty() {
+                changes.push(PlannedChange {
+                    scope: DiffScope::Availability,
+                    operation: Operation::Update,
+                    resource_id: Some(app_id.to_string()),
+                    parent_id: None,
+                    changes: field_changes,
+                });
+            }
+        }
+
+        for (subscription_id, availability) in &desired.subscription_availability {
+            let scope = DiffScope::SubscriptionAvailability {
+                subscription_id: subscription_id.clone(),
+            };
+            let live_territories = client
+                .subscriptions()
+                .get_available_territories(subscription_id)
+                .await?;
+            let live_available_in_new = client
+                .subscriptions()
+                .is_available_in_new_territories(subscription_id)
+                .await?;
+            let field_changes = availability_changes(
+                &scope,
+                &live_territories,
+                live_available_in_new,
+                availability,
+            );
+
+            if !field_changes.is_empty() {
+                changes.push(PlannedChange {
+                    scope,
+                    operation: Operation::Update,
+                    resource_id: Some(subscription_id.clone()),
+                    parent_id: None,
+                    changes: field_changes,
+                });
+            }
+        }
+
+        if let Some(pricing) = &desired.pricing {
+            let current = client.pricing().get_base_price(app_id).await?;
+            let desired_price = (
+                pricing.base_territory.clone(),
+                pricing.price_point_id.clone(),
+            );
+
+            if current.as_ref() != Some(&desired_price) {
+                changes.push(PlannedChange {
+                    scope: DiffScope::Pricing,
+                    operation: Operation::Update,
+                    resource_id: Some(app_id.to_string()),
+                    parent_id: None,
+                    changes: vec![FieldChange {
+                        scope: DiffScope::Pricing,
+                        field: "basePrice".to_string(),
+                        kind: if current.is_some() {
+                            ChangeKind::Modified
+                        } else {
+                            ChangeKind::Added
+                        },
+                        old: current.map(|(territory, point)| {
+                            json!({ "territory": territory, "pricePoint": point })
+                        }),
+                        new: Some(json!({
+                            "territory": desired_price.0,
+                            "pricePoint": desired_price.1
+                        })),
+                    }],
+                });
+            }
+        }
+
+        Ok(Self {
+            app_id: app_id.to_string(),
+            changes,
+        })
+    }
+
+    pub async fn apply(&self, client: &Client) -> Result<ApplyReport, AppStoreConnectError> {
+        let mut report = ApplyReport::default();
+
+        for planned in &self.changes {
+            let resource = planned.scope.to_string();
+            let result = apply_change(client, &self.app_id, planned).await;
+            report.record_result(resource, result);
+        }
+
+        Ok(report)
+    }
+
+    pub async fn apply_with_confirmation(
+        &self,
+        client: &Client,
+        confirm: impl FnOnce(&Plan) -> bool,
+    ) -> Result<Option<ApplyReport>, AppStoreConnectError> {
+        if self.is_empty() || !confirm(self) {
+            return Ok(None);
+        }
+
+        self.apply(client).await.map(Some)
+    }
+}
+
+// Desired locales get the same checks the localization APIs apply, so a plan
+// never proposes a locale or field Apple would reject.
+pub(crate) fn normalize_desired_locales(
+    desired: &BTreeMap<String, FieldMap>,
+    editable_fields: &[&str],
+) -> Result<BTreeMap<String, FieldMap>, AppStoreConnectError> {
+    let mut normalized = BTreeMap::new();
+
+    for (locale, fields) in desired {
+        let canonical = normalize_locale(locale)?;
+        if let Some(field) = fields
+            .keys()
+            .find(|field| !editable_fields.contains(&field.as_str()))
+        {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                format!(
+                    "{}: '{}' is not an editable localization field",
+                    locale, field
+                ),
+            )));
+        }
+        if normalized
+            .insert(canonical.clone(), fields.clone())
+            .is_some()
+        {
+            return Err(AppStoreConnectError::Validation(ValidationError::new(
+                format!("Locale {} is listed more than once", canonical),
+            )));
+        }
+    }
+
+    Ok(normalized)
+}
+
+fn plan_locales(
+    changes: &mut Vec<PlannedChange>,
+    parent_id: &str,
+    existing: &HashMap<String, (String, Value)>,
+    live: &BTreeMap<String, FieldMap>,
+    desired: &BTreeMap<String, FieldMap>,
+    prune: bool,
+    scope: impl Fn(&str) -> DiffScope,
+) {
+    let empty = FieldMap::new();
+
+    for (locale, fields) in desired {
+        match existing.get(locale) {
+            Some((localization_id, _)) => {
+                let live_fields = live.get(locale).unwrap_or(&empty);
+                let field_changes = changed_fields(&scope(locale), live_fields, fields);
+                if !field_changes.is_empty() {
+                    changes.push(PlannedChange {
+                        scope: scope(locale),
+                        operation: Operation::Update,
+                        resource_id: Some(localization_id.clone()),
+                        parent_id: Some(parent_id.to_string()),
+                        changes: field_changes,
+                    });
+                }
+            }
+            None => changes.push(PlannedChange {
+                scope: scope(locale),
+                operation: Operation::Create,
+                resource_id: None,
+                parent_id: Some(parent_id.to_string()),
+                changes: changed_fields(&scope(locale), &empty, fields),
+            }),
+        }
+    }
+
+    if prune {
+        for (locale, (localization_id, _)) in existing {
+            if !desired.contains_key(locale) {
+                changes.push(PlannedChange {
+                    scope: scope(locale),
+                    operation: Operation::Delete,
+                    resource_id: Some(localization_id.clone()),
+                    parent_id: Some(parent_id.to_string()),
+                    changes: Vec::new(),
+                });
+            }
+        }
+    }
+}
+
+fn changed_fields(scope: &DiffScope, live: &FieldMap, desired: &FieldMap) -> Vec<FieldChange> {
+    desired
+        .iter()
+        .filter_map(|(field, new)| {
+            let old = live.get(field).filter(|v| !v.is_null());
+            let new = Some(new).filter(|v| !v.is_null());
+            if old == new {
+                return None;
+            }
+
+            let kind = match (old, new) {
+                (None, Some(_)) => ChangeKind::Added,
+                (Some(_), None) => ChangeKind::Removed,
+                _ => ChangeKind::Modified,
+            };
+
+            Some(FieldChange {
+                scope: scope.clone(),
+                field: field.clone(),
+                kind,
+                old: old.cloned(),
+                new: new.cloned(),
+            })
+        })
+        .collect()
+}
+
+fn availability_changes(
+    scope: &DiffScope,
+    live_territories: &[String],
+    live_available_in_new: bool,
+    desired: &DesiredAvailability,
+) -> Vec<FieldChange> {
+    let live: BTreeSet<&String> = live_territories.iter().collect();
+    let wanted: BTreeSet<&String> = desired.territories.iter().collect();
+
+    let mut field_changes = Vec::new();
+    if let Some(wanted_in_new) = desired
+        .available_in_new_territories
+        .filter(|wanted| *wanted != live_available_in_new)
+    {
+        field_changes.push(FieldChange {
+            scope: scope.clone(),
+            field: "availableInNewTerritories".to_string(),
+            kind: ChangeKind::Modified,
+            old: Some(json!(live_available_in_new)),
+            new: Some(json!(wanted_in_new)),
+        });
+    }
+    for territory in live.difference(&wanted) {
+        field_changes.push(territory_change(territory, ChangeKind::Removed));
+    }
+    for territory in wanted.difference(&live) {
+        field_changes.push(territory_change(territory, ChangeKind::Added));
+    }
+
+    field_changes
+}
+
+fn merge_availability(
+    changes: &[FieldChange],
+    territories: &mut BTreeSet<String>,
+    available_in_new_territories: &mut bool,
+) {
+    for change in changes {
+        match &change.scope {
+            DiffScope::Territory { territory } => match change.kind {
+                ChangeKind::Removed => {
+                    territories.remove(territory);
+                }
+                _ => {
+                    territories.insert(territory.clone());
+                }
+            },
+            _ => {
+                *available_in_new_territories = change
+                    .new
+                    .as_ref()
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+            }
+        }
+    }
+}
+
+fn territory_change(territory: &str, kind: ChangeKind) -> FieldChange {
+    let scope = DiffScope::Territory {
+        territory: territory.to_string(),
+    };
+
+    match kind {
+        ChangeKind::Removed => FieldChange {
+            scope,
+            field: "available".to_string(),
+            kind,
+            old: Some(json!(true)),
+            new: None,
+        },
+        _ => FieldChange {
+            scope,
+            field: "available".to_string(),
+            kind,
+            old: None,
+            new: Some(json!(true)),
+        },
+    }
+}
+
+fn new_attributes(planned: &PlannedChange) -> Value {
+    let attributes: serde_json::Map<String, Value> = planned
+        .changes
+        .iter()
+        .map(|c| (c.field.clone(), c.new.clone().unwrap_or(Value::Null)))
+        .collect();
+
+    Value::Object(attributes)
+}
+
+// Removed categories are sent as null so Apple clears them; leaving a field
+// out of the request would keep the old value in place.
+pub(crate) fn categories_patch(app_info_id: &str, changes: &[FieldChange]) -> Value {
+    let mut attributes = json!({});
+    let mut relationships = json!({});
+
+    for change in changes {
+        let id = change.new.as_ref().and_then(|v| v.as_str());
+        match change.field.as_str() {
+            "primaryCategory" | "secondaryCategory" => {
+                relationships[change.field.as_str()] = json!({
+                    "data": id.map(|id| json!({ "type": "appCategories", "id": id }))
+                });
+            }
+            field => attributes[field] = json!(id),
+        }
+    }
+
+    json!({
+        "data": {
+            "type": "appInfos",
+            "id": app_info_id,
+            "attributes": attributes,
+            "relationships": relationships
+        }
+    })
+}
+
+fn missing_id(planned: &PlannedChange) -> AppStoreConnectError {
+    AppStoreConnectError::Api {
+        message: format!(
+            "Planned change for {} is missing a resource ID",
+            planned.scope
+        ),
+    }
+}
+
+async fn apply_change(
+    client: &Client,
+    app_id: &str,
+    planned: &PlannedChange,
+) -> Result<ApplyOutcome, AppStoreConnectError> {
+    match &planned.scope {
+        DiffScope::AppInfoLocalization { locale } => {
+            apply_app_info_localization(client, planned, locale).await
+        }
+        DiffScope::VersionLocalization { locale, .. } => {
+            apply_version_localization(client, planned, locale).await
+        }
+        DiffScope::Categories => {
+            let app_info_id = planned
+                .resource_id
+                .as_deref()
+                .ok_or_else(|| missing_id(planned))?;
+            client
+                .base()
+                .patch(
+                    &format!("appInfos/{}", app_info_id),
+                    categories_patch(app_info_id, &planned.changes),
+                )
+                .await?;
+            Ok(ApplyOutcome::Updated)
+        }
+        DiffScope::Availability => {
+            let mut territories: BTreeSet<String> = client
+                .availability()
+                .get_available_territories(app_id)
+                .await?
+                .into_iter()
+                .collect();
+            let mut available_in_new_territories = client
+                .availability()
+                .is_available_in_new_territories(app_id)
+                .await?;
+
+            merge_availability(
+                &planned.changes,
+                &mut territories,
+                &mut available_in_new_territories,
+            );
+
+            let territories: Vec<&str> = territories.iter().map(|t| t.as_str()).collect();
+            client
+                .availability()
+                .set(app_id, &territories, available_in_new_territories)
+                .await?;
+            Ok(ApplyOutcome::Updated)
+        }
+        DiffScope::SubscriptionAvailability { subscription_id } => {
+            let mut territories: BTreeSet<String> = client
+                .subscriptions()
+                .get_available_territories(subscription_id)
+                .await?
+                .into_iter()
+                .collect();
+            let mut available_in_new_territories = client
+                .subscriptions()
+                .is_available_in_new_territories(subscription_id)
+                .await?;
+
+            merge_availability(
+                &planned.changes,
+                &mut territories,
+                &mut available_in_new_territories,
+            );
+
+            let territories: Vec<&str> = territories.iter().map(|t| t.as_str()).collect();
+            client
+                .subscriptions()
+                .set_availability(subscription_id, &territories, available_in_new_territories)
+                .await?;
+            Ok(ApplyOutcome::Updated)
+        }
+        DiffScope::Pricing => {
+            let attributes = new_attributes(planned);
+            let price = attributes.get("basePrice");
+            let field = |name: &str| price.and_then(|p| p.get(name)).and_then(|v| v.as_str());
+            let (Some(territory), Some(price_point)) = (field("territory"), field("pricePoint"))
+            else {
+                return Err(missing_id(planned));
+            };
+            client
+                .pricing()
+                .set_base_price(app_id, territory, price_point)
+                .await?;
+            Ok(ApplyOutcome::Updated)
+        }
+        _ => Ok(ApplyOutcome::Skipped(format!(
+            "{} cannot be planned",
+            planned.scope
+        ))),
+    }
+}
+
+// Localization changes go through the localization APIs so locales are
+// normalized and field limits checked before anything is sent.
+async fn apply_app_info_localization(
+    client: &Client,
+    planned: &PlannedChange,
+    locale: &str,
+) -> Result<ApplyOutcome, AppStoreConnectError> {
+    let attributes = new_attributes(planned);
+    let text = |field: &str| attributes.get(field).and_then(|v| v.as_str());
+
+    match planned.operation {
+        Operation::Create => {
+            let app_info_id = planned
+                .parent_id
+                .as_deref()
+                .ok_or_else(|| missing_id(planned))?;
+            client
+                .localizations()
+                .create(
+                    app_info_id,
+                    locale,
+                    text("name"),
+                    text("subtitle"),
+                    text("privacyPolicyUrl"),
+                    text("privacyPolicyText"),
+                )
+                .await?;
+            Ok(ApplyOutcome::Created)
+        }
+        Operation::Update => {
+            let localization_id = planned
+                .resource_id
+                .as_deref()
+                .ok_or_else(|| missing_id(planned))?;
+            client
+                .localizations()
+                .update_fields(
+                    localization_id,
+                    &AppInfoLocalizationUpdate::from_value(&attributes),
+                )
+                .await?;
+            Ok(ApplyOutcome::Updated)
+        }
+        Operation::Delete => {
+            let localization_id = planned
+                .resource_id
+                .as_deref()
+                .ok_or_else(|| missing_id(planned))?;
+            client.localizations().delete(localization_id).await?;
+            Ok(ApplyOutcome::Deleted)
+        }
+    }
+}
+
+async fn apply_version_localization(
+    client: &Client,
+    planned: &PlannedChange,
+    locale: &str,
+) -> Result<ApplyOutcome, AppStoreConnectError> {
+    let attributes = new_attributes(planned);
+    let text = |field: &str| attributes.get(field).and_then(|v| v.as_str());
+
+    match planned.operation {
+        Operation::Create => {
+            let version_id = planned
+                .parent_id
+                .as_deref()
+                .ok_or_else(|| missing_id(planned))?;
+            client
+                .version_localizations()
+                .create(
+                    version_id,
+                    locale,
+                    text("description"),
+                    text("keywords"),
+                    text("marketingUrl"),
+                    text("promotionalText"),
+                    text("supportUrl"),
+                    text("whatsNew"),
+                )
+                .await?;
+            Ok(ApplyOutcome::Created)
+        }
+        Operation::Update => {
+            let localization_id = planned
+                .resource_id
+                .as_deref()
+                .ok_or_else(|| missing_id(planned))?;
+            client
+                .version_localizations()
+                .update_fields(
+                    localization_id,
+                    &VersionLocalizationUpdate::from_value(&attributes),
+                )
+                .await?;
+            Ok(ApplyOutcome::Updated)
+        }
+        Operation::Delete => {
+            let localization_id = planned
+                .resource_id
+                .as_deref()
+                .ok_or_else(|| missing_id(planned))?;
+            client
+                .version_localizations()
+                .delete(localization_id)
+                .await?;
+            Ok(ApplyOutcome::Deleted)
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::error::{AppStoreConnectError, TimeoutError};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[derive(Debug, Clone)]
+pub struct PollOptions {
+    pub timeout: Duration,
+    pub poll_interval: Duration,
+    pub max_interval: Duration,
+    pub backoff_factor: f64,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30 * 60),
+            poll_interval: Duration::from_secs(30),
+            max_interval: Duration::from_secs(5 * 60),
+            backoff_factor: 1.5,
+        }
+    }
+}
+
+impl PollOptions {
+    pub fn new(timeout: Duration, poll_interval: Duration) -> Self {
+        Self {
+            timeout,
+            poll_interval,
+            max_interval: poll_interval.max(Duration::from_secs(5 * 60)),
+            ..Default::default()
+        }
+    }
+
+    fn next_interval(&self, current: Duration) -> Duration {
+        current.mul_f64(self.backoff_factor).min(self.max_interval)
+    }
+}
+
+pub(crate) const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(60);
+
+// Delay before the next poll of a fixed-interval poller that was rate limited,
+// grown the way `poll_until` grows its interval: at least a minute, longer on
+// each further limit, capped at the usual maximum.
+pub(crate) fn rate_limit_delay(interval: Duration, previous: Option<Duration>) -> Duration {
+    PollOptions::new(Duration::ZERO, interval)
+        .next_interval(previous.unwrap_or(interval).max(RATE_LIMIT_BACKOFF))
+}
+
+// Waits for the next tick, or for the backoff delay after a rate limit. The
+// ticker is reset afterwards so missed ticks don't fire in a burst.
+pub(crate) async fn wait_for_tick(ticker: &mut tokio::time::Interval, backoff: Option<Duration>) {
+    match backoff {
+        Some(delay) => {
+            tokio::time::sleep(delay).await;
+            ticker.reset();
+        }
+        None => {
+            ticker.tick().await;
+        }
+    }
+}
+
+pub async fn poll_until<T, F, Fut>(
+    description: &str,
+    options: &PollOptions,
+    mut check: F,
+) -> Result<T, AppStoreConnectError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<T>, AppStoreConnectError>>,
+{
+    let deadline = Instant::now() + options.timeout;
+    let mut interval = options.poll_interval;
+
+    loop {
+        match check().await {
+            Ok(Some(value)) => return Ok(value),
+            Ok(None) => {}
+            Err(AppStoreConnectError::RateLimit(_)) => {
+                interval = options.next_interval(interval.max(RATE_LIMIT_BACKOFF));
+            }
+            Err(e) => return Err(e),
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(AppStoreConnectError::Timeout(TimeoutError::new(format!(
+                "Timed out after {:?} waiting for {}",
+                options.timeout, description
+            ))));
+        }
+
+        tokio::time::sleep(interval.min(deadline - now)).await;
+        interval = options.next_interval(interval);
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+// Apple's documented per-key budget, a sensible starting point for
+// `Client::with_rate_limit`.
+pub const DEFAULT_REQUESTS_PER_HOUR: u32 = 3600;
+pub const DEFAULT_BURST: u32 = 60;
+
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_second: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(requests_per_hour: u32, burst: u32) -> Self {
+        let capacity = burst.max(1) as f64;
+
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_second: requests_per_hour.max(1) as f64 / 3600.0,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.last_refill = now;
+    }
+}
+
+// Clones share one bucket, so every API handle cloned from a client draws
+// from the same budget. Without a bucket requests are never delayed.
+#[derive(Clone, Default)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Option<Bucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_hour: u32, burst: u32) -> Self {
+        Self {
+            bucket: Arc::new(Mutex::new(Some(Bucket::new(requests_per_hour, burst)))),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.bucket.lock().unwrap().is_some()
+    }
+
+    pub fn configure(&self, requests_per_hour: u32, burst: u32) {
+        let mut bucket = self.bucket.lock().unwrap();
+        match bucket.as_mut() {
+            Some(bucket) => {
+                bucket.refill();
+                bucket.capacity = burst.max(1) as f64;
+                bucket.tokens = bucket.tokens.min(bucket.capacity);
+                bucket.refill_per_second = requests_per_hour.max(1) as f64 / 3600.0;
+            }
+            None => *bucket = Some(Bucket::new(requests_per_hour, burst)),
+        }
+    }
+
+    pub fn disable(&self) {
+        *self.bucket.lock().unwrap() = None;
+    }
+
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let Some(bucket) = bucket.as_mut() else {
+                    return;
+                };
+                bucket.refill();
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.refill_per_second)
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
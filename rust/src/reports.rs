@@ -0,0 +1,511 @@
+use crate::api::sales_reports::{SalesReportFrequency, SalesReportQuery};
+use crate::client::Client;
+use crate::error::{AppStoreConnectError, ValidationError};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use flate2::read::MultiGzDecoder;
+use futures_core::Stream;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+const STREAM_BUFFER_ROWS: usize = 1024;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SalesReportRow {
+    pub provider: Option<String>,
+    pub provider_country: Option<String>,
+    pub sku: String,
+    pub developer: Option<String>,
+    pub title: Option<String>,
+    pub version: Option<String>,
+    pub product_type_identifier: Option<String>,
+    pub units: i64,
+    pub developer_proceeds: f64,
+    pub begin_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub customer_currency: Option<String>,
+    pub country_code: Option<String>,
+    pub currency_of_proceeds: Option<String>,
+    pub apple_identifier: Option<String>,
+    pub customer_price: Option<f64>,
+    pub promo_code: Option<String>,
+    pub parent_identifier: Option<String>,
+    pub subscription: Option<String>,
+    pub period: Option<String>,
+    pub category: Option<String>,
+    pub device: Option<String>,
+    pub supported_platforms: Option<String>,
+    pub proceeds_reason: Option<String>,
+    pub preserved_pricing: Option<String>,
+    pub client: Option<String>,
+    pub order_type: Option<String>,
+}
+
+impl SalesReportRow {
+    pub fn total_proceeds(&self) -> f64 {
+        self.developer_proceeds * self.units as f64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SalesReportParser {
+    columns: HashMap<String, usize>,
+}
+
+impl SalesReportParser {
+    pub fn from_header(header: &str) -> Result<Self, AppStoreConnectError> {
+        let columns: HashMap<String, usize> = header
+            .trim_start_matches('\u{feff}')
+            .trim_end_matches(['\r', '\n'])
+            .split('\t')
+            .enumerate()
+            .map(|(index, name)| (column_key(name), index))
+            .collect();
+
+        let missing: Vec<String> = ["sku", "units", "developer proceeds"]
+            .iter()
+            .filter(|name| !columns.contains_key(**name))
+            .map(|name| format!("missing column '{}'", name))
+            .collect();
+        if !missing.is_empty() {
+            return Err(AppStoreConnectError::Validation(
+                ValidationError::with_issues("Unrecognized sales report header", missing),
+            ));
+        }
+
+        Ok(Self { columns })
+    }
+
+    pub fn parse_line(
+        &self,
+        line_number: usize,
+        line: &str,
+    ) -> Result<Option<SalesReportRow>, AppStoreConnectError> {
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+
+        let cells: Vec<&str> = line.split('\t').collect();
+        let text = |names: &[&str]| -> Option<String> {
+            names
+                .iter()
+                .find_map(|name| self.columns.get(*name))
+                .and_then(|index| cells.get(*index))
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+                .map(|value| value.to_string())
+        };
+        let invalid = |column: &str, value: &str| {
+            AppStoreConnectError::Validation(ValidationError::new(format!(
+                "line {}: invalid {} '{}'",
+                line_number, column, value
+            )))
+        };
+        let number = |names: &[&str]| -> Result<Option<f64>, AppStoreConnectError> {
+            text(names)
+                .map(|value| {
+                    value
+                        .replace(',', "")
+                        .parse::<f64>()
+                        .map_err(|_| invalid(names[0], &value))
+                })
+                .transpose()
+        };
+        let date = |names: &[&str]| -> Result<Option<NaiveDate>, AppStoreConnectError> {
+            text(names)
+                .map(|value| {
+                    NaiveDate::parse_from_str(&value, "%m/%d/%Y")
+                        .or_else(|_| NaiveDate::parse_from_str(&value, "%Y-%m-%d"))
+                        .map_err(|_| invalid(names[0], &value))
+                })
+                .transpose()
+        };
+
+        let sku = text(&["sku"]).ok_or_else(|| invalid("sku", ""))?;
+
+        Ok(Some(SalesReportRow {
+            provider: text(&["provider"]),
+            provider_country: text(&["provider country"]),
+            sku,
+            developer: text(&["developer"]),
+            title: text(&["title"]),
+            version: text(&["version"]),
+            product_type_identifier: text(&["product type identifier"]),
+            units: number(&["units"])?.unwrap_or_default().round() as i64,
+            developer_proceeds: number(&["developer proceeds"])?.unwrap_or_default(),
+            begin_date: date(&["begin date"])?,
+            end_date: date(&["end date"])?,
+            customer_currency: text(&["customer currency"]),
+            country_code: text(&["country code"]),
+            currency_of_proceeds: text(&["currency of proceeds"]),
+            apple_identifier: text(&["apple identifier"]),
+            customer_price: number(&["customer price"])?,
+            promo_code: text(&["promo code"]),
+            parent_identifier: text(&["parent identifier"]),
+            subscription: text(&["subscription"]),
+            period: text(&["period"]),
+            category: text(&["category"]),
+            device: text(&["device"]),
+            supported_platforms: text(&["supported platforms"]),
+            proceeds_reason: text(&["proceeds reason"]),
+            preserved_pricing: text(&["preserved pricing"]),
+            client: text(&["client"]),
+            order_type: text(&["order type"]),
+        }))
+    }
+}
+
+pub fn parse_sales_report(content: &str) -> Result<Vec<SalesReportRow>, AppStoreConnectError> {
+    let mut lines = content.lines().enumerate();
+    let Some((_, header)) = lines.next() else {
+        return Ok(Vec::new());
+    };
+    let parser = SalesReportParser::from_header(header)?;
+
+    let mut rows = Vec::new();
+    for (index, line) in lines {
+        if let Some(row) = parser.parse_line(index + 1, line)? {
+            rows.push(row);
+        }
+    }
+    Ok(rows)
+}
+
+// Report versions differ in capitalization and occasionally in naming
+// ("Developer Proceeds" vs "Developer Proceeds (per item)"), so headers are
+// compared on a lowercased name with any parenthesized suffix dropped.
+fn column_key(name: &str) -> String {
+    let name = name.trim();
+    let name = name.split('(').next().unwrap_or(name);
+    name.trim().to_lowercase()
+}
+
+pub struct SalesReportStream {
+    receiver: mpsc::Receiver<Result<SalesReportRow, AppStoreConnectError>>,
+}
+
+impl SalesReportStream {
+    pub fn from_reader(reader: impl Read + Send + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel(STREAM_BUFFER_ROWS);
+
+        tokio::task::spawn_blocking(move || {
+            let mut reader = BufReader::new(reader);
+            let gzipped = reader.fill_buf().map(|buf| buf.starts_with(&[0x1f, 0x8b]));
+            let lines: Box<dyn BufRead> = match gzipped {
+                Ok(true) => Box::new(BufReader::new(MultiGzDecoder::new(reader))),
+                Ok(false) => Box::new(reader),
+                Err(e) => {
+                    let _ = sender.blocking_send(Err(e.into()));
+                    return;
+                }
+            };
+
+            let mut parser: Option<SalesReportParser> = None;
+            for (index, line) in lines.lines().enumerate() {
+                let result = match line {
+                    Ok(line) if is_sales_report_header(&line) => {
+                        // Concatenated backfills repeat the header for every report.
+                        match SalesReportParser::from_header(&line) {
+                            Ok(next) => {
+                                parser = Some(next);
+                                continue;
+                            }
+                            Err(e) => Err(e),
+                        }
+                    }
+                    Ok(line) => match &parser {
+                        Some(parser) => match parser.parse_line(index + 1, &line) {
+                            Ok(Some(row)) => Ok(row),
+                            Ok(None) => continue,
+                            Err(e) => Err(e),
+                        },
+                        None if line.trim().is_empty() => continue,
+                        None => Err(AppStoreConnectError::Validation(ValidationError::new(
+                            format!("line {}: expected a sales report header", index + 1),
+                        ))),
+                    },
+                    Err(e) => Err(e.into()),
+                };
+
+                let failed = result.is_err();
+                if sender.blocking_send(result).is_err() || failed {
+                    return;
+                }
+            }
+        });
+
+        Self { receiver }
+    }
+
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, AppStoreConnectError> {
+        let file = tokio::fs::File::open(path).await?.into_std().await;
+        Ok(Self::from_reader(file))
+    }
+
+    pub async fn next(&mut self) -> Option<Result<SalesReportRow, AppStoreConnectError>> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for SalesReportStream {
+    type Item = Result<SalesReportRow, AppStoreConnectError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+fn is_sales_report_header(line: &str) -> bool {
+    let keys: HashSet<String> = line
+        .trim_start_matches('\u{feff}')
+        .split('\t')
+        .map(column_key)
+        .collect();
+    keys.contains("sku") && keys.contains("units")
+}
+
+pub fn gunzip(data: &[u8]) -> Result<String, AppStoreConnectError> {
+    if !data.starts_with(&[0x1f, 0x8b]) {
+        return Ok(String::from_utf8_lossy(data).into_owned());
+    }
+
+    let mut content = String::new();
+    MultiGzDecoder::new(data).read_to_string(&mut content)?;
+    Ok(content)
+}
+
+// Apple's fiscal year starts the day after the last Saturday of September and is
+// split into quarters of 5-4-4 weeks; September absorbs the 53rd week when there is one.
+pub fn fiscal_year_start(fiscal_year: i32) -> NaiveDate {
+    let mut day = NaiveDate::from_ymd_opt(fiscal_year - 1, 9, 30).unwrap_or_default();
+    while day.weekday() != Weekday::Sat {
+        day -= Duration::days(1);
+    }
+    day + Duration::days(1)
+}
+
+pub fn fiscal_month_range(year: i32, month: u32) -> Option<(NaiveDate, NaiveDate)> {
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+
+    let fiscal_year = if month >= 10 { year + 1 } else { year };
+    let index = (month + 2) % 12;
+    let weeks = |i: u32| if i.is_multiple_of(3) { 5 } else { 4 };
+    let offset: i64 = (0..index).map(|i| weeks(i) * 7).sum();
+    let start = fiscal_year_start(fiscal_year) + Duration::days(offset);
+    let end = if index == 11 {
+        fiscal_year_start(fiscal_year + 1) - Duration::days(1)
+    } else {
+        start + Duration::days(weeks(index) * 7 - 1)
+    };
+
+    Some((start, end))
+}
+
+pub fn fiscal_month_for(date: NaiveDate) -> (i32, u32) {
+    let mut year = date.year();
+    let mut month = date.month();
+
+    // The fiscal month containing a date is either its calendar month or the next one.
+    for _ in 0..2 {
+        if let Some((start, end)) = fiscal_month_range(year, month) {
+            if date < start {
+                (year, month) = if month == 1 {
+                    (year - 1, 12)
+                } else {
+                    (year, month - 1)
+                };
+            } else if date > end {
+                (year, month) = if month == 12 {
+                    (year + 1, 1)
+                } else {
+                    (year, month + 1)
+                };
+            } else {
+                break;
+            }
+        }
+    }
+
+    (year, month)
+}
+
+pub fn fiscal_months_between(start: NaiveDate, end: NaiveDate) -> Vec<String> {
+    let mut months = BTreeSet::new();
+    let mut day = start;
+
+    while day <= end {
+        let (year, month) = fiscal_month_for(day);
+        months.insert(format!("{:04}-{:02}", year, month));
+        match fiscal_month_range(year, month) {
+            Some((_, month_end)) => day = month_end + Duration::days(1),
+            None => break,
+        }
+    }
+
+    months.into_iter().collect()
+}
+
+pub fn report_dates(
+    frequency: SalesReportFrequency,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Vec<String> {
+    let mut dates = BTreeSet::new();
+    let mut day = start;
+
+    while day <= end {
+        dates.insert(match frequency {
+            SalesReportFrequency::Daily => day.format("%Y-%m-%d").to_string(),
+            SalesReportFrequency::Weekly => {
+                let days_to_sunday = 6 - day.weekday().num_days_from_monday();
+                (day + Duration::days(days_to_sunday.into()))
+                    .format("%Y-%m-%d")
+                    .to_string()
+            }
+            SalesReportFrequency::Monthly => day.format("%Y-%m").to_string(),
+            SalesReportFrequency::Yearly => day.format("%Y").to_string(),
+        });
+        day += Duration::days(1);
+    }
+
+    dates.into_iter().collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SalesTotal {
+    pub app_sku: String,
+    pub sku: String,
+    pub territory: String,
+    pub currency: String,
+    pub units: i64,
+    pub proceeds: f64,
+}
+
+pub fn dedupe_rows(rows: Vec<SalesReportRow>) -> Vec<SalesReportRow> {
+    let mut seen = HashSet::new();
+    rows.into_iter()
+        .filter(|row| seen.insert(serde_json::to_string(row).unwrap_or_default()))
+        .collect()
+}
+
+pub fn aggregate_sales(rows: &[SalesReportRow]) -> Vec<SalesTotal> {
+    let mut totals: BTreeMap<(String, String, String, String), SalesTotal> = BTreeMap::new();
+
+    for row in rows {
+        let app_sku = row
+            .parent_identifier
+            .clone()
+            .unwrap_or_else(|| row.sku.clone());
+        let territory = row.country_code.clone().unwrap_or_default();
+        let currency = row.currency_of_proceeds.clone().unwrap_or_default();
+        let key = (
+            app_sku.clone(),
+            row.sku.clone(),
+            territory.clone(),
+            currency.clone(),
+        );
+
+        let total = totals.entry(key).or_insert_with(|| SalesTotal {
+            app_sku,
+            sku: row.sku.clone(),
+            territory,
+            currency,
+            units: 0,
+            proceeds: 0.0,
+        });
+        total.units += row.units;
+        total.proceeds += row.total_proceeds();
+    }
+
+    totals.into_values().collect()
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReportRun {
+    pub report_dates: Vec<String>,
+    pub missing: Vec<String>,
+    pub rows: Vec<SalesReportRow>,
+    pub totals: Vec<SalesTotal>,
+}
+
+#[derive(Clone)]
+pub struct ReportRunner {
+    client: Client,
+    vendor_number: String,
+    frequency: SalesReportFrequency,
+}
+
+impl ReportRunner {
+    pub fn new(
+        client: Client,
+        vendor_number: impl Into<String>,
+        frequency: SalesReportFrequency,
+    ) -> Self {
+        Self {
+            client,
+            vendor_number: vendor_number.into(),
+            frequency,
+        }
+    }
+
+    pub async fn run(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<ReportRun, AppStoreConnectError> {
+        let mut run = ReportRun {
+            report_dates: report_dates(self.frequency, start, end),
+            ..Default::default()
+        };
+        let mut rows = Vec::new();
+
+        for report_date in &run.report_dates {
+            let query = SalesReportQuery::summary(&self.vendor_number, self.frequency, report_date);
+            match self.client.sales_reports().get_sales_report(&query).await {
+                Ok(report) => rows.extend(report),
+                // Reports that Apple hasn't generated yet (or days without sales) come back as 404.
+                Err(AppStoreConnectError::NotFound(_)) => run.missing.push(report_date.clone()),
+                Err(e) => return Err(e),
+            }
+        }
+
+        run.rows = dedupe_rows(rows);
+        run.totals = aggregate_sales(&run.rows);
+        Ok(run)
+    }
+
+    pub async fn download_finance_reports(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        region_code: &str,
+        dest_dir: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>, AppStoreConnectError> {
+        let dest_dir = dest_dir.as_ref();
+        tokio::fs::create_dir_all(dest_dir).await?;
+        let mut paths = Vec::new();
+
+        for fiscal_month in fiscal_months_between(start, end) {
+            let path = dest_dir.join(format!("finance_{}_{}.txt", region_code, fiscal_month));
+            match self
+                .client
+                .sales_reports()
+                .save_finance_report(&self.vendor_number, region_code, &fiscal_month, &path)
+                .await
+            {
+                Ok(()) => paths.push(path),
+                Err(AppStoreConnectError::NotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(paths)
+    }
+}
@@ -0,0 +1,186 @@
+use crate::api::certificates::Certificate;
+use crate::api::profiles::Profile;
+use crate::client::Client;
+use crate::error::AppStoreConnectError;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SigningAssetKind {
+    Certificate,
+    Profile,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SigningIssueKind {
+    Expired,
+    ExpiringSoon { days_left: i64 },
+    RevokedCertificate { serial_number: String },
+    Invalid,
+    Unreadable(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SigningIssue {
+    pub asset: SigningAssetKind,
+    pub id: String,
+    pub name: Option<String>,
+    pub expires: Option<DateTime<Utc>>,
+    pub kind: SigningIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SigningAudit {
+    pub checked_at: DateTime<Utc>,
+    pub within_days: i64,
+    pub certificates_checked: usize,
+    pub profiles_checked: usize,
+    pub issues: Vec<SigningIssue>,
+}
+
+impl SigningAudit {
+    pub async fn run(client: &Client, within_days: i64) -> Result<Self, AppStoreConnectError> {
+        let certificates = client.certificates().get_all(&[], None, None).await?;
+        let profiles = client.profiles().get_all(&[], None, false).await?;
+
+        Ok(Self::from_assets(
+            &certificates,
+            &profiles,
+            Utc::now(),
+            within_days,
+        ))
+    }
+
+    // Revoked certificates drop out of the certificates list, so a profile
+    // signed by a serial that isn't in `certificates` references a revoked
+    // (or deleted) certificate.
+    pub fn from_assets(
+        certificates: &[Certificate],
+        profiles: &[Profile],
+        now: DateTime<Utc>,
+        within_days: i64,
+    ) -> Self {
+        let mut issues = Vec::new();
+        let mut active_serials = HashSet::new();
+
+        for certificate in certificates {
+            let expires = match certificate.info() {
+                Ok(info) => {
+                    active_serials.insert(normalize_serial(&info.serial_number));
+                    Some(info.not_after)
+                }
+                Err(_) => certificate
+                    .expiration_date
+                    .as_deref()
+                    .and_then(parse_api_date),
+            };
+            if let Some(serial) = &certificate.serial_number {
+                active_serials.insert(normalize_serial(serial));
+            }
+
+            if let Some(kind) = expiry_issue(expires, now, within_days) {
+                issues.push(SigningIssue {
+                    asset: SigningAssetKind::Certificate,
+                    id: certificate.id.clone(),
+                    name: certificate
+                        .display_name
+                        .clone()
+                        .or(certificate.name.clone()),
+                    expires,
+                    kind,
+                });
+            }
+        }
+
+        for profile in profiles {
+            let issue = |expires, kind| SigningIssue {
+                asset: SigningAssetKind::Profile,
+                id: profile.id.clone(),
+                name: profile.name.clone(),
+                expires,
+                kind,
+            };
+
+            let (expires, signed_by) = match profile.decode() {
+                Ok(info) => (
+                    Some(info.expiration_date),
+                    info.developer_certificates
+                        .into_iter()
+                        .map(|certificate| certificate.serial_number)
+                        .collect(),
+                ),
+                Err(e) => {
+                    let expires = profile.expiration_date.as_deref().and_then(parse_api_date);
+                    if expires.is_none() {
+                        issues.push(issue(None, SigningIssueKind::Unreadable(e.to_string())));
+                    }
+                    (expires, Vec::new())
+                }
+            };
+
+            if let Some(kind) = expiry_issue(expires, now, within_days) {
+                issues.push(issue(expires, kind));
+            }
+            for serial_number in signed_by {
+                if !active_serials.contains(&normalize_serial(&serial_number)) {
+                    issues.push(issue(
+                        expires,
+                        SigningIssueKind::RevokedCertificate { serial_number },
+                    ));
+                }
+            }
+            if profile.profile_state.is_some() && !profile.is_active() {
+                issues.push(issue(expires, SigningIssueKind::Invalid));
+            }
+        }
+
+        issues.sort_by_key(|issue| issue.expires);
+
+        Self {
+            checked_at: now,
+            within_days,
+            certificates_checked: certificates.len(),
+            profiles_checked: profiles.len(),
+            issues,
+        }
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn issues_for(&self, asset: SigningAssetKind) -> Vec<&SigningIssue> {
+        self.issues.iter().filter(|i| i.asset == asset).collect()
+    }
+}
+
+fn expiry_issue(
+    expires: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    within_days: i64,
+) -> Option<SigningIssueKind> {
+    let expires = expires?;
+    if expires <= now {
+        Some(SigningIssueKind::Expired)
+    } else if expires <= now + Duration::days(within_days) {
+        Some(SigningIssueKind::ExpiringSoon {
+            days_left: (expires - now).num_days(),
+        })
+    } else {
+        None
+    }
+}
+
+fn normalize_serial(serial: &str) -> String {
+    serial.trim_start_matches('0').to_uppercase()
+}
+
+// App Store Connect dates look like `2025-05-01T12:00:00.000+0000`, which
+// isn't quite RFC 3339.
+fn parse_api_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
@@ -0,0 +1,611 @@
+use crate::api::pricing::{schedule_base_territory, PriceTimelineEntry};
+use crate::client::Client;
+use crate::error::{AppStoreConnectError, ValidationError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+pub type FieldMap = BTreeMap<String, Value>;
+
+pub const APP_INFO_LOCALIZATION_FIELDS: &[&str] =
+    &["name", "subtitle", "privacyPolicyUrl", "privacyPolicyText"];
+
+pub const VERSION_LOCALIZATION_FIELDS: &[&str] = &[
+    "description",
+    "keywords",
+    "marketingUrl",
+    "promotionalText",
+    "supportUrl",
+    "whatsNew",
+];
+
+pub const EDITABLE_VERSION_STATES: &[&str] = &[
+    "PREPARE_FOR_SUBMISSION",
+    "DEVELOPER_REJECTED",
+    "REJECTED",
+    "METADATA_REJECTED",
+    "INVALID_BINARY",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Snapshot {
+    pub app_id: String,
+    pub exported_at: DateTime<Utc>,
+    pub app: FieldMap,
+    pub app_infos: Vec<AppInfoSnapshot>,
+    pub versions: Vec<VersionSnapshot>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing: Option<PricingSnapshot>,
+    pub availability: AvailabilitySnapshot,
+}
+
+// Manual prices still in effect or scheduled, so the schedule can be
+// recreated. Ended prices are history and are left out.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PricingSnapshot {
+    pub base_territory: String,
+    pub manual_prices: Vec<PriceTimelineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AppInfoSnapshot {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    pub categories: Value,
+    pub localizations: BTreeMap<String, FieldMap>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VersionSnapshot {
+    pub id: String,
+    pub version_string: String,
+    pub platform: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    pub attributes: FieldMap,
+    pub localizations: BTreeMap<String, FieldMap>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct AvailabilitySnapshot {
+    pub available_in_new_territories: bool,
+    pub territories: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    Json,
+    Yaml,
+}
+
+impl SnapshotFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("yaml") | Some("yml") => SnapshotFormat::Yaml,
+            _ => SnapshotFormat::Json,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ApplyOptions {
+    pub dry_run: bool,
+    pub localizations: bool,
+    pub categories: bool,
+    pub versions: bool,
+}
+
+impl Default for ApplyOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            localizations: true,
+            categories: true,
+            versions: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApplyOutcome {
+    Created,
+    Updated,
+    Deleted,
+    Skipped(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceResult {
+    pub resource: String,
+    pub outcome: ApplyOutcome,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ApplyReport {
+    pub dry_run: bool,
+    pub results: Vec<ResourceResult>,
+}
+
+impl ApplyReport {
+    pub(crate) fn record(&mut self, resource: impl Into<String>, outcome: ApplyOutcome) {
+        self.results.push(ResourceResult {
+            resource: resource.into(),
+            outcome,
+        });
+    }
+
+    pub(crate) fn record_result(
+        &mut self,
+        resource: impl Into<String>,
+        result: Result<ApplyOutcome, AppStoreConnectError>,
+    ) {
+        let outcome = result.unwrap_or_else(|e| ApplyOutcome::Failed(e.to_string()));
+        self.record(resource, outcome);
+    }
+
+    pub fn failures(&self) -> Vec<&ResourceResult> {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, ApplyOutcome::Failed(_)))
+            .collect()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failures().is_empty()
+    }
+}
+
+impl Snapshot {
+    pub async fn capture(client: &Client, app_id: &str) -> Result<Self, AppStoreConnectError> {
+        let app = client.apps().get_app(app_id).await?;
+
+        let mut app_infos = Vec::new();
+        for app_info in client.apps().get_app_infos(app_id).await? {
+            let id = resource_id(&app_info)?;
+            let categories = client.categories().get_app_categories(&id).await?;
+            let localizations = client.localizations().get_all(&id).await?;
+
+            app_infos.push(AppInfoSnapshot {
+                state: string_attribute(&app_info, "appStoreState"),
+                categories,
+                localizations: by_locale(&localizations),
+                id,
+            });
+        }
+
+        let mut versions = Vec::new();
+        for version in client.versions().get_all(app_id).await? {
+            let id = resource_id(&version)?;
+            let localizations = client.version_localizations().get_all(&id).await?;
+
+            versions.push(VersionSnapshot {
+                version_string: string_attribute(&version, "versionString").unwrap_or_default(),
+                platform: string_attribute(&version, "platform").unwrap_or_default(),
+                state: string_attribute(&version, "appStoreState"),
+                attributes: field_map(version.get("attributes"), &[]),
+                localizations: by_locale(&localizations),
+                id,
+            });
+        }
+
+        let pricing = match client.pricing().get_schedule(app_id).await? {
+            Some(schedule) => {
+                let schedule_id = resource_id(&schedule)?;
+                let today = Utc::now().date_naive();
+                let manual_prices = client
+                    .pricing()
+                    .get_manual_price_timeline(&schedule_id, None)
+                    .await?
+                    .into_iter()
+                    .filter(|price| price.end_date.is_none_or(|end| end > today))
+                    .collect();
+
+                Some(PricingSnapshot {
+                    base_territory: schedule_base_territory(&schedule)
+                        .unwrap_or_default()
+                        .to_string(),
+                    manual_prices,
+                })
+            }
+            None => None,
+        };
+
+        let mut territories = client
+            .availability()
+            .get_available_territories(app_id)
+            .await?;
+        territories.sort();
+
+        let availability = AvailabilitySnapshot {
+            available_in_new_territories: client
+                .availability()
+                .is_available_in_new_territories(app_id)
+                .await?,
+            territories,
+        };
+
+        Ok(Self {
+            app_id: app_id.to_string(),
+            exported_at: Utc::now(),
+            app: field_map(app.get("attributes"), &[]),
+            app_infos,
+            versions,
+            pricing,
+            availability,
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, AppStoreConnectError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn to_yaml(&self) -> Result<String, AppStoreConnectError> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, AppStoreConnectError> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self, AppStoreConnectError> {
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), AppStoreConnectError> {
+        let path = path.as_ref();
+        let content = match SnapshotFormat::from_path(path) {
+            SnapshotFormat::Json => self.to_json()?,
+            SnapshotFormat::Yaml => self.to_yaml()?,
+        };
+
+        tokio::fs::write(path, content).await?;
+        Ok(())
+    }
+
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self, AppStoreConnectError> {
+        let path = path.as_ref();
+        let content = tokio::fs::read_to_string(path).await?;
+
+        match SnapshotFormat::from_path(path) {
+            SnapshotFormat::Json => Self::from_json(&content),
+            SnapshotFormat::Yaml => Self::from_yaml(&content),
+        }
+    }
+
+    pub fn version(&self, version_string: &str, platform: &str) -> Option<&VersionSnapshot> {
+        self.versions
+            .iter()
+            .find(|v| v.version_string == version_string && v.platform == platform)
+    }
+
+    pub fn editable_app_info(&self) -> Option<&AppInfoSnapshot> {
+        self.app_infos
+            .iter()
+            .find(|info| info.state.as_deref() != Some("READY_FOR_SALE"))
+            .or_else(|| self.app_infos.first())
+    }
+
+    pub async fn apply(
+        &self,
+        client: &Client,
+        app_id: &str,
+        options: &ApplyOptions,
+    ) -> Result<ApplyReport, AppStoreConnectError> {
+        let mut report = ApplyReport {
+            dry_run: options.dry_run,
+            results: Vec::new(),
+        };
+
+        let live = Snapshot::capture(client, app_id).await?;
+
+        if options.localizations || options.categories {
+            match (self.editable_app_info(), live.editable_app_info()) {
+                (Some(source), Some(target)) => {
+                    if options.localizations {
+                        self.apply_app_info_localizations(
+                            client,
+                            source,
+                            target,
+                            options,
+                            &mut report,
+                        )
+                        .await;
+                    }
+                    if options.categories {
+                        let resource = format!("appInfo:{}:categories", target.id);
+                        if source.categories == target.categories {
+                            report.record(resource, ApplyOutcome::Skipped("unchanged".into()));
+                        } else if options.dry_run {
+                            report.record(resource, ApplyOutcome::Updated);
+                        } else {
+                            let result = apply_categories(client, &target.id, &source.categories)
+                                .await
+                                .map(|_| ApplyOutcome::Updated);
+                            report.record_result(resource, result);
+                        }
+                    }
+                }
+                _ => report.record(
+                    "appInfo",
+                    ApplyOutcome::Skipped("no app info available".into()),
+                ),
+            }
+        }
+
+        if options.versions {
+            for source in &self.versions {
+                let resource = format!(
+                    "appStoreVersion:{}:{}",
+                    source.platform, source.version_string
+                );
+
+                let Some(target) = live.version(&source.version_string, &source.platform) else {
+                    report.record(resource, ApplyOutcome::Skipped("version not found".into()));
+                    continue;
+                };
+
+                let state = target.state.as_deref().unwrap_or_default();
+                if !EDITABLE_VERSION_STATES.contains(&state) {
+                    report.record(
+                        resource,
+                        ApplyOutcome::Skipped(format!("version is not editable ({})", state)),
+                    );
+                    continue;
+                }
+
+                self.apply_version(client, source, target, options, &mut report)
+                    .await;
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn apply_app_info_localizations(
+        &self,
+        client: &Client,
+        source: &AppInfoSnapshot,
+        target: &AppInfoSnapshot,
+        options: &ApplyOptions,
+        report: &mut ApplyReport,
+    ) {
+        let existing = match client.localizations().get_all(&target.id).await {
+            Ok(existing) => index_by_locale(existing),
+            Err(e) => {
+                report.record(
+                    format!("appInfo:{}:localizations", target.id),
+                    ApplyOutcome::Failed(e.to_string()),
+                );
+                return;
+            }
+        };
+
+        for (locale, fields) in &source.localizations {
+            let resource = format!("appInfoLocalization:{}", locale);
+            let field = |name: &str| fields.get(name).and_then(|v| v.as_str());
+
+            let result = match existing.get(locale) {
+                Some(_) if target.localizations.get(locale) == Some(fields) => {
+                    Ok(ApplyOutcome::Skipped("unchanged".into()))
+                }
+                Some(_) if options.dry_run => Ok(ApplyOutcome::Updated),
+                None if options.dry_run => Ok(ApplyOutcome::Created),
+                Some((localization_id, _)) => client
+                    .localizations()
+                    .update(
+                        localization_id,
+                        field("name"),
+                        field("subtitle"),
+                        field("privacyPolicyUrl"),
+                        field("privacyPolicyText"),
+                    )
+                    .await
+                    .map(|_| ApplyOutcome::Updated),
+                None => client
+                    .localizations()
+                    .create(
+                        &target.id,
+                        locale,
+                        field("name"),
+                        field("subtitle"),
+                        field("privacyPolicyUrl"),
+                        field("privacyPolicyText"),
+                    )
+                    .await
+                    .map(|_| ApplyOutcome::Created),
+            };
+
+            report.record_result(resource, result);
+        }
+    }
+
+    async fn apply_version(
+        &self,
+        client: &Client,
+        source: &VersionSnapshot,
+        target: &VersionSnapshot,
+        options: &ApplyOptions,
+        report: &mut ApplyReport,
+    ) {
+        let resource = format!("appStoreVersion:{}", target.id);
+        let attribute = |name: &str| source.attributes.get(name).and_then(|v| v.as_str());
+
+        let metadata_fields = ["copyright", "releaseType"];
+        let unchanged = metadata_fields
+            .iter()
+            .all(|name| source.attributes.get(*name) == target.attributes.get(*name));
+
+        let result = if unchanged {
+            Ok(ApplyOutcome::Skipped("unchanged".into()))
+        } else if options.dry_run {
+            Ok(ApplyOutcome::Updated)
+        } else {
+            client
+                .versions()
+                .update(
+                    &target.id,
+                    None,
+                    attribute("copyright"),
+                    attribute("releaseType"),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .map(|_| ApplyOutcome::Updated)
+        };
+        report.record_result(resource, result);
+
+        if !options.localizations {
+            return;
+        }
+
+        let existing = match client.version_localizations().get_all(&target.id).await {
+            Ok(existing) => index_by_locale(existing),
+            Err(e) => {
+                report.record(
+                    format!("appStoreVersion:{}:localizations", target.id),
+                    ApplyOutcome::Failed(e.to_string()),
+                );
+                return;
+            }
+        };
+
+        for (locale, fields) in &source.localizations {
+            let resource = format!(
+                "appStoreVersionLocalization:{}:{}",
+                source.version_string, locale
+            );
+            let field = |name: &str| fields.get(name).and_then(|v| v.as_str());
+
+            let result = match existing.get(locale) {
+                Some(_) if target.localizations.get(locale) == Some(fields) => {
+                    Ok(ApplyOutcome::Skipped("unchanged".into()))
+                }
+                Some(_) if options.dry_run => Ok(ApplyOutcome::Updated),
+                None if options.dry_run => Ok(ApplyOutcome::Created),
+                Some((localization_id, _)) => client
+                    .version_localizations()
+                    .update(
+                        localization_id,
+                        field("description"),
+                        field("keywords"),
+                        field("marketingUrl"),
+                        field("promotionalText"),
+                        field("supportUrl"),
+                        field("whatsNew"),
+                    )
+                    .await
+                    .map(|_| ApplyOutcome::Updated),
+                None => client
+                    .version_localizations()
+                    .create(
+                        &target.id,
+                        locale,
+                        field("description"),
+                        field("keywords"),
+                        field("marketingUrl"),
+                        field("promotionalText"),
+                        field("supportUrl"),
+                        field("whatsNew"),
+                    )
+                    .await
+                    .map(|_| ApplyOutcome::Created),
+            };
+
+            report.record_result(resource, result);
+        }
+    }
+}
+
+async fn apply_categories(
+    client: &Client,
+    app_info_id: &str,
+    categories: &Value,
+) -> Result<Value, AppStoreConnectError> {
+    let category_id = |name: &str| {
+        categories
+            .get(name)
+            .and_then(|c| c.get("id"))
+            .and_then(|i| i.as_str())
+    };
+    let subcategory = |name: &str| categories.get(name).and_then(|c| c.as_str());
+
+    client
+        .categories()
+        .update_app_categories(
+            app_info_id,
+            category_id("primaryCategory"),
+            category_id("secondaryCategory"),
+            subcategory("primarySubcategoryOne"),
+            subcategory("primarySubcategoryTwo"),
+            subcategory("secondarySubcategoryOne"),
+            subcategory("secondarySubcategoryTwo"),
+        )
+        .await
+}
+
+pub(crate) fn resource_id(resource: &Value) -> Result<String, AppStoreConnectError> {
+    resource
+        .get("id")
+        .and_then(|i| i.as_str())
+        .map(|id| id.to_string())
+        .ok_or_else(|| {
+            AppStoreConnectError::Validation(ValidationError::new("Resource is missing an ID"))
+        })
+}
+
+pub(crate) fn string_attribute(resource: &Value, name: &str) -> Option<String> {
+    resource
+        .get("attributes")
+        .and_then(|a| a.get(name))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
+
+fn field_map(attributes: Option<&Value>, skip: &[&str]) -> FieldMap {
+    attributes
+        .and_then(|a| a.as_object())
+        .map(|obj| {
+            obj.iter()
+                .filter(|(key, _)| !skip.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub(crate) fn by_locale(localizations: &[Value]) -> BTreeMap<String, FieldMap> {
+    let mut result = BTreeMap::new();
+
+    for localization in localizations {
+        if let Some(locale) = string_attribute(localization, "locale") {
+            result.insert(
+                locale,
+                field_map(localization.get("attributes"), &["locale"]),
+            );
+        }
+    }
+
+    result
+}
+
+pub(crate) fn index_by_locale(localizations: Vec<Value>) -> HashMap<String, (String, Value)> {
+    let mut result = HashMap::new();
+
+    for localization in localizations {
+        if let (Some(locale), Some(id)) = (
+            string_attribute(&localization, "locale"),
+            localization.get("id").and_then(|i| i.as_str()),
+        ) {
+            result.insert(locale, (id.to_string(), localization));
+        }
+    }
+
+    result
+}
@@ -0,0 +1,247 @@
+use crate::base::BaseAPI;
+use crate::error::{AppStoreConnectError, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UploadOperation {
+    pub method: String,
+    pub url: String,
+    pub length: u64,
+    pub offset: u64,
+    pub request_headers: Vec<(String, String)>,
+}
+
+impl UploadOperation {
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let request_headers = value
+            .get("requestHeaders")
+            .and_then(|h| h.as_array())
+            .map(|headers| {
+                headers
+                    .iter()
+                    .filter_map(|h| {
+                        Some((
+                            h.get("name")?.as_str()?.to_string(),
+                            h.get("value")?.as_str()?.to_string(),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            method: value.get("method")?.as_str()?.to_string(),
+            url: value.get("url")?.as_str()?.to_string(),
+            length: value.get("length")?.as_u64()?,
+            offset: value.get("offset")?.as_u64()?,
+            request_headers,
+        })
+    }
+
+    pub fn from_resource(resource: &Value) -> Vec<Self> {
+        resource
+            .get("attributes")
+            .and_then(|a| a.get("uploadOperations"))
+            .and_then(|ops| ops.as_array())
+            .map(|ops| ops.iter().filter_map(Self::from_value).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn slice<'a>(&self, data: &'a [u8]) -> Result<&'a [u8], AppStoreConnectError> {
+        let start = self.offset as usize;
+        let end = start + self.length as usize;
+
+        data.get(start..end).ok_or_else(|| {
+            AppStoreConnectError::Validation(ValidationError::new(format!(
+                "Upload operation range {}..{} exceeds file size {}",
+                start,
+                end,
+                data.len()
+            )))
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryOptions {
+    pub max_attempts: u32,
+    pub initial_delay: Duration,
+    pub backoff_factor: f64,
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_secs(2),
+            backoff_factor: 2.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UploadProgress {
+    pub reservation_id: String,
+    pub completed_offsets: BTreeSet<u64>,
+}
+
+impl UploadProgress {
+    pub fn new(reservation_id: impl Into<String>) -> Self {
+        Self {
+            reservation_id: reservation_id.into(),
+            completed_offsets: BTreeSet::new(),
+        }
+    }
+
+    pub fn pending<'a>(&self, operations: &'a [UploadOperation]) -> Vec<&'a UploadOperation> {
+        operations
+            .iter()
+            .filter(|op| !self.completed_offsets.contains(&op.offset))
+            .collect()
+    }
+
+    pub fn is_complete(&self, operations: &[UploadOperation]) -> bool {
+        self.pending(operations).is_empty()
+    }
+
+    // Progress for an upload of `path` is kept next to it, so an interrupted
+    // upload can be resumed from the file alone.
+    pub fn sidecar_path(path: &Path) -> PathBuf {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(".upload.json");
+        PathBuf::from(sidecar)
+    }
+
+    pub async fn load(path: &Path) -> Result<Self, AppStoreConnectError> {
+        let contents = tokio::fs::read_to_string(path).await?;
+        serde_json::from_str(&contents).map_err(AppStoreConnectError::Json)
+    }
+
+    // Written to a temporary file and renamed over the old one, so a process
+    // killed mid-write never leaves a truncated progress file behind.
+    pub async fn save(&self, path: &Path) -> Result<(), AppStoreConnectError> {
+        let contents = serde_json::to_string_pretty(self)?;
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+        tokio::fs::write(&temp, contents).await?;
+        tokio::fs::rename(&temp, path).await?;
+        Ok(())
+    }
+}
+
+// With a `checkpoint` path, progress is saved there after every part so an
+// upload cut short by a crash can still be resumed.
+pub async fn upload_parts(
+    base: &BaseAPI,
+    operations: &[UploadOperation],
+    path: &Path,
+    progress: &mut UploadProgress,
+    retry: &RetryOptions,
+    checkpoint: Option<&Path>,
+) -> Result<(), AppStoreConnectError> {
+    for operation in progress.pending(operations) {
+        let mut delay = retry.initial_delay;
+        let mut attempt = 1;
+
+        loop {
+            // Parts are re-read on each attempt so only one is ever held in memory.
+            let chunk = read_part(path, operation).await?;
+            match base.upload_part(operation, chunk).await {
+                Ok(()) => break,
+                Err(e) if attempt < retry.max_attempts && is_transient(&e) => {
+                    tokio::time::sleep(delay).await;
+                    delay = delay.mul_f64(retry.backoff_factor);
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        progress.completed_offsets.insert(operation.offset);
+        if let Some(checkpoint) = checkpoint {
+            // A failed save only costs re-sending parts on resume.
+            let _ = progress.save(checkpoint).await;
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn read_part(
+    path: &Path,
+    operation: &UploadOperation,
+) -> Result<Vec<u8>, AppStoreConnectError> {
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(SeekFrom::Start(operation.offset)).await?;
+
+    let mut chunk = vec![0u8; operation.length as usize];
+    file.read_exact(&mut chunk).await.map_err(|e| {
+        if e.kind() == std::io::ErrorKind::UnexpectedEof {
+            AppStoreConnectError::Validation(ValidationError::new(format!(
+                "Upload operation range {}..{} exceeds the size of {}",
+                operation.offset,
+                operation.offset + operation.length,
+                path.display()
+            )))
+        } else {
+            AppStoreConnectError::Io(e)
+        }
+    })?;
+
+    Ok(chunk)
+}
+
+// Server errors, throttling and dropped connections are worth another attempt;
+// 4xx responses such as a bad checksum or an expired operation are not.
+pub fn is_transient(error: &AppStoreConnectError) -> bool {
+    match error {
+        AppStoreConnectError::Http(e) => match e.status() {
+            Some(status) => status.is_server_error() || status.as_u16() == 429,
+            None => e.is_timeout() || e.is_connect() || e.is_request() || e.is_body(),
+        },
+        AppStoreConnectError::RateLimit(_) | AppStoreConnectError::Timeout(_) => true,
+        _ => false,
+    }
+}
+
+pub fn md5_hex(data: &[u8]) -> String {
+    format!("{:x}", md5::compute(data))
+}
+
+pub async fn md5_file(path: &Path) -> Result<String, AppStoreConnectError> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut context = md5::Context::new();
+    let mut buffer = vec![0u8; 1024 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        context.consume(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", context.compute()))
+}
+
+pub(crate) async fn upload_file_info(path: &Path) -> Result<(String, u64), AppStoreConnectError> {
+    let file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| {
+            AppStoreConnectError::Validation(ValidationError::new(format!(
+                "Invalid file path: {}",
+                path.display()
+            )))
+        })?
+        .to_string();
+
+    let file_size = tokio::fs::metadata(path).await?.len();
+    Ok((file_name, file_size))
+}
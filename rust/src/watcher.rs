@@ -0,0 +1,463 @@
+use crate::api::reviews::CustomerReview;
+use crate::client::Client;
+use crate::error::AppStoreConnectError;
+use crate::polling::{rate_limit_delay, wait_for_tick};
+use crate::upload::md5_hex;
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WatchTarget {
+    VersionState { version_id: String },
+    BuildProcessing { build_id: String },
+    ReviewSubmission { submission_id: String },
+}
+
+impl WatchTarget {
+    pub fn version(version_id: impl Into<String>) -> Self {
+        WatchTarget::VersionState {
+            version_id: version_id.into(),
+        }
+    }
+
+    pub fn build(build_id: impl Into<String>) -> Self {
+        WatchTarget::BuildProcessing {
+            build_id: build_id.into(),
+        }
+    }
+
+    pub fn review_submission(submission_id: impl Into<String>) -> Self {
+        WatchTarget::ReviewSubmission {
+            submission_id: submission_id.into(),
+        }
+    }
+
+    async fn fetch_state(&self, client: &Client) -> Result<String, AppStoreConnectError> {
+        let (endpoint, attribute) = match self {
+            WatchTarget::VersionState { version_id } => {
+                (format!("appStoreVersions/{}", version_id), "appStoreState")
+            }
+            WatchTarget::BuildProcessing { build_id } => {
+                (format!("builds/{}", build_id), "processingState")
+            }
+            WatchTarget::ReviewSubmission { submission_id } => {
+                (format!("reviewSubmissions/{}", submission_id), "state")
+            }
+        };
+
+        let response = client.base().get(&endpoint, None).await?;
+
+        Ok(response
+            .get("data")
+            .and_then(|d| d.get("attributes"))
+            .and_then(|a| a.get(attribute))
+            .and_then(|s| s.as_str())
+            .unwrap_or("UNKNOWN")
+            .to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WatchEvent {
+    StateChanged {
+        target: WatchTarget,
+        from: Option<String>,
+        to: String,
+    },
+    Error {
+        target: WatchTarget,
+        message: String,
+    },
+}
+
+impl WatchEvent {
+    pub fn target(&self) -> &WatchTarget {
+        match self {
+            WatchEvent::StateChanged { target, .. } | WatchEvent::Error { target, .. } => target,
+        }
+    }
+
+    pub fn new_state(&self) -> Option<&str> {
+        match self {
+            WatchEvent::StateChanged { to, .. } => Some(to.as_str()),
+            WatchEvent::Error { .. } => None,
+        }
+    }
+
+    pub fn is_initial(&self) -> bool {
+        matches!(self, WatchEvent::StateChanged { from: None, .. })
+    }
+}
+
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    async fn handle(&self, event: &WatchEvent);
+}
+
+#[derive(Clone)]
+pub struct Watcher {
+    client: Client,
+    targets: Vec<WatchTarget>,
+    interval: Duration,
+    last_states: HashMap<WatchTarget, String>,
+}
+
+impl Watcher {
+    pub fn new(client: Client, interval: Duration) -> Self {
+        Self {
+            client,
+            targets: Vec::new(),
+            interval,
+            last_states: HashMap::new(),
+        }
+    }
+
+    pub fn watch(mut self, target: WatchTarget) -> Self {
+        if !self.targets.contains(&target) {
+            self.targets.push(target);
+        }
+        self
+    }
+
+    pub fn unwatch(&mut self, target: &WatchTarget) {
+        self.targets.retain(|t| t != target);
+        self.last_states.remove(target);
+    }
+
+    pub fn targets(&self) -> &[WatchTarget] {
+        &self.targets
+    }
+
+    pub async fn poll_once(&mut self) -> Vec<WatchEvent> {
+        self.poll_round().await.0
+    }
+
+    // A rate limit ends the round early and is reported as an `Error` event,
+    // so handlers can tell that polling has stalled.
+    async fn poll_round(&mut self) -> (Vec<WatchEvent>, bool) {
+        let mut events = Vec::new();
+
+        for target in &self.targets {
+            match target.fetch_state(&self.client).await {
+                Ok(state) => {
+                    let previous = self.last_states.get(target);
+                    if previous != Some(&state) {
+                        events.push(WatchEvent::StateChanged {
+                            target: target.clone(),
+                            from: previous.cloned(),
+                            to: state.clone(),
+                        });
+                        self.last_states.insert(target.clone(), state);
+                    }
+                }
+                Err(e @ AppStoreConnectError::RateLimit(_)) => {
+                    events.push(WatchEvent::Error {
+                        target: target.clone(),
+                        message: format!("Polling paused: {}", e),
+                    });
+                    return (events, true);
+                }
+                Err(e) => events.push(WatchEvent::Error {
+                    target: target.clone(),
+                    message: e.to_string(),
+                }),
+            }
+        }
+
+        (events, false)
+    }
+
+    pub async fn run<H: EventHandler>(mut self, handler: H) {
+        let mut ticker = tokio::time::interval(self.interval);
+        let mut backoff = None;
+
+        loop {
+            wait_for_tick(&mut ticker, backoff).await;
+            let (events, rate_limited) = self.poll_round().await;
+            backoff = rate_limited.then(|| rate_limit_delay(self.interval, backoff));
+
+            for event in events {
+                handler.handle(&event).await;
+            }
+        }
+    }
+
+    pub fn spawn(mut self) -> (JoinHandle<()>, mpsc::UnboundedReceiver<WatchEvent>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            let mut backoff = None;
+
+            loop {
+                wait_for_tick(&mut ticker, backoff).await;
+                let (events, rate_limited) = self.poll_round().await;
+                backoff = rate_limited.then(|| rate_limit_delay(self.interval, backoff));
+
+                for event in events {
+                    if sender.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        (handle, receiver)
+    }
+}
+
+#[async_trait]
+pub trait StateStore: Send + Sync {
+    async fn get(&self, key: &str) -> Result<Option<String>, AppStoreConnectError>;
+    async fn set(&self, key: &str, value: &str) -> Result<(), AppStoreConnectError>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStateStore {
+    entries: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl MemoryStateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl StateStore for MemoryStateStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, AppStoreConnectError> {
+        Ok(self.entries.lock().await.get(key).cloned())
+    }
+
+    async fn set(&self, key: &str, value: &str) -> Result<(), AppStoreConnectError> {
+        self.entries
+            .lock()
+            .await
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct FileStateStore {
+    path: PathBuf,
+    entries: Mutex<HashMap<String, String>>,
+}
+
+impl FileStateStore {
+    pub async fn open(path: impl Into<PathBuf>) -> Result<Self, AppStoreConnectError> {
+        let path = path.into();
+        let entries = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => serde_json::from_str(&content)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+}
+
+#[async_trait]
+impl StateStore for FileStateStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, AppStoreConnectError> {
+        Ok(self.entries.lock().await.get(key).cloned())
+    }
+
+    async fn set(&self, key: &str, value: &str) -> Result<(), AppStoreConnectError> {
+        let mut entries = self.entries.lock().await;
+        entries.insert(key.to_string(), value.to_string());
+        tokio::fs::write(&self.path, serde_json::to_string_pretty(&*entries)?).await?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+pub struct SqliteStateStore {
+    connection: std::sync::Mutex<rusqlite::Connection>,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteStateStore {
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self, AppStoreConnectError> {
+        Self::from_connection(rusqlite::Connection::open(path).map_err(sqlite_error)?)
+    }
+
+    pub fn in_memory() -> Result<Self, AppStoreConnectError> {
+        Self::from_connection(rusqlite::Connection::open_in_memory().map_err(sqlite_error)?)
+    }
+
+    fn from_connection(connection: rusqlite::Connection) -> Result<Self, AppStoreConnectError> {
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS state (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+                [],
+            )
+            .map_err(sqlite_error)?;
+
+        Ok(Self {
+            connection: std::sync::Mutex::new(connection),
+        })
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn sqlite_error(e: rusqlite::Error) -> AppStoreConnectError {
+    AppStoreConnectError::Unknown(format!("SQLite error: {}", e))
+}
+
+// Lookups are single-row primary key queries, so they run inline rather than
+// on a blocking thread.
+#[cfg(feature = "sqlite")]
+#[async_trait]
+impl StateStore for SqliteStateStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, AppStoreConnectError> {
+        use rusqlite::OptionalExtension;
+
+        let connection = self.connection.lock().unwrap_or_else(|e| e.into_inner());
+        connection
+            .query_row("SELECT value FROM state WHERE key = ?1", [key], |row| {
+                row.get(0)
+            })
+            .optional()
+            .map_err(sqlite_error)
+    }
+
+    async fn set(&self, key: &str, value: &str) -> Result<(), AppStoreConnectError> {
+        let connection = self.connection.lock().unwrap_or_else(|e| e.into_inner());
+        connection
+            .execute(
+                "INSERT INTO state (key, value) VALUES (?1, ?2)
+                 ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+                [key, value],
+            )
+            .map_err(sqlite_error)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReviewEvent {
+    New(CustomerReview),
+    Updated(CustomerReview),
+    Error(String),
+}
+
+#[async_trait]
+pub trait ReviewHandler: Send + Sync {
+    async fn handle(&self, event: &ReviewEvent);
+}
+
+pub fn review_fingerprint(review: &CustomerReview) -> String {
+    let content = serde_json::json!([
+        review.rating,
+        review.title,
+        review.body,
+        review.response.as_ref().map(|r| &r.response_body),
+    ]);
+    md5_hex(content.to_string().as_bytes())
+}
+
+pub(crate) async fn poll_reviews(
+    client: &Client,
+    store: &dyn StateStore,
+    app_id: &str,
+    lookback: chrono::Duration,
+) -> Result<Vec<ReviewEvent>, AppStoreConnectError> {
+    let reviews = client
+        .reviews()
+        .fetch_since(app_id, Utc::now() - lookback)
+        .await?;
+    let mut events = Vec::new();
+
+    // Oldest first so handlers see reviews in the order they were written.
+    for review in reviews.into_iter().rev() {
+        let key = format!("review:{}", review.id);
+        let fingerprint = review_fingerprint(&review);
+
+        match store.get(&key).await? {
+            Some(seen) if seen == fingerprint => continue,
+            Some(_) => events.push(ReviewEvent::Updated(review)),
+            None => events.push(ReviewEvent::New(review)),
+        }
+        store.set(&key, &fingerprint).await?;
+    }
+
+    Ok(events)
+}
+
+#[derive(Clone)]
+pub struct ReviewWatcher {
+    client: Client,
+    app_id: String,
+    interval: Duration,
+    lookback: chrono::Duration,
+    store: Arc<dyn StateStore>,
+}
+
+impl ReviewWatcher {
+    pub fn new(client: Client, app_id: impl Into<String>, interval: Duration) -> Self {
+        Self {
+            client,
+            app_id: app_id.into(),
+            interval,
+            lookback: chrono::Duration::days(7),
+            store: Arc::new(MemoryStateStore::new()),
+        }
+    }
+
+    pub fn with_store(mut self, store: impl StateStore + 'static) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    pub fn with_lookback(mut self, lookback: chrono::Duration) -> Self {
+        self.lookback = lookback;
+        self
+    }
+
+    pub async fn poll_once(&self) -> Result<Vec<ReviewEvent>, AppStoreConnectError> {
+        poll_reviews(
+            &self.client,
+            self.store.as_ref(),
+            &self.app_id,
+            self.lookback,
+        )
+        .await
+    }
+
+    pub async fn run<H: ReviewHandler>(self, handler: H) {
+        let mut ticker = tokio::time::interval(self.interval);
+        let mut backoff = None;
+
+        loop {
+            wait_for_tick(&mut ticker, backoff).await;
+            match self.poll_once().await {
+                Ok(events) => {
+                    backoff = None;
+                    for event in events {
+                        handler.handle(&event).await;
+                    }
+                }
+                Err(e @ AppStoreConnectError::RateLimit(_)) => {
+                    backoff = Some(rate_limit_delay(self.interval, backoff));
+                    let message = format!("Polling paused: {}", e);
+                    handler.handle(&ReviewEvent::Error(message)).await;
+                }
+                Err(e) => {
+                    backoff = None;
+                    handler.handle(&ReviewEvent::Error(e.to_string())).await;
+                }
+            }
+        }
+    }
+}